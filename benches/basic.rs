@@ -8,6 +8,7 @@ use crate::object;
 ///
 /// TODO: small and large object benchmarks.
 ///     examples: hashing a key for HashMap vs. hashing a large value for HashSet
+#[allow(clippy::type_complexity)]
 pub fn bench(c: &mut Criterion) {
     let groups: &[(
         &str,
@@ -34,7 +35,7 @@ pub fn bench(c: &mut Criterion) {
 
     let sizes = [2usize, 8, 16, 64, 100, 177, 256, 1024, 4096];
 
-    for (name, string_fn, int_fn, object_fn) in groups.into_iter() {
+    for (name, string_fn, int_fn, object_fn) in groups.iter() {
         let mut group = c.benchmark_group(name.to_string());
         for size in sizes {
             let name = "str_".to_string() + &size.to_string();
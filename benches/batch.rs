@@ -0,0 +1,102 @@
+use criterion::{Bencher, Criterion, Throughput};
+use rand::distributions::{Alphanumeric, DistString};
+use rand::Rng;
+
+/// Benchmark [rapidhash::rapidhash_batch] against hashing the same keys one at a time.
+///
+/// The lane-wise path gains the most on short keys of mixed lengths, and longer keys take the
+/// scalar path, so `16_64` should match the loop. With the `hashbrown` feature, also benchmark
+/// [rapidhash::RapidBatchGet] against looking up the same keys one at a time, in a map that fits in
+/// cache and one that doesn't. Only the larger map is expected to gain.
+pub fn bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch");
+    for (min, max) in [(8, 8), (4, 16), (0, 16), (16, 64)] {
+        group.throughput(Throughput::Elements(10000));
+        group.bench_function(format!("batch_{min}_{max}"), bench_batch(10000, min, max));
+        group.bench_function(format!("loop_{min}_{max}"), bench_loop(10000, min, max));
+    }
+
+    #[cfg(feature = "hashbrown")]
+    for size in [1 << 12, 1 << 22] {
+        group.throughput(Throughput::Elements(4096));
+        group.bench_function(format!("map_get_{size}"), bench_map_get(size));
+        group.bench_function(format!("map_get_batch_{size}"), bench_map_get_batch(size));
+    }
+}
+
+fn sample_keys(count: usize, min: usize, max: usize) -> Vec<String> {
+    let mut rng = rand::thread_rng();
+    (0..count)
+        .map(|_| {
+            let len = rng.gen_range(min..=max);
+            Alphanumeric.sample_string(&mut rng, len)
+        })
+        .collect()
+}
+
+fn bench_batch(count: usize, min: usize, max: usize) -> Box<dyn FnMut(&mut Bencher)> {
+    let keys = sample_keys(count, min, max);
+    Box::new(move |b: &mut Bencher| {
+        let keys: Vec<&[u8]> = keys.iter().map(|key| key.as_bytes()).collect();
+        let mut out = vec![0u64; keys.len()];
+        b.iter(|| {
+            rapidhash::rapidhash_batch(criterion::black_box(&keys), 0, &mut out);
+            out[out.len() - 1]
+        });
+    })
+}
+
+fn bench_loop(count: usize, min: usize, max: usize) -> Box<dyn FnMut(&mut Bencher)> {
+    let keys = sample_keys(count, min, max);
+    Box::new(move |b: &mut Bencher| {
+        let keys: Vec<&[u8]> = keys.iter().map(|key| key.as_bytes()).collect();
+        let mut out = vec![0u64; keys.len()];
+        b.iter(|| {
+            for (key, out) in criterion::black_box(&keys).iter().zip(out.iter_mut()) {
+                *out = rapidhash::rapidhash_seeded(key, 0);
+            }
+            out[out.len() - 1]
+        });
+    })
+}
+
+/// A [rapidhash::RapidHashbrownMap] of `size` random keys, and 4096 keys to look up, half of which
+/// are in the map.
+#[cfg(feature = "hashbrown")]
+fn sample_map(size: usize) -> (rapidhash::RapidHashbrownMap<u64, u64>, Vec<u64>) {
+    let mut rng = rand::thread_rng();
+    let inserted: Vec<u64> = (0..size).map(|_| rng.gen()).collect();
+    let map = inserted.iter().map(|&key| (key, key >> 3)).collect();
+    let keys = (0..4096)
+        .map(|i| if i % 2 == 0 { inserted[rng.gen_range(0..size)] } else { rng.gen() })
+        .collect();
+    (map, keys)
+}
+
+#[cfg(feature = "hashbrown")]
+fn bench_map_get(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
+    let (map, keys) = sample_map(size);
+    Box::new(move |b: &mut Bencher| {
+        let mut out = vec![None; keys.len()];
+        b.iter(|| {
+            for (key, out) in criterion::black_box(&keys).iter().zip(out.iter_mut()) {
+                *out = map.get(key);
+            }
+            out[out.len() - 1]
+        });
+    })
+}
+
+#[cfg(feature = "hashbrown")]
+fn bench_map_get_batch(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
+    use rapidhash::RapidBatchGet;
+
+    let (map, keys) = sample_map(size);
+    Box::new(move |b: &mut Bencher| {
+        let mut out = vec![None; keys.len()];
+        b.iter(|| {
+            map.get_batch_into(criterion::black_box(&keys), &mut out);
+            out[out.len() - 1]
+        });
+    })
+}
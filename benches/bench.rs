@@ -1,18 +1,22 @@
 use criterion::{criterion_group, criterion_main};
 
 mod basic;
+mod batch;
 mod int;
 mod vector;
 mod object;
 mod hashmap;
 mod rng;
 mod compiled;
+mod large_path;
 
 criterion_group!(
     benches,
     basic::bench,
+    batch::bench,
     hashmap::bench,
     rng::bench,
     compiled::bench,
+    large_path::bench,
 );
 criterion_main!(benches);
@@ -184,47 +184,47 @@ pub fn bench_match_slice() -> Box<dyn FnMut(&mut Bencher)> {
             random_input().to_vec()
         }, |i: &mut Vec<u8>| {
             match i.as_slice() {
-                h if h == &INPUTS[0] => const_random!(u64),
-                h if h == &INPUTS[1] => const_random!(u64),
-                h if h == &INPUTS[2] => const_random!(u64),
-                h if h == &INPUTS[3] => const_random!(u64),
-                h if h == &INPUTS[4] => const_random!(u64),
-                h if h == &INPUTS[5] => const_random!(u64),
-                h if h == &INPUTS[6] => const_random!(u64),
-                h if h == &INPUTS[7] => const_random!(u64),
-                h if h == &INPUTS[8] => const_random!(u64),
-                h if h == &INPUTS[9] => const_random!(u64),
-                h if h == &INPUTS[10] => const_random!(u64),
-                h if h == &INPUTS[11] => const_random!(u64),
-                h if h == &INPUTS[12] => const_random!(u64),
-                h if h == &INPUTS[13] => const_random!(u64),
-                h if h == &INPUTS[14] => const_random!(u64),
-                h if h == &INPUTS[15] => const_random!(u64),
-                h if h == &INPUTS[16] => const_random!(u64),
-                h if h == &INPUTS[17] => const_random!(u64),
-                h if h == &INPUTS[18] => const_random!(u64),
-                h if h == &INPUTS[19] => const_random!(u64),
-                h if h == &INPUTS[20] => const_random!(u64),
-                h if h == &INPUTS[21] => const_random!(u64),
-                h if h == &INPUTS[22] => const_random!(u64),
-                h if h == &INPUTS[23] => const_random!(u64),
-                h if h == &INPUTS[24] => const_random!(u64),
-                h if h == &INPUTS[25] => const_random!(u64),
-                h if h == &INPUTS[26] => const_random!(u64),
-                h if h == &INPUTS[27] => const_random!(u64),
-                h if h == &INPUTS[28] => const_random!(u64),
-                h if h == &INPUTS[29] => const_random!(u64),
-                h if h == &INPUTS[30] => const_random!(u64),
-                h if h == &INPUTS[31] => const_random!(u64),
-                h if h == &INPUTS[32] => const_random!(u64),
-                h if h == &INPUTS[33] => const_random!(u64),
-                h if h == &INPUTS[34] => const_random!(u64),
-                h if h == &INPUTS[35] => const_random!(u64),
-                h if h == &INPUTS[36] => const_random!(u64),
-                h if h == &INPUTS[37] => const_random!(u64),
-                h if h == &INPUTS[38] => const_random!(u64),
-                h if h == &INPUTS[39] => const_random!(u64),
-                h if h == &INPUTS[39] => const_random!(u64),
+                h if h == INPUTS[0] => const_random!(u64),
+                h if h == INPUTS[1] => const_random!(u64),
+                h if h == INPUTS[2] => const_random!(u64),
+                h if h == INPUTS[3] => const_random!(u64),
+                h if h == INPUTS[4] => const_random!(u64),
+                h if h == INPUTS[5] => const_random!(u64),
+                h if h == INPUTS[6] => const_random!(u64),
+                h if h == INPUTS[7] => const_random!(u64),
+                h if h == INPUTS[8] => const_random!(u64),
+                h if h == INPUTS[9] => const_random!(u64),
+                h if h == INPUTS[10] => const_random!(u64),
+                h if h == INPUTS[11] => const_random!(u64),
+                h if h == INPUTS[12] => const_random!(u64),
+                h if h == INPUTS[13] => const_random!(u64),
+                h if h == INPUTS[14] => const_random!(u64),
+                h if h == INPUTS[15] => const_random!(u64),
+                h if h == INPUTS[16] => const_random!(u64),
+                h if h == INPUTS[17] => const_random!(u64),
+                h if h == INPUTS[18] => const_random!(u64),
+                h if h == INPUTS[19] => const_random!(u64),
+                h if h == INPUTS[20] => const_random!(u64),
+                h if h == INPUTS[21] => const_random!(u64),
+                h if h == INPUTS[22] => const_random!(u64),
+                h if h == INPUTS[23] => const_random!(u64),
+                h if h == INPUTS[24] => const_random!(u64),
+                h if h == INPUTS[25] => const_random!(u64),
+                h if h == INPUTS[26] => const_random!(u64),
+                h if h == INPUTS[27] => const_random!(u64),
+                h if h == INPUTS[28] => const_random!(u64),
+                h if h == INPUTS[29] => const_random!(u64),
+                h if h == INPUTS[30] => const_random!(u64),
+                h if h == INPUTS[31] => const_random!(u64),
+                h if h == INPUTS[32] => const_random!(u64),
+                h if h == INPUTS[33] => const_random!(u64),
+                h if h == INPUTS[34] => const_random!(u64),
+                h if h == INPUTS[35] => const_random!(u64),
+                h if h == INPUTS[36] => const_random!(u64),
+                h if h == INPUTS[37] => const_random!(u64),
+                h if h == INPUTS[38] => const_random!(u64),
+                h if h == INPUTS[39] => const_random!(u64),
+                h if h == INPUTS[39] => const_random!(u64),
                 _ => const_random!(u64),
             }
         }, criterion::BatchSize::SmallInput);
@@ -237,7 +237,7 @@ pub fn bench_hashmap_get() -> Box<dyn FnMut(&mut Bencher)> {
         let hashmap: RapidHashMap<Vec<u8>, u64> = INPUTS
             .into_iter()
             .map(|i| i.to_vec())
-            .zip(HASHES.into_iter())
+            .zip(HASHES)
             .collect();
 
         b.iter_batched_ref(|| {
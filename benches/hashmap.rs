@@ -5,6 +5,7 @@ use rand::Rng;
 use wyhash::WyHash;
 
 /// Benchmark each hashing algorithm with hashmaps.
+#[allow(clippy::type_complexity)]
 pub fn bench(c: &mut Criterion) {
     let groups: &[(
         &str,
@@ -34,7 +35,7 @@ pub fn bench(c: &mut Criterion) {
         10000,
     ];
 
-    for (name, strings, ints, objs) in groups.into_iter() {
+    for (name, strings, ints, objs) in groups.iter() {
         let mut group = c.benchmark_group(name.to_string());
         for (size, min, max, name) in string_sizes {
             let name_size = if size == 0 { 450000 } else { size };
@@ -58,7 +59,7 @@ pub fn bench(c: &mut Criterion) {
 lazy_static::lazy_static! {
     static ref WORDS: Vec<String> = {
         const WORDS_FILE: &str = "target/words.txt";
-        let text: String = if std::fs::exists(WORDS_FILE).unwrap_or(false) {
+        let text: String = if std::path::Path::new(WORDS_FILE).exists() {
             println!("Reading dictionary words from {WORDS_FILE}");
             std::fs::read_to_string(WORDS_FILE).expect("Failed to read words from text file.")
         } else {
@@ -117,8 +118,8 @@ fn sample_emails(count: usize) -> Vec<String> {
     (0..count)
         .map(|_| {
             let length = index.sample(&mut rng);
-            let address = Alphanumeric.sample_string(&mut rng, length);
-            address
+            
+            Alphanumeric.sample_string(&mut rng, length)
         })
         .collect()
 }
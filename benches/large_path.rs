@@ -0,0 +1,60 @@
+use criterion::{Bencher, Criterion, Throughput};
+use rand::distributions::{Alphanumeric, DistString};
+use rand::Rng;
+use rapidhash::RapidHashMap;
+
+/// Benchmark the trade-off of the `cold-large` feature, which outlines the >16-byte path.
+///
+/// Inputs just over 16 bytes always pay for the extra call. Whether short-key lookups gain from the
+/// smaller inlined hash depends on the surrounding hot code and the CPU's instruction cache, so
+/// measure on the target workload. Criterion compares each run against the previous one, so run
+/// this twice:
+/// ```shell
+/// cargo bench --bench bench -- large_path
+/// cargo bench --bench bench --features cold-large -- large_path
+/// ```
+pub fn bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_path");
+
+    group.throughput(Throughput::Elements(10000));
+    group.bench_function("map_get_4_16", bench_map_get(10000, 4, 16));
+    group.bench_function("map_get_4_64", bench_map_get(10000, 4, 64));
+
+    for size in [16usize, 17, 64, 1024] {
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_function(format!("str_{size}"), bench_str(size));
+    }
+}
+
+/// Look up every key of a map with `count` random alphanumeric keys of `min..=max` bytes.
+fn bench_map_get(count: usize, min: usize, max: usize) -> Box<dyn FnMut(&mut Bencher)> {
+    let mut rng = rand::thread_rng();
+    let keys: Vec<String> = (0..count)
+        .map(|_| {
+            let len = rng.gen_range(min..=max);
+            Alphanumeric.sample_string(&mut rng, len)
+        })
+        .collect();
+    let map: RapidHashMap<String, u32> = keys.iter().cloned().zip(0..).collect();
+
+    Box::new(move |b: &mut Bencher| {
+        b.iter(|| {
+            let mut sum = 0u32;
+            for key in &keys {
+                sum = sum.wrapping_add(*map.get(criterion::black_box(key.as_str())).unwrap());
+            }
+            sum
+        });
+    })
+}
+
+/// Hash a random `size`-byte input whose length the compiler can't see.
+fn bench_str(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
+    Box::new(move |b: &mut Bencher| {
+        b.iter_batched_ref(|| {
+            Alphanumeric.sample_string(&mut rand::thread_rng(), size)
+        }, |s: &mut String| {
+            rapidhash::rapidhash(criterion::black_box(s.as_bytes()))
+        }, criterion::BatchSize::SmallInput);
+    })
+}
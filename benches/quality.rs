@@ -87,7 +87,7 @@ where B : BuildHasher + Default
     check!(hasher_collisions_powerset::<B, u32>(&[0, 1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384]));
 }
 
-fn hasher_collisions_permute<B, D>(data: &[impl Hash]) -> f64
+fn hasher_collisions_permute<B, D: Hash>(data: &[D]) -> f64
 where B : BuildHasher + Default
 {
     use itertools::Itertools;
@@ -98,9 +98,9 @@ where B : BuildHasher + Default
     let mut i = 0;
 
     for perm in data.iter().permutations(data.len()) {
-        let mut hasher = build_hasher.build_hasher();
-        perm.hash(&mut hasher);
-        set.insert(hasher.finish());
+        
+        
+        set.insert(build_hasher.hash_one(&perm));
         i += 1;
     }
 
@@ -121,12 +121,12 @@ where B : BuildHasher + Default,
 
     let mut x = data.to_vec();
     permute(&mut x, 0, step, &mut |d| {
-        let len = data.len() * std::mem::size_of::<D>();
+        let len = std::mem::size_of_val(data);
         let perm_u8 = unsafe {
             slice::from_raw_parts(d.as_ptr() as *const u8, len)
         };
         let mut hasher = build_hasher.build_hasher();
-        hasher.write(&perm_u8);
+        hasher.write(perm_u8);
         set.insert(hasher.finish());
         i += 1;
     });
@@ -151,7 +151,7 @@ where F: FnMut(&[T])
     }
 }
 
-fn hasher_collisions_powerset<B, D>(data: &[impl Hash]) -> f64
+fn hasher_collisions_powerset<B, D: Hash>(data: &[D]) -> f64
 where B : BuildHasher + Default
 {
     use itertools::Itertools;
@@ -162,9 +162,9 @@ where B : BuildHasher + Default
     let mut i = 0;
 
     for perm in data.iter().powerset() {
-        let mut hasher = build_hasher.build_hasher();
-        perm.hash(&mut hasher);
-        set.insert(hasher.finish());
+        
+        
+        set.insert(build_hasher.hash_one(&perm));
         i += 1;
     }
 
@@ -256,7 +256,7 @@ where B : BuildHasher + Default
         input[i / 8] ^= bit;
 
         let mut hasher = build_hasher.build_hasher();
-        hasher.write(&input);
+        hasher.write(input);
         hashes.push(hasher.finish());
 
         if bits_left > 1 {
@@ -312,7 +312,7 @@ where B : BuildHasher + Default
         rng.fill(input);
 
         let mut hasher1 = build_hasher.build_hasher();
-        hasher1.write(&input);
+        hasher1.write(input);
         let v1 = hasher1.finish();
 
         let bytes_bit_changed = &mut input.to_vec().clone();
@@ -324,7 +324,7 @@ where B : BuildHasher + Default
             bytes_bit_changed[i / 8] = input[i / 8] ^ (1 << (i % 8));
 
             let mut hasher2 = build_hasher.build_hasher();
-            hasher2.write(black_box(&bytes_bit_changed)); // It seems there is a LLVM bug!?? Using black_box to prevent breaking compiler optimization
+            hasher2.write(black_box(bytes_bit_changed)); // It seems there is a LLVM bug!?? Using black_box to prevent breaking compiler optimization
             let v2 = black_box(hasher2.finish());
 
             // Compute diffs
@@ -340,9 +340,9 @@ where B : BuildHasher + Default
     }
 
     let count = iterations * N * 8;
-    let score = (1.0 - 2.0 * (scores_sum / count as f64)).abs();
+    
 
-    score
+    (1.0 - 2.0 * (scores_sum / count as f64)).abs()
 }
 
 fn distribution_bits<B, const N: usize>() -> f64
@@ -385,7 +385,7 @@ where B : BuildHasher + Default
         rng.fill(input);
 
         let mut hasher = build_hasher.build_hasher();
-        hasher.write(&input);
+        hasher.write(input);
         let hash = hasher.finish();
 
         let hash_bytes = hash.to_ne_bytes();
@@ -403,9 +403,9 @@ where B : BuildHasher + Default
     let worst_variance = 0.25f64;
 
     // Divide by the theoritical worst variance to normalize result from 0 to 1
-    let score = std / worst_variance;
+    
 
-    score
+    std / worst_variance
 }
 
 fn variance(data: &[f64]) -> f64 {
@@ -476,7 +476,7 @@ where B : BuildHasher + Default
         rng.fill(input);
 
         let mut hasher = build_hasher.build_hasher();
-        hasher.write(&input);
+        hasher.write(input);
         let hash = hasher.finish();
 
         let hash_f = hash as f64;
@@ -495,9 +495,9 @@ where B : BuildHasher + Default
     let worst_variance = 1f64 / buckets_count as f64;
 
     // Divide by the theoritical worst variance to normalize result from 0 to 1
-    let score = std / worst_variance;
+    
 
-    score
+    std / worst_variance
 }
 
 fn round_to_decimal(value: f64, decimals: usize) -> f64
@@ -17,6 +17,7 @@ pub fn bench(c: &mut Criterion) {
     bench_rng!(c, "rapidhash", bench_rapidhash);
     bench_rng!(c, "rapidhash_fast", bench_rapidhash_fast);
     bench_rng!(c, "rapidhash_time", bench_rapidhash_time);
+    bench_rng!(c, "rapidhash_x4", bench_rapidhash_x4);
     bench_rng!(c, "wyhash", bench_wyhash);
 }
 
@@ -63,6 +64,24 @@ pub fn bench_rapidhash_time(count: usize) -> Box<dyn FnMut(&mut Bencher)> {
     })
 }
 
+pub fn bench_rapidhash_x4(count: usize) -> Box<dyn FnMut(&mut Bencher)> {
+    Box::new(move |b: &mut Bencher| {
+        b.iter_batched(|| {
+            rand::random::<u64>()
+        }, |i: u64| {
+            let mut out = [0; 4];
+            let mut rng = rapidhash::RapidRngX4::new(i);
+            for _ in 0..(count + 3) / 4 {
+                let next = rng.next();
+                for lane in 0..4 {
+                    out[lane] ^= next[lane];
+                }
+            }
+            out
+        }, criterion::BatchSize::SmallInput);
+    })
+}
+
 pub fn bench_wyhash(count: usize) -> Box<dyn FnMut(&mut Bencher)> {
     Box::new(move |b: &mut Bencher| {
         b.iter_batched(|| {
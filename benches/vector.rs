@@ -14,7 +14,7 @@ pub fn bench_rapidhash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = rapidhash::RapidHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -27,7 +27,7 @@ pub fn bench_rapidhash_raw(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             OsRng.fill(slice.as_mut_slice());
             slice
         }, |bytes| {
-            rapidhash::rapidhash_inline(&bytes, RAPID_SEED)
+            rapidhash::rapidhash_inline(bytes, RAPID_SEED)
         }, criterion::BatchSize::SmallInput);
     })
 }
@@ -40,7 +40,7 @@ pub fn bench_default(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = std::collections::hash_map::DefaultHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -54,7 +54,7 @@ pub fn bench_fxhash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = fxhash::FxHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -68,7 +68,7 @@ pub fn bench_t1ha(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = t1ha::T1haHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -82,7 +82,7 @@ pub fn bench_wyhash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = wyhash::WyHash::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -95,7 +95,7 @@ pub fn bench_wyhash_raw(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             OsRng.fill(slice.as_mut_slice());
             slice
         }, |bytes| {
-            wyhash::wyhash(&bytes, 0)
+            wyhash::wyhash(bytes, 0)
         }, criterion::BatchSize::SmallInput);
     })
 }
@@ -108,7 +108,7 @@ pub fn bench_xxhash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = twox_hash::XxHash::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -122,7 +122,7 @@ pub fn bench_metrohash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = metrohash::MetroHash::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -136,7 +136,7 @@ pub fn bench_seahash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = seahash::SeaHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -150,7 +150,7 @@ pub fn bench_ahash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = ahash::AHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -164,7 +164,7 @@ pub fn bench_gxhash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = gxhash::GxHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -178,7 +178,7 @@ pub fn bench_farmhash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = farmhash::FarmHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -192,7 +192,7 @@ pub fn bench_highwayhash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = highway::HighwayHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -206,7 +206,7 @@ pub fn bench_rustchash(size: usize) -> Box<dyn FnMut(&mut Bencher)> {
             slice
         }, |bytes| {
             let mut hasher = rustc_hash::FxHasher::default();
-            hasher.write(&bytes);
+            hasher.write(bytes);
             hasher.finish()
         }, criterion::BatchSize::SmallInput);
     })
@@ -0,0 +1,28 @@
+//! Detects rustc versions older than 1.77, where `slice::first_chunk` is not yet stable, and enables
+//! the `rapidhash_legacy_read` cfg to read integers byte-by-byte instead.
+
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(rapidhash_legacy_read)");
+
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let minor = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|version| rustc_minor_version(&version));
+
+    // assume a modern compiler if the version can't be determined
+    if matches!(minor, Some(minor) if minor < 77) {
+        println!("cargo:rustc-cfg=rapidhash_legacy_read");
+    }
+}
+
+/// Parse the minor version from `rustc 1.xx.y (hash date)`.
+fn rustc_minor_version(version: &str) -> Option<u32> {
+    let mut parts = version.strip_prefix("rustc 1.")?.split('.');
+    parts.next()?.parse().ok()
+}
@@ -71,7 +71,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
         for (i, (hash_function, color)) in hash_settings.iter().enumerate() {
             cc.draw_series(LineSeries::new(sizes.iter().zip(latency_data[i].iter()).map(|(x, y)| (*x, *y)), color))?
                 .label(*hash_function)
-                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.clone()));
+                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], *color));
         }
 
         cc.configure_series_labels().border_style(BLACK).draw()?;
@@ -96,14 +96,14 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
         for (i, (hash_function, color)) in hash_settings.iter().enumerate() {
             cc.draw_series(LineSeries::new(sizes.iter().zip(throughput_data[i].iter()).map(|(x, y)| (*x, *y)), color))?
                 .label(*hash_function)
-                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.clone()));
+                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], *color));
         }
 
         cc.configure_series_labels().border_style(BLACK).draw()?;
     }
 
     root_area.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
-    println!("Result has been saved to {}", "charts.svg");
+    println!("Result has been saved to charts.svg");
 
     Ok(())
 }
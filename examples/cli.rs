@@ -1,4 +1,13 @@
-use std::io::Read;
+use std::collections::BTreeMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{BufRead, BufReader, ErrorKind, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use rapidhash::manifest::{self, HashEncoding, LineStyle};
 
 /// Command-line tool for rapidhash.
 ///
@@ -9,25 +18,809 @@ use std::io::Read;
 /// 8543579700415218186
 /// ```
 ///
-/// Reading file:
+/// Reading files, printing checksum-style `HASH  FILENAME` lines:
 /// ```bash
-/// cargo run --example cli -- example.txt
-/// 8543579700415218186
+/// cargo run --example cli -- example.txt other.txt
+/// 8543579700415218186  example.txt
+/// 8679691362217132241  other.txt
+/// ```
+///
+/// BSD-style output with `--tag`:
+/// ```bash
+/// cargo run --example cli -- --tag example.txt
+/// RAPIDHASH (example.txt) = 8543579700415218186
+/// ```
+///
+/// Verify a manifest of previously emitted lines with `--check`:
+/// ```bash
+/// cargo run --example cli -- example.txt other.txt > manifest.txt
+/// cargo run --example cli -- --check manifest.txt
+/// example.txt: OK
+/// other.txt: OK
+/// ```
+///
+/// Hashing literal strings with `--string`, without echo's trailing newline:
+/// ```bash
+/// cargo run --example cli -- --string foo bar
+/// 9623366293051875342  foo
+/// 12201805117608043625  bar
+/// ```
+///
+/// Recursively hashing directories, using 4 threads:
+/// ```bash
+/// cargo run --example cli -- -r --jobs 4 target/
+/// ```
+///
+/// Custom seeds and output encodings:
+/// ```bash
+/// cargo run --example cli -- --seed 0x1234 --format hex example.txt
+/// ```
+///
+/// Selecting the hash variant with `--algorithm`, which is labelled in json and csv output. Only
+/// `rapidhash` is currently available:
+/// ```bash
+/// cargo run --example cli -- --algorithm rapidhash example.txt
+/// ```
+///
+/// Machine-readable output for build systems and audit pipelines:
+/// ```bash
+/// cargo run --example cli -- --output json example.txt
+/// [{"path":"example.txt","size":8,"algorithm":"rapidhash","seed":"13679853920966426665","hash":"8543579700415218186"}
+/// ]
 /// ```
-pub fn main() {
-    let hash_arg = std::env::args().nth(1);
+///
+/// Finding duplicate files with `--dedupe`, printing each group of identical files separated by
+/// blank lines, and confirming each group byte for byte with `--verify-bytes`:
+/// ```bash
+/// cargo run --example cli -- --dedupe --verify-bytes photos/ backup/photos/
+/// 8543579700415218186  backup/photos/a.jpg
+/// 8543579700415218186  photos/a.jpg
+/// ```
+///
+/// Verifying a directory tree against an expected [rapidhash::rapidhash_dir] root with
+/// `--verify-root`, exiting with 0 when it matches and 1 otherwise:
+/// ```bash
+/// cargo run --example cli -- --format hex --verify-root 2f0a1c9d4e6b8a73 target/release
+/// target/release: OK
+/// ```
+///
+/// Comparing two inputs, exiting with 0 when their hashes are equal and 1 otherwise:
+/// ```bash
+/// cat example.txt | cargo run --example cli -- --compare - example.txt
+/// 8543579700415218186  -
+/// 8543579700415218186  example.txt
+/// ```
+///
+/// A filename of `-` reads from stdin.
+pub fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("rapidhash: {err}");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.help {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
 
-    let buffer = match hash_arg {
-        None => {
-            let mut buffer = Vec::with_capacity(1024);
-            std::io::stdin().read_to_end(&mut buffer).expect("Could not read from stdin.");
-            buffer
+    if let Some(manifest) = &options.check {
+        return check_manifest(manifest, &options);
+    }
+
+    if options.dedupe {
+        return dedupe(&options);
+    }
+
+    if let Some(expected) = options.verify_root {
+        return verify_root(expected, &options);
+    }
+
+    let mut exit = ExitCode::SUCCESS;
+    let mut output = Output::new(&options);
+
+    if options.compare {
+        return compare(&options);
+    }
+
+    if options.string {
+        for string in &options.files {
+            let hash = options.algorithm.hash_bytes(string.as_bytes(), options.seed);
+            output.record(string, string.len() as u64, hash);
         }
-        Some(filename) => {
-            std::fs::read(filename).expect("Could not load file.")
+        output.finish();
+        return exit;
+    }
+
+    // preserve the plain output when hashing stdin without any file arguments
+    if options.files.is_empty() {
+        match hash_input(Path::new("-"), options.algorithm, options.seed) {
+            Ok(hashed) if options.output == OutputFormat::Text => println!("{}", options.format.encode(hashed.hash)),
+            Ok(hashed) => output.record("-", hashed.size, hashed.hash),
+            Err(err) => {
+                eprintln!("rapidhash: -: {err}");
+                exit = ExitCode::FAILURE;
+            }
+        }
+        output.finish();
+        return exit;
+    }
+
+    let files = expand_inputs(&options.files, options.recursive, &mut exit);
+    let results = hash_all(&files, options.algorithm, options.seed, options.jobs);
+
+    for (file, result) in files.iter().zip(results) {
+        let file = file.display().to_string();
+        match result {
+            Ok(hashed) => output.record(&file, hashed.size, hashed.hash),
+            Err(err) => {
+                eprintln!("rapidhash: {file}: {err}");
+                exit = ExitCode::FAILURE;
+            }
+        }
+    }
+    output.finish();
+    exit
+}
+
+const USAGE: &str = "\
+Usage: rapidhash [OPTIONS] [FILE]...
+
+Hash each FILE with rapidhash, printing `HASH  FILENAME` lines.
+With no FILE, hash stdin and print only the hash. A FILE of `-` reads stdin.
+
+Options:
+      --tag           print BSD-style `RAPIDHASH (FILENAME) = HASH` lines
+  -c, --check FILE    read hashes from FILE and verify each listed file
+      --seed SEED     hash with a custom seed, in decimal or 0x-prefixed hex
+  -a, --algorithm ALG hash variant: rapidhash (default)
+      --format FMT    output encoding: decimal (default), hex, or base64
+  -o, --output OUT    output style: text (default), json, or csv
+      --compare A B   hash two inputs, exiting 0 if equal, 1 if different, or 2 on error
+  -s, --string        hash each argument as a literal UTF-8 string instead of a file
+  -r, --recursive     hash every file in the given directories, in sorted order
+  -j, --jobs N        hash up to N files concurrently (default: 1)
+      --dedupe        print groups of duplicate files under the given paths, exiting 1 if any
+      --verify-bytes  with --dedupe, confirm duplicates by comparing their bytes
+      --verify-root ROOT DIR
+                      verify the directory tree hash of DIR against ROOT, in the --format encoding
+  -h, --help          print this help message";
+
+/// Parsed command-line arguments.
+struct Options {
+    tag: bool,
+    help: bool,
+    check: Option<String>,
+    seed: u64,
+    algorithm: Algorithm,
+    format: HashEncoding,
+    output: OutputFormat,
+    compare: bool,
+    string: bool,
+    recursive: bool,
+    jobs: usize,
+    dedupe: bool,
+    verify_bytes: bool,
+    verify_root: Option<u64>,
+    files: Vec<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            tag: false,
+            help: false,
+            check: None,
+            seed: rapidhash::RAPID_SEED,
+            algorithm: Algorithm::Rapidhash,
+            format: HashEncoding::Decimal,
+            output: OutputFormat::Text,
+            compare: false,
+            string: false,
+            recursive: false,
+            jobs: 1,
+            dedupe: false,
+            verify_bytes: false,
+            verify_root: None,
+            files: Vec::new(),
+        }
+    }
+}
+
+impl Options {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut only_files = false;
+        let mut verify_root = None;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if only_files || arg == "-" || !arg.starts_with('-') {
+                options.files.push(arg);
+                continue;
+            }
+
+            match arg.as_str() {
+                "--" => only_files = true,
+                "--tag" => options.tag = true,
+                "-c" | "--check" => {
+                    let manifest = args.next().ok_or("option '--check' requires a FILE")?;
+                    options.check = Some(manifest);
+                }
+                "--seed" => {
+                    let seed = args.next().ok_or("option '--seed' requires a SEED")?;
+                    options.seed = parse_seed(&seed).ok_or(format!("invalid seed '{seed}'"))?;
+                }
+                "-a" | "--algorithm" => {
+                    let algorithm = args.next().ok_or("option '--algorithm' requires an ALG")?;
+                    options.algorithm = Algorithm::parse(&algorithm).ok_or(format!("invalid algorithm '{algorithm}'"))?;
+                }
+                "--format" => {
+                    let format = args.next().ok_or("option '--format' requires a FMT")?;
+                    options.format = HashEncoding::from_name(&format).ok_or(format!("invalid format '{format}'"))?;
+                }
+                "-o" | "--output" => {
+                    let output = args.next().ok_or("option '--output' requires an OUT style")?;
+                    options.output = OutputFormat::parse(&output).ok_or(format!("invalid output style '{output}'"))?;
+                }
+                "--compare" => options.compare = true,
+                "-s" | "--string" => options.string = true,
+                "-r" | "--recursive" => options.recursive = true,
+                "-j" | "--jobs" => {
+                    let jobs = args.next().ok_or("option '--jobs' requires a number N")?;
+                    options.jobs = jobs.parse().ok().filter(|&jobs| jobs > 0).ok_or(format!("invalid number of jobs '{jobs}'"))?;
+                }
+                "--dedupe" => options.dedupe = true,
+                "--verify-bytes" => options.verify_bytes = true,
+                "--verify-root" => {
+                    let root = args.next().ok_or("option '--verify-root' requires a ROOT")?;
+                    verify_root = Some(root);
+                }
+                "-h" | "--help" => options.help = true,
+                _ => return Err(format!("unrecognised option '{arg}'")),
+            }
+        }
+
+        // the root is decoded once every option is parsed, as it depends on `--format`
+        if let Some(root) = verify_root {
+            options.verify_root = Some(options.format.decode(&root).ok_or(format!("invalid root '{root}'"))?);
+        }
+
+        if options.string && options.files.is_empty() {
+            return Err("option '--string' requires at least one STRING".into());
+        }
+        if options.compare && options.files.len() != 2 {
+            return Err("option '--compare' requires exactly two inputs".into());
+        }
+        if options.compare && (options.recursive || options.check.is_some()) {
+            return Err("option '--compare' cannot be combined with '--recursive' or '--check'".into());
+        }
+        if options.string && (options.recursive || options.check.is_some()) {
+            return Err("option '--string' cannot be combined with '--recursive' or '--check'".into());
+        }
+        if options.dedupe && options.files.is_empty() {
+            return Err("option '--dedupe' requires at least one PATH".into());
+        }
+        if options.dedupe && (options.compare || options.string || options.check.is_some()) {
+            return Err("option '--dedupe' cannot be combined with '--compare', '--string', or '--check'".into());
+        }
+        if options.dedupe && options.output != OutputFormat::Text {
+            return Err("option '--dedupe' only supports text output".into());
+        }
+        if options.verify_bytes && !options.dedupe {
+            return Err("option '--verify-bytes' requires '--dedupe'".into());
+        }
+        if options.verify_root.is_some() && options.files.len() != 1 {
+            return Err("option '--verify-root' requires exactly one DIR".into());
+        }
+        if options.verify_root.is_some() && (options.compare || options.string || options.dedupe || options.check.is_some()) {
+            return Err("option '--verify-root' cannot be combined with '--compare', '--string', '--dedupe', or '--check'".into());
+        }
+
+        Ok(options)
+    }
+}
+
+/// Parse a seed as either a decimal `u64` or a `0x`-prefixed hexadecimal `u64`.
+fn parse_seed(seed: &str) -> Option<u64> {
+    match seed.strip_prefix("0x").or_else(|| seed.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => seed.parse().ok(),
+    }
+}
+
+/// A hash variant selected with `--algorithm`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Algorithm {
+    /// rapidhash, as computed by [rapidhash::rapidhash_seeded].
+    Rapidhash,
+}
+
+impl Algorithm {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "rapidhash" => Some(Self::Rapidhash),
+            _ => None,
+        }
+    }
+
+    /// The name that labels hashes from this variant in the output.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Rapidhash => "rapidhash",
+        }
+    }
+
+    fn hash_bytes(self, bytes: &[u8], seed: u64) -> u64 {
+        match self {
+            Self::Rapidhash => rapidhash::rapidhash_seeded(bytes, seed),
+        }
+    }
+
+    fn hash_stream(self, reader: impl Read, len: u64, seed: u64) -> std::io::Result<u64> {
+        match self {
+            Self::Rapidhash => rapidhash::rapidhash_stream_seeded(reader, len, seed),
+        }
+    }
+}
+
+/// The hash and size of a single input.
+struct Hashed {
+    hash: u64,
+    size: u64,
+}
+
+/// Hash two inputs and print both hashes, mirroring `cmp` exit codes: 0 if the hashes are equal,
+/// 1 if they differ, and 2 if either input could not be hashed.
+///
+/// With `--string`, the two arguments are compared as literal strings.
+fn compare(options: &Options) -> ExitCode {
+    let mut output = Output::new(options);
+    let mut hashes = Vec::with_capacity(2);
+
+    for input in &options.files {
+        let hashed = if options.string {
+            Ok(Hashed { hash: options.algorithm.hash_bytes(input.as_bytes(), options.seed), size: input.len() as u64 })
+        } else {
+            hash_input(Path::new(input), options.algorithm, options.seed)
+        };
+
+        match hashed {
+            Ok(hashed) => {
+                output.record(input, hashed.size, hashed.hash);
+                hashes.push(hashed.hash);
+            }
+            Err(err) => eprintln!("rapidhash: {input}: {err}"),
         }
+    }
+    output.finish();
+
+    match hashes.as_slice() {
+        [a, b] if a == b => ExitCode::SUCCESS,
+        [_, _] => ExitCode::from(1),
+        _ => ExitCode::from(2),
+    }
+}
+
+/// Find groups of duplicate files under the input paths, printing each group as `HASH  FILENAME`
+/// lines with a blank line between groups.
+///
+/// Directories are always walked. Files are first grouped by size with
+/// [rapidhash::duplicate_candidates], so only files that share a size with another file are read.
+/// With `--verify-bytes`, files sharing a hash are then compared byte for byte, so that a hash
+/// collision is never reported as a duplicate.
+///
+/// Mirrors the `dupes` example's exit codes: 0 if no duplicates were found, 1 if some were, and 2
+/// if any file could not be read.
+fn dedupe(options: &Options) -> ExitCode {
+    let mut failed = false;
+    let files: Vec<PathBuf> = match rapidhash::duplicate_candidates(&options.files) {
+        Ok(candidates) => candidates.into_iter().flatten().collect(),
+        Err(err) => {
+            eprintln!("rapidhash: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    // candidates are sorted, so each group's paths stay sorted
+    let mut by_hash: BTreeMap<(u64, u64), Vec<PathBuf>> = BTreeMap::new();
+    for (file, result) in files.iter().zip(hash_all(&files, options.algorithm, options.seed, options.jobs)) {
+        match result {
+            Ok(hashed) => by_hash.entry((hashed.size, hashed.hash)).or_default().push(file.clone()),
+            Err(err) => {
+                eprintln!("rapidhash: {}: {err}", file.display());
+                failed = true;
+            }
+        }
+    }
+
+    let mut groups = Vec::new();
+    for ((_, hash), paths) in by_hash {
+        if !options.verify_bytes {
+            groups.push((hash, paths));
+            continue;
+        }
+        match split_by_contents(paths) {
+            Ok(split) => groups.extend(split.into_iter().map(|paths| (hash, paths))),
+            Err(err) => {
+                eprintln!("rapidhash: {err}");
+                failed = true;
+            }
+        }
+    }
+    groups.retain(|(_, paths)| paths.len() > 1);
+    groups.sort_unstable_by(|a, b| a.1[0].cmp(&b.1[0]));
+
+    for (i, (hash, paths)) in groups.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        for path in paths {
+            println!("{}", format_line(*hash, &path.display().to_string(), options));
+        }
+    }
+
+    if failed {
+        ExitCode::from(2)
+    } else if groups.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
+
+/// Split files with the same hash into groups with identical bytes, keeping the order of `paths`.
+fn split_by_contents(paths: Vec<PathBuf>) -> std::io::Result<Vec<Vec<PathBuf>>> {
+    let mut groups: Vec<Vec<PathBuf>> = Vec::new();
+    for path in paths {
+        let mut matched = None;
+        for (index, group) in groups.iter().enumerate() {
+            if same_contents(&group[0], &path)? {
+                matched = Some(index);
+                break;
+            }
+        }
+        match matched {
+            Some(index) => groups[index].push(path),
+            None => groups.push(vec![path]),
+        }
+    }
+    Ok(groups)
+}
+
+/// Whether two files have exactly the same bytes.
+fn same_contents(a: &Path, b: &Path) -> std::io::Result<bool> {
+    let open = |path: &Path| {
+        std::fs::File::open(path)
+            .map(BufReader::new)
+            .map_err(|err| std::io::Error::new(err.kind(), format!("{}: {err}", path.display())))
+    };
+    let (mut a, mut b) = (open(a)?, open(b)?);
+
+    loop {
+        let (left, right) = (a.fill_buf()?, b.fill_buf()?);
+        let len = left.len().min(right.len());
+        if len == 0 {
+            return Ok(left.len() == right.len());
+        }
+        if left[..len] != right[..len] {
+            return Ok(false);
+        }
+        a.consume(len);
+        b.consume(len);
+    }
+}
+
+/// Hash the input directory with [rapidhash::rapidhash_dir_seeded] and compare it to the expected
+/// root, printing `DIR: OK` or `DIR: FAILED` with the computed root.
+///
+/// Exits with a failure code if the roots differ or the directory can't be read.
+fn verify_root(expected: u64, options: &Options) -> ExitCode {
+    let dir = &options.files[0];
+    match rapidhash::rapidhash_dir_seeded(dir, options.seed) {
+        Ok(root) if root == expected => {
+            println!("{dir}: OK");
+            ExitCode::SUCCESS
+        }
+        Ok(root) => {
+            println!("{dir}: FAILED, root is {}", options.format.encode(root));
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("rapidhash: {dir}: {err}");
+            println!("{dir}: FAILED open or read");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Expand the input arguments into a list of files, walking directories when `recursive` is set.
+///
+/// Files found in a directory are sorted so the output is deterministic. Unreadable directories are
+/// reported to stderr and set the exit code to failure.
+fn expand_inputs(inputs: &[String], recursive: bool, exit: &mut ExitCode) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for input in inputs {
+        let path = PathBuf::from(input);
+        if recursive && input != "-" && path.is_dir() {
+            let mut found = Vec::new();
+            walk_dir(&path, &mut found, exit);
+            found.sort();
+            files.extend(found);
+        } else {
+            files.push(path);
+        }
+    }
+    files
+}
+
+/// Recursively collect every file under `dir`.
+fn walk_dir(dir: &Path, files: &mut Vec<PathBuf>, exit: &mut ExitCode) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            eprintln!("rapidhash: {}: {err}", dir.display());
+            *exit = ExitCode::FAILURE;
+            return;
+        }
+    };
+
+    for entry in entries {
+        match entry.and_then(|entry| Ok((entry.path(), entry.file_type()?))) {
+            Ok((path, file_type)) if file_type.is_dir() => walk_dir(&path, files, exit),
+            Ok((path, _)) => files.push(path),
+            Err(err) => {
+                eprintln!("rapidhash: {}: {err}", dir.display());
+                *exit = ExitCode::FAILURE;
+            }
+        }
+    }
+}
+
+/// Hash every file using up to `jobs` worker threads, returning the results in input order.
+fn hash_all(files: &[PathBuf], algorithm: Algorithm, seed: u64, jobs: usize) -> Vec<std::io::Result<Hashed>> {
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<std::io::Result<Hashed>>>> = files.iter().map(|_| Mutex::new(None)).collect();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(files.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(file) = files.get(index) else {
+                    break;
+                };
+                let result = hash_input(file, algorithm, seed);
+                *results[index].lock().unwrap() = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.into_inner().unwrap().expect("every file is hashed"))
+        .collect()
+}
+
+/// Hash a file, or stdin when the path is `-`, streaming it in fixed-size chunks.
+fn hash_input(path: &Path, algorithm: Algorithm, seed: u64) -> std::io::Result<Hashed> {
+    if path == Path::new("-") {
+        return hash_stdin(algorithm, seed);
+    }
+
+    let file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    hash_reader(file, len, algorithm, seed)
+}
+
+/// Hash stdin in constant memory.
+///
+/// rapidhash mixes the total input length into the seed before reading any data, so an input of
+/// unknown length (such as a pipe) is first spooled to a temporary file in fixed-size chunks, and
+/// then streamed from there.
+fn hash_stdin(algorithm: Algorithm, seed: u64) -> std::io::Result<Hashed> {
+    // std's RandomState is seeded by the OS, so the name can't be predicted by other users of a
+    // shared temp dir, and every `-` argument gets its own spool
+    let random = RandomState::new().build_hasher().finish();
+    let path = std::env::temp_dir().join(format!("rapidhash-stdin-{}-{random:016x}", std::process::id()));
+    let mut spool = std::fs::File::options().read(true).write(true).create_new(true).open(&path)?;
+
+    // only reached once the spool is ours, so a file that already existed is never removed
+    let result = (|| {
+        let len = std::io::copy(&mut std::io::stdin().lock(), &mut spool)?;
+        spool.seek(SeekFrom::Start(0))?;
+        hash_reader(&mut spool, len, algorithm, seed)
+    })();
+
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+/// Stream exactly `len` bytes from a reader through the selected [Algorithm], checking the input did
+/// not change size while hashing.
+fn hash_reader(mut reader: impl Read, len: u64, algorithm: Algorithm, seed: u64) -> std::io::Result<Hashed> {
+    let hash = algorithm.hash_stream(&mut reader, len, seed).map_err(|err| match err.kind() {
+        ErrorKind::UnexpectedEof => std::io::Error::new(ErrorKind::UnexpectedEof, "file shrank while hashing"),
+        _ => err,
+    })?;
+
+    if reader.read(&mut [0u8; 1])? != 0 {
+        return Err(std::io::Error::new(ErrorKind::Other, "file grew while hashing"));
+    }
+
+    Ok(Hashed { hash, size: len })
+}
+
+/// Format a single output line in GNU (`HASH  FILENAME`) or BSD (`--tag`) style.
+fn format_line(hash: u64, file: &str, options: &Options) -> String {
+    let style = if options.tag { LineStyle::Bsd } else { LineStyle::Gnu };
+    manifest::format_line(hash, file, options.format, style)
+}
+
+/// Re-hash every file listed in a manifest, mirroring `sha256sum --check`.
+///
+/// Prints `FILENAME: OK` or `FILENAME: FAILED` for each entry, and exits with a failure code if any
+/// file is missing, unreadable, or does not match. The manifest must use the same `--algorithm`,
+/// `--seed`, and `--format` options it was generated with.
+fn check_manifest(manifest: &str, options: &Options) -> ExitCode {
+    let contents = if manifest == "-" {
+        let mut contents = String::new();
+        std::io::stdin().read_to_string(&mut contents).map(|_| contents)
+    } else {
+        std::fs::read_to_string(manifest)
     };
 
-    let hash = rapidhash::rapidhash(&buffer);
-    println!("{hash}");
+    let contents = match contents {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("rapidhash: {manifest}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut malformed = 0;
+    let mut unreadable = 0;
+    let mut mismatched = 0;
+    let mut checked = 0;
+
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let Some(entry) = manifest::parse_line(line, options.format) else {
+            malformed += 1;
+            continue;
+        };
+        let (expected, file) = (entry.hash, entry.path.as_str());
+
+        checked += 1;
+        match hash_input(Path::new(file), options.algorithm, options.seed) {
+            Ok(hashed) if hashed.hash == expected => println!("{file}: OK"),
+            Ok(_) => {
+                println!("{file}: FAILED");
+                mismatched += 1;
+            }
+            Err(err) => {
+                eprintln!("rapidhash: {file}: {err}");
+                println!("{file}: FAILED open or read");
+                unreadable += 1;
+            }
+        }
+    }
+
+    if malformed > 0 {
+        eprintln!("rapidhash: WARNING: {malformed} line(s) are improperly formatted");
+    }
+    if unreadable > 0 {
+        eprintln!("rapidhash: WARNING: {unreadable} listed file(s) could not be read");
+    }
+    if mismatched > 0 {
+        eprintln!("rapidhash: WARNING: {mismatched} computed checksum(s) did NOT match");
+    }
+    if checked == 0 {
+        eprintln!("rapidhash: {manifest}: no properly formatted checksum lines found");
+        return ExitCode::FAILURE;
+    }
+
+    if unreadable > 0 || mismatched > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Output style for hashed records.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// Checksum-style `HASH  FILENAME` lines, or BSD-style lines with `--tag`.
+    Text,
+    /// A JSON array of `{"path", "size", "algorithm", "seed", "hash"}` objects.
+    Json,
+    /// CSV with a `path,size,algorithm,seed,hash` header row.
+    Csv,
+}
+
+impl OutputFormat {
+    fn parse(output: &str) -> Option<Self> {
+        match output {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Prints records in the selected [OutputFormat] as they are produced.
+struct Output<'a> {
+    options: &'a Options,
+    count: usize,
+}
+
+impl<'a> Output<'a> {
+    fn new(options: &'a Options) -> Self {
+        Self { options, count: 0 }
+    }
+
+    fn record(&mut self, path: &str, size: u64, hash: u64) {
+        let seed = self.options.seed;
+        let algorithm = self.options.algorithm.name();
+        let encoded = self.options.format.encode(hash);
+
+        match self.options.output {
+            OutputFormat::Text => println!("{}", format_line(hash, path, self.options)),
+            OutputFormat::Json => {
+                // seeds and hashes are strings, as JSON numbers lose precision above 2^53
+                let separator = if self.count == 0 { "[" } else { "," };
+                println!(
+                    "{separator}{{\"path\":{},\"size\":{size},\"algorithm\":\"{algorithm}\",\"seed\":\"{seed}\",\"hash\":\"{encoded}\"}}",
+                    json_string(path),
+                );
+            }
+            OutputFormat::Csv => {
+                if self.count == 0 {
+                    println!("path,size,algorithm,seed,hash");
+                }
+                println!("{},{size},{algorithm},{seed},{encoded}", csv_field(path));
+            }
+        }
+
+        self.count += 1;
+    }
+
+    fn finish(self) {
+        match self.options.output {
+            OutputFormat::Json if self.count == 0 => println!("[]"),
+            OutputFormat::Json => println!("]"),
+            OutputFormat::Csv if self.count == 0 => println!("path,size,algorithm,seed,hash"),
+            _ => {}
+        }
+    }
+}
+
+/// Quote and escape a string for JSON output.
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
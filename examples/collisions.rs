@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+use std::process::ExitCode;
+
+/// Scan a dataset of keys for 64-bit rapidhash collisions, and report how evenly the hashes spread
+/// over the buckets of a hash table with a given capacity.
+///
+/// # Usage
+/// Reads one key per line from a file, or from stdin when no file is given. Trailing `\n` and
+/// `\r\n` line endings are not part of the key, and repeated keys are only counted once.
+/// ```shell
+/// seq 1 1000000 | sed 's/^/user-/' > keys.txt
+/// cargo run --release --example collisions -- --capacity 1000000 keys.txt
+/// keys:            1000000 (0 repeated)
+/// collisions:      0
+/// buckets:         1048576
+/// empty buckets:   404315 (expected 404040)
+/// longest bucket:  9 (expected 8 to 9)
+/// chi-squared:     1050251.3 (expected 1048575 ± 1448)
+/// ```
+///
+/// The buckets mirror `std::collections::HashMap` and hashbrown: the capacity is rounded up to a
+/// power of two, and each hash is placed in the bucket given by its low bits. The expected values
+/// are those of a uniformly random hash. A chi-squared statistic more than a few of its standard
+/// deviations away from the expected value means the keys are spread unevenly.
+///
+/// Options:
+/// - `--capacity N`: the number of buckets before rounding, defaulting to the number of keys.
+/// - `--seed SEED`: hash with a custom seed.
+///
+/// Exits with `1` if any collisions were found, and `2` on errors.
+fn main() -> ExitCode {
+    let (capacity, seed, path) = match parse_args() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("collisions: {err}");
+            eprintln!("Usage: collisions [--capacity N] [--seed SEED] [FILE]");
+            return ExitCode::from(2);
+        }
+    };
+
+    let reader: Box<dyn Read> = match &path {
+        Some(path) => match std::fs::File::open(path) {
+            Ok(file) => Box::new(file),
+            Err(err) => {
+                eprintln!("collisions: {path}: {err}");
+                return ExitCode::from(2);
+            }
+        },
+        None => Box::new(std::io::stdin()),
+    };
+
+    let mut keys: HashMap<u64, Vec<u8>> = HashMap::new();
+    let mut repeated = 0u64;
+    let mut collisions = Vec::new();
+
+    let mut reader = BufReader::new(reader);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("collisions: {err}");
+                return ExitCode::from(2);
+            }
+        }
+
+        let key = trim_line_ending(&line);
+        let hash = rapidhash::rapidhash_seeded(key, seed);
+        match keys.get(&hash) {
+            Some(existing) if existing == key => repeated += 1,
+            Some(existing) => collisions.push((hash, existing.clone(), key.to_vec())),
+            None => {
+                keys.insert(hash, key.to_vec());
+            }
+        }
+    }
+
+    for (hash, a, b) in &collisions {
+        println!("collision {hash}: {:?} and {:?}", String::from_utf8_lossy(a), String::from_utf8_lossy(b));
+    }
+
+    let count = keys.len() as u64;
+    println!("keys:            {count} ({repeated} repeated)");
+    println!("collisions:      {}", collisions.len());
+    report_buckets(keys.keys().copied(), count, capacity.unwrap_or(count));
+
+    match collisions.is_empty() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::from(1),
+    }
+}
+
+/// Parse the `--capacity`, `--seed`, and optional `FILE` arguments.
+fn parse_args() -> Result<(Option<u64>, u64, Option<String>), String> {
+    let mut capacity = None;
+    let mut seed = rapidhash::RAPID_SEED;
+    let mut path = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--capacity" => {
+                let value = args.next().ok_or("option '--capacity' requires a number N")?;
+                capacity = Some(value.parse().ok().filter(|&n| n > 0).ok_or(format!("invalid capacity '{value}'"))?);
+            }
+            "--seed" => {
+                let value = args.next().ok_or("option '--seed' requires a SEED")?;
+                seed = value.parse().map_err(|_| format!("invalid seed '{value}'"))?;
+            }
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => return Err(format!("unexpected argument '{arg}'")),
+        }
+    }
+
+    Ok((capacity, seed, path))
+}
+
+fn trim_line_ending(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+/// Place every hash in one of `capacity.next_power_of_two()` buckets by its low bits, and print the
+/// bucket statistics next to their expected values for uniformly random hashes.
+fn report_buckets(hashes: impl Iterator<Item = u64>, count: u64, capacity: u64) {
+    let buckets = capacity.max(1).next_power_of_two();
+    let mut loads = vec![0u32; buckets as usize];
+    for hash in hashes {
+        loads[(hash & (buckets - 1)) as usize] += 1;
+    }
+
+    let n = buckets as f64;
+    let mean = count as f64 / n;
+    let empty = loads.iter().filter(|&&load| load == 0).count();
+    let longest = loads.iter().copied().max().unwrap_or(0);
+    let chi_squared: f64 = match count {
+        0 => 0.0,
+        _ => loads.iter().map(|&load| (load as f64 - mean).powi(2) / mean).sum(),
+    };
+
+    println!("buckets:         {buckets}");
+    println!("empty buckets:   {empty} (expected {:.0})", n * (-mean).exp());
+    match expected_longest(mean, n) {
+        Some(load) => println!("longest bucket:  {longest} (expected {load} to {})", load + 1),
+        None => println!("longest bucket:  {longest}"),
+    }
+    println!("chi-squared:     {chi_squared:.1} (expected {:.0} ± {:.0})", n - 1.0, (2.0 * (n - 1.0)).sqrt());
+}
+
+/// The usual longest of `n` buckets with Poisson distributed loads: the smallest load that fewer
+/// than one bucket is expected to exceed. `None` if the mean load is too large to estimate it.
+fn expected_longest(mean: f64, n: f64) -> Option<u32> {
+    let (mut load, mut term, mut tail) = (0u32, (-mean).exp(), 1.0);
+    if term == 0.0 {
+        return None;
+    }
+    while tail - term > 1.0 / n {
+        tail -= term;
+        load += 1;
+        term *= mean / load as f64;
+    }
+    Some(load)
+}
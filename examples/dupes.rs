@@ -0,0 +1,44 @@
+use std::process::ExitCode;
+
+/// Find duplicate files with [rapidhash::find_duplicates].
+///
+/// # Usage
+/// Print each group of duplicates, separated by blank lines:
+/// ```shell
+/// cargo run --release --example dupes -- photos/ backup/photos/
+/// 2048 bytes, hash 8543579700415218186:
+///   backup/photos/a.jpg
+///   photos/a.jpg
+/// ```
+///
+/// Exits with `1` if any duplicates were found, and `2` on errors.
+fn main() -> ExitCode {
+    let paths: Vec<String> = std::env::args().skip(1).collect();
+    if paths.is_empty() {
+        eprintln!("Usage: dupes <path>...");
+        return ExitCode::from(2);
+    }
+
+    let groups = match rapidhash::find_duplicates(&paths) {
+        Ok(groups) => groups,
+        Err(err) => {
+            eprintln!("Failed to find duplicates: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    for (i, group) in groups.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{} bytes, hash {}:", group.len, group.hash);
+        for path in &group.paths {
+            println!("  {}", path.display());
+        }
+    }
+
+    match groups.is_empty() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::from(1),
+    }
+}
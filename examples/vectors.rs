@@ -0,0 +1,76 @@
+use std::process::ExitCode;
+
+/// Emit a JSON file of rapidhash test vectors, to validate implementations in other languages
+/// against this crate.
+///
+/// # Usage
+/// Each vector hashes the first `len` bytes of the [rapidhash::test_vector_input] pattern, for
+/// every length from 0 to 128 bytes and the boundaries of the 96-byte loop up to 1024 bytes. The
+/// input bytes are included as hex, so the pattern does not need to be reimplemented. Seeds and
+/// hashes are decimal strings, as JSON numbers lose precision above 2^53.
+/// ```shell
+/// cargo run --example vectors --features test-vectors -- --seed 42
+/// {"pattern":"(i * 31 + 7) % 251","vectors":[
+/// {"len":0,"seed":"0","input":"","hash":"10602188539874428322"}
+/// ,{"len":1,"seed":"0","input":"07","hash":"1180302412105480581"}
+/// ...
+/// ]}
+/// ```
+///
+/// The seeds `0`, [rapidhash::RAPID_SEED], and `0x0123456789abcdef` are always included, and
+/// `--seed SEED` adds a custom seed, in decimal or 0x-prefixed hex. It may be repeated.
+///
+/// The reference vectors are checked first with [rapidhash::verify_reference_vectors], so vectors
+/// are never emitted from a build that disagrees with them. The 48-byte vectors are this crate's
+/// output, which differs from the C implementation, see [rapidhash::REFERENCE_VECTORS].
+fn main() -> ExitCode {
+    let mut seeds = vec![0, rapidhash::RAPID_SEED, 0x0123456789abcdef];
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let seed = match arg.as_str() {
+            "--seed" => args.next().and_then(|seed| parse_seed(&seed)),
+            _ => None,
+        };
+        match seed {
+            Some(seed) => seeds.push(seed),
+            None => {
+                eprintln!("Usage: vectors [--seed SEED]...");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if let Err(mismatch) = rapidhash::verify_reference_vectors() {
+        eprintln!("vectors: {mismatch}");
+        return ExitCode::FAILURE;
+    }
+
+    let lens = (0..=128).chain([191, 192, 193, 255, 256, 257, 511, 512, 513, 1000, 1024]);
+    let input: Vec<u8> = (0..1024).map(rapidhash::test_vector_input).collect();
+
+    println!("{{\"pattern\":\"(i * 31 + 7) % 251\",\"vectors\":[");
+    let mut separator = "";
+    for &seed in &seeds {
+        for len in lens.clone() {
+            let data = &input[..len];
+            let hash = rapidhash::rapidhash_seeded(data, seed);
+            println!("{separator}{{\"len\":{len},\"seed\":\"{seed}\",\"input\":\"{}\",\"hash\":\"{hash}\"}}", hex(data));
+            separator = ",";
+        }
+    }
+    println!("]}}");
+
+    ExitCode::SUCCESS
+}
+
+/// Parse a seed as either a decimal `u64` or a `0x`-prefixed hexadecimal `u64`.
+fn parse_seed(seed: &str) -> Option<u64> {
+    match seed.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => seed.parse().ok(),
+    }
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,34 @@
+//! Compiles the C reference rapidhash for the `c_reference` fuzz target, when the `c-reference`
+//! feature is enabled.
+
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rerun-if-changed=c/shim.c");
+    println!("cargo:rerun-if-env-changed=RAPIDHASH_C_DIR");
+
+    if std::env::var_os("CARGO_FEATURE_C_REFERENCE").is_none() {
+        return;
+    }
+
+    // the upstream header is not vendored, see the fuzz README for where to put it
+    let dir = std::env::var_os("RAPIDHASH_C_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("c"));
+    let header = dir.join("rapidhash.h");
+    println!("cargo:rerun-if-changed={}", header.display());
+
+    if !header.exists() {
+        panic!(
+            "{} not found. Download rapidhash.h (V1) from https://github.com/Nicoshev/rapidhash \
+            into fuzz/c/ or set RAPIDHASH_C_DIR to its directory.",
+            header.display(),
+        );
+    }
+
+    cc::Build::new()
+        .file("c/shim.c")
+        .include(dir)
+        .opt_level(2)
+        .compile("rapidhash_c_reference");
+}
@@ -0,0 +1,8 @@
+// Exposes the C reference implementation under a name that does not clash with the Rust exports.
+#include <stddef.h>
+#include <stdint.h>
+#include "rapidhash.h"
+
+uint64_t rapidhash_c_reference(const void *key, size_t len, uint64_t seed) {
+    return rapidhash_withSeed(key, len, seed);
+}
@@ -0,0 +1,33 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // the first byte picks how many of the following bytes are chunk sizes, and the rest is the input
+    let Some((&control, rest)) = data.split_first() else {
+        return;
+    };
+    let (sizes, input) = rest.split_at((control as usize % 16 + 1).min(rest.len()));
+
+    let mut hasher = rapidhash::RapidStreamHasher::new_seeded(input.len() as u64, control as u64);
+    let mut remaining = input;
+    for &size in sizes.iter().cycle() {
+        if remaining.is_empty() {
+            break;
+        }
+
+        // empty writes must be no-ops, but always make progress to avoid looping forever
+        if size == 0 {
+            hasher.write(&[]);
+        }
+
+        // scale the chunk sizes so inputs can span multiple 96-byte blocks in one write
+        let size = (size as usize * (1 + control as usize % 4)).clamp(1, remaining.len());
+        let (chunk, tail) = remaining.split_at(size);
+        hasher.write(chunk);
+        remaining = tail;
+    }
+    hasher.write(remaining);
+
+    assert_eq!(hasher.finish(), rapidhash::rapidhash_seeded(input, control as u64));
+});
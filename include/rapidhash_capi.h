@@ -0,0 +1,80 @@
+#ifndef RAPIDHASH_CAPI_H
+#define RAPIDHASH_CAPI_H
+
+/* Generated by cbindgen from src/capi.rs, do not edit by hand. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// An opaque streaming hasher state, created with `rapidhash_stream_init`.
+typedef struct RapidhashStream RapidhashStream;
+
+// Hash `len` bytes at `key` with the default seed.
+//
+// # Safety
+// `key` must be valid for reads of `len` bytes, or `len` must be zero.
+uint64_t rapidhash(const uint8_t *key, size_t len);
+
+// Hash `len` bytes at `key` with a custom seed.
+//
+// # Safety
+// `key` must be valid for reads of `len` bytes, or `len` must be zero.
+uint64_t rapidhash_seeded(const uint8_t *key, size_t len, uint64_t seed);
+
+// Allocate a new streaming hasher for an input of exactly `total_len` bytes, which will produce
+// the same hash as `rapidhash_seeded` of the full input.
+//
+// The returned state must be released with `rapidhash_stream_free`.
+struct RapidhashStream *rapidhash_stream_init(uint64_t total_len, uint64_t seed);
+
+// Write the next `len` bytes at `key` to the streaming hasher.
+//
+// Returns `-1` without consuming any input if `state` is null, or if this would write more than
+// the total length declared in `rapidhash_stream_init`.
+//
+// # Safety
+// `state` must be null or returned by `rapidhash_stream_init` and not yet freed. `key` must be
+// valid for reads of `len` bytes, or `len` must be zero.
+int32_t rapidhash_stream_update(struct RapidhashStream *state, const uint8_t *key, size_t len);
+
+// Write the final hash to `out`.
+//
+// Returns `-1` without writing to `out` if `state` or `out` is null, or if fewer bytes have been
+// written than the total length declared in `rapidhash_stream_init`.
+//
+// # Safety
+// `state` must be null or returned by `rapidhash_stream_init` and not yet freed. `out` must be
+// null or valid for writes.
+int32_t rapidhash_stream_finish(const struct RapidhashStream *state, uint64_t *out);
+
+// Release a streaming hasher. Passing null is a no-op.
+//
+// # Safety
+// `state` must be null or returned by `rapidhash_stream_init` and not yet freed.
+void rapidhash_stream_free(struct RapidhashStream *state);
+
+#if defined(RAPIDHASH_SMHASHER)
+// SMHasher3 entry point: hash `len` bytes at `input` with a 64-bit seed, writing the 64-bit hash
+// to `out` in native byte order.
+//
+// Requires the `smhasher` feature.
+//
+// # Safety
+// `input` must be valid for reads of `len` bytes, or `len` must be zero. `out` must be valid for
+// writes of 8 bytes, and need not be aligned.
+void rapidhash_smhasher3(const void *input, size_t len, uint64_t seed, void *out);
+#endif
+
+#if defined(RAPIDHASH_SMHASHER)
+// Original SMHasher entry point: hash `len` bytes at `input` with a 32-bit seed, writing the
+// 64-bit hash to `out` in native byte order.
+//
+// Requires the `smhasher` feature.
+//
+// # Safety
+// `input` must be valid for reads of `len` bytes, or `len` must not be positive. `out` must be
+// valid for writes of 8 bytes, and need not be aligned.
+void rapidhash_smhasher(const void *input, int32_t len, uint32_t seed, void *out);
+#endif
+
+#endif  /* RAPIDHASH_CAPI_H */
@@ -5,30 +5,259 @@
 #![cfg_attr(docsrs, feature(doc_cfg_hide))]
 #![cfg_attr(docsrs, doc(cfg_hide(docsrs)))]
 
-#[deny(missing_docs)]
-#[deny(unused_must_use)]
-
+#![deny(missing_docs)]
+#![deny(unused_must_use)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(any(feature = "capi", docsrs))]
+pub mod capi;
+#[cfg(any(feature = "std", docsrs))]
+pub mod manifest;
+#[cfg(any(feature = "quality", docsrs))]
+pub mod quality;
+pub mod raw;
+#[cfg(any(feature = "random-state", docsrs))]
+mod rapid_adaptive;
+#[cfg(any(feature = "arrow", docsrs))]
+mod rapid_arrow;
+mod rapid_ascii;
+#[cfg(any(feature = "hasher", docsrs))]
+mod rapid_auto;
+mod rapid_batch;
+mod rapid_block;
+#[cfg(any(feature = "bytes", docsrs))]
+mod rapid_bytes;
+mod rapid_cdc;
+#[cfg(any(feature = "std", docsrs))]
+mod rapid_checksum;
+#[cfg(any(feature = "std", docsrs))]
+mod rapid_chunked;
+#[cfg(any(feature = "tokio-codec", docsrs))]
+mod rapid_codec;
 mod rapid_const;
+mod rapid_core;
+#[cfg(any(feature = "std", docsrs))]
+mod rapid_dupes;
+#[cfg(any(feature = "rng", docsrs))]
+mod rapid_entropy;
+#[cfg(any(feature = "std", docsrs))]
+mod rapid_external;
+mod rapid_float;
+#[cfg(any(feature = "hasher", docsrs))]
+mod rapid_global_seed;
+#[cfg(any(feature = "hashbrown", docsrs))]
+mod rapid_hashbrown;
+#[cfg(any(feature = "hasher", docsrs))]
+mod rapid_hashed;
+#[cfg(any(all(feature = "std", feature = "hasher"), docsrs))]
+mod rapid_heavy;
+#[cfg(any(feature = "hasher", docsrs))]
 mod rapid_hasher;
+#[cfg(any(feature = "hasher", docsrs))]
+mod rapid_hasher_array;
+#[cfg(any(feature = "hasher", docsrs))]
+mod rapid_hasher_ascii;
+#[cfg(any(feature = "hasher", docsrs))]
+mod rapid_hasher_buffered;
+#[cfg(any(feature = "hasher", docsrs))]
 mod rapid_hasher_inline;
-#[cfg(any(feature = "std", feature = "rand", docsrs))]
+#[cfg(any(feature = "hasher", docsrs))]
+mod rapid_hasher_int;
+#[cfg(any(all(feature = "alloc", feature = "hasher"), docsrs))]
+mod rapid_hll;
+mod rapid_hash_one;
+mod rapid_id;
+#[cfg(any(feature = "std", docsrs))]
+mod rapid_io;
+mod rapid_iter;
+#[cfg(any(feature = "hasher", docsrs))]
+mod rapid_key;
+#[cfg(any(all(feature = "std", feature = "hasher"), docsrs))]
+mod rapid_map_batch;
+#[cfg(any(all(feature = "std", feature = "hasher"), docsrs))]
+mod rapid_map_hash;
+#[cfg(any(feature = "bytemuck", docsrs))]
+mod rapid_pod;
+mod rapid_range;
+#[cfg(any(all(feature = "std", feature = "rng"), docsrs))]
+mod rapid_reservoir;
+#[cfg(any(feature = "alloc", docsrs))]
+mod rapid_ring;
+#[cfg(any(feature = "alloc", docsrs))]
+mod rapid_ring_bounded;
+mod rapid_seed;
+#[cfg(any(all(feature = "std", feature = "hasher"), docsrs))]
+mod rapid_sharded;
+mod rapid_simhash;
+mod rapid_slices;
+mod rapid_stream;
+mod rapid_tagged;
+#[cfg(any(all(feature = "alloc", feature = "hasher"), docsrs))]
+mod rapid_trace;
+#[cfg(any(feature = "std", docsrs))]
+mod rapid_tree;
+mod rapid_uuid;
+#[cfg(any(feature = "test-vectors", docsrs))]
+mod rapid_vectors;
+#[cfg(any(feature = "random-state", docsrs))]
 mod random_state;
+#[cfg(any(feature = "rng", docsrs))]
 mod rng;
 
 #[doc(inline)]
-pub use crate::rapid_const::{rapidhash, rapidhash_inline, rapidhash_seeded, RAPID_SEED};
+#[cfg(any(feature = "random-state", docsrs))]
+pub use crate::rapid_adaptive::*;
+#[doc(inline)]
+#[cfg(any(feature = "arrow", docsrs))]
+pub use crate::rapid_arrow::*;
+
+#[doc(inline)]
+pub use crate::rapid_ascii::*;
+
+#[doc(inline)]
+#[cfg(any(feature = "hasher", docsrs))]
+pub use crate::rapid_auto::*;
+#[doc(inline)]
+pub use crate::rapid_batch::*;
+#[doc(inline)]
+pub use crate::rapid_block::*;
+#[doc(inline)]
+#[cfg(any(feature = "bytes", docsrs))]
+pub use crate::rapid_bytes::*;
+#[doc(inline)]
+pub use crate::rapid_cdc::*;
+#[doc(inline)]
+#[cfg(any(feature = "std", docsrs))]
+pub use crate::rapid_checksum::*;
+#[doc(inline)]
+#[cfg(any(feature = "std", docsrs))]
+pub use crate::rapid_chunked::*;
+#[doc(inline)]
+#[cfg(any(feature = "tokio-codec", docsrs))]
+pub use crate::rapid_codec::*;
+#[doc(inline)]
+pub use crate::rapid_const::{
+    rapidhash,
+    rapidhash_array,
+    rapidhash_array_seeded,
+    rapidhash_inline,
+    rapidhash_seeded,
+    RAPID_SEED,
+};
+#[doc(inline)]
+#[cfg(any(feature = "std", docsrs))]
+pub use crate::rapid_dupes::*;
+#[doc(inline)]
+#[cfg(any(feature = "rng", docsrs))]
+pub use crate::rapid_entropy::*;
+
+#[doc(inline)]
+#[cfg(any(feature = "std", docsrs))]
+pub use crate::rapid_external::*;
+#[doc(inline)]
+pub use crate::rapid_float::*;
+#[doc(inline)]
+#[cfg(any(all(feature = "hasher", feature = "std"), docsrs))]
+pub use crate::rapid_global_seed::{global_seed, set_global_seed};
+#[doc(inline)]
+pub use crate::rapid_hash_one::*;
+#[doc(inline)]
+#[cfg(any(feature = "hashbrown", docsrs))]
+pub use crate::rapid_hashbrown::*;
+
+#[doc(inline)]
+#[cfg(any(feature = "hasher", docsrs))]
+pub use crate::rapid_hashed::*;
+#[doc(inline)]
+#[cfg(any(all(feature = "std", feature = "hasher"), docsrs))]
+pub use crate::rapid_heavy::*;
 #[doc(inline)]
+#[cfg(any(feature = "hasher", docsrs))]
 pub use crate::rapid_hasher::*;
 #[doc(inline)]
+#[cfg(any(feature = "hasher", docsrs))]
+pub use crate::rapid_hasher_array::*;
+
+#[doc(inline)]
+#[cfg(any(feature = "hasher", docsrs))]
+pub use crate::rapid_hasher_ascii::*;
+#[doc(inline)]
+#[cfg(any(feature = "hasher", docsrs))]
+pub use crate::rapid_hasher_buffered::*;
+#[doc(inline)]
+#[cfg(any(feature = "hasher", docsrs))]
 pub use crate::rapid_hasher_inline::*;
 #[doc(inline)]
-#[cfg(any(feature = "std", feature = "rand", docsrs))]
+#[cfg(any(feature = "hasher", docsrs))]
+pub use crate::rapid_hasher_int::*;
+#[doc(inline)]
+#[cfg(any(all(feature = "alloc", feature = "hasher"), docsrs))]
+pub use crate::rapid_hll::*;
+#[doc(inline)]
+pub use crate::rapid_id::*;
+#[doc(inline)]
+#[cfg(any(feature = "std", docsrs))]
+pub use crate::rapid_io::*;
+#[doc(inline)]
+pub use crate::rapid_iter::*;
+#[doc(inline)]
+#[cfg(any(feature = "hasher", docsrs))]
+pub use crate::rapid_key::*;
+#[doc(inline)]
+#[cfg(any(all(feature = "std", feature = "hasher"), docsrs))]
+pub use crate::rapid_map_batch::*;
+#[doc(inline)]
+#[cfg(any(all(feature = "std", feature = "hasher"), docsrs))]
+pub use crate::rapid_map_hash::*;
+#[doc(inline)]
+#[cfg(any(feature = "bytemuck", docsrs))]
+pub use crate::rapid_pod::*;
+#[doc(inline)]
+pub use crate::rapid_range::*;
+#[doc(inline)]
+#[cfg(any(all(feature = "std", feature = "rng"), docsrs))]
+pub use crate::rapid_reservoir::*;
+#[doc(inline)]
+#[cfg(any(feature = "alloc", docsrs))]
+pub use crate::rapid_ring::*;
+#[doc(inline)]
+#[cfg(any(feature = "alloc", docsrs))]
+pub use crate::rapid_ring_bounded::*;
+#[doc(inline)]
+pub use crate::rapid_seed::*;
+#[doc(inline)]
+#[cfg(any(all(feature = "std", feature = "hasher"), docsrs))]
+pub use crate::rapid_sharded::*;
+#[doc(inline)]
+pub use crate::rapid_simhash::*;
+#[doc(inline)]
+pub use crate::rapid_slices::*;
+#[doc(inline)]
+pub use crate::rapid_stream::*;
+#[doc(inline)]
+pub use crate::rapid_tagged::*;
+#[doc(inline)]
+#[cfg(any(all(feature = "alloc", feature = "hasher"), docsrs))]
+pub use crate::rapid_trace::*;
+#[doc(inline)]
+#[cfg(any(feature = "std", docsrs))]
+pub use crate::rapid_tree::*;
+#[doc(inline)]
+pub use crate::rapid_uuid::*;
+#[doc(inline)]
+#[cfg(any(feature = "test-vectors", docsrs))]
+pub use crate::rapid_vectors::*;
+#[doc(inline)]
+#[cfg(any(feature = "random-state", docsrs))]
 pub use crate::random_state::*;
 #[doc(inline)]
+#[cfg(any(feature = "rng", docsrs))]
 pub use crate::rng::*;
 
 
-#[cfg(test)]
+#[cfg(all(test, feature = "hasher"))]
 mod tests {
     extern crate std;
 
@@ -50,7 +279,7 @@ mod tests {
         assert_ne!(hash, 0);
         assert_eq!(hash, 17498481775468162579);
 
-        let mut hasher = RapidHasher::default();
+        let mut hasher = RapidHasher::new(RAPID_SEED);
         hasher.write(b"hello world");
         assert_eq!(hasher.finish(), 17498481775468162579);
 
@@ -62,14 +291,14 @@ mod tests {
     #[test]
     fn derive_hash_works() {
         let object = Object { bytes: b"hello world".to_vec() };
-        let mut hasher = RapidHasher::default();
+        let mut hasher = RapidHasher::new(RAPID_SEED);
         object.hash(&mut hasher);
-        assert_eq!(hasher.finish(), 3415994554582211120);
+        assert_eq!(hasher.finish(), 3735036129343247068);
 
-        let mut hasher = RapidHasher::default();
+        let mut hasher = RapidHasher::new(RAPID_SEED);
         hasher.write_usize(b"hello world".len());
         hasher.write(b"hello world");
-        assert_eq!(hasher.finish(), 3415994554582211120);
+        assert_eq!(hasher.finish(), 3735036129343247068);
     }
 
     /// Check RapidHasher is equivalent to the raw rapidhash for a single byte stream.
@@ -84,7 +313,7 @@ mod tests {
             OsRng.fill(data.as_mut_slice());
 
             let hash1 = rapidhash(&data);
-            let mut hasher = RapidHasher::default();
+            let mut hasher = RapidHasher::new(RAPID_SEED);
             hasher.write(&data);
             let hash2 = hasher.finish();
 
@@ -0,0 +1,407 @@
+//! Checksum manifests of `HASH  PATH` lines, as written by `sha256sum` and similar tools.
+//!
+//! Requires the `std` feature.
+//!
+//! A [Manifest] can be built by hashing a directory, written out, parsed back, and then verified
+//! against the files it lists, or compared with a fresh manifest of the same directory to find
+//! added, removed, and changed files:
+//!
+//! ```no_run
+//! use rapidhash::manifest::{HashEncoding, LineStyle, Manifest};
+//! use rapidhash::RAPID_SEED;
+//!
+//! let manifest = Manifest::from_dir("backup/", RAPID_SEED)?;
+//! manifest.write(std::fs::File::create("backup.manifest")?, HashEncoding::Decimal, LineStyle::Gnu)?;
+//!
+//! // later...
+//! let contents = std::fs::read_to_string("backup.manifest")?;
+//! let manifest = Manifest::parse(&contents, HashEncoding::Decimal).expect("valid manifest");
+//!
+//! let report = manifest.verify("backup/", RAPID_SEED);
+//! assert!(report.is_ok());
+//!
+//! let diff = manifest.diff(&Manifest::from_dir("backup/", RAPID_SEED)?);
+//! for path in &diff.added {
+//!     println!("new file: {path}");
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! Paths are stored as UTF-8 strings relative to the manifest's root directory, with `/` as the
+//! separator on every platform. Hashes are [crate::rapidhash_file_seeded] of each file's contents,
+//! so a manifest must be verified with the seed it was written with. Symbolic links are listed with
+//! the hash of their target path and never followed, as in [crate::rapidhash_dir].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::path::Path;
+use std::string::{String, ToString};
+use std::vec::Vec;
+use crate::rapid_tree::hash_symlink;
+use crate::rapidhash_file_seeded;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The text encoding of hashes in a manifest.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HashEncoding {
+    /// Unsigned decimal, e.g. `8543579700415218186`.
+    Decimal,
+    /// Zero-padded lowercase big-endian hex, e.g. `7690e478c17cc20a`.
+    Hex,
+    /// Standard padded base64 of the big-endian bytes, e.g. `dpDkeMF8wgo=`.
+    Base64,
+}
+
+impl HashEncoding {
+    /// Look up an encoding by name: `decimal` (or `dec`), `hex`, or `base64`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "decimal" | "dec" => Some(Self::Decimal),
+            "hex" => Some(Self::Hex),
+            "base64" => Some(Self::Base64),
+            _ => None,
+        }
+    }
+
+    /// Encode a hash as text.
+    pub fn encode(self, hash: u64) -> String {
+        match self {
+            Self::Decimal => hash.to_string(),
+            Self::Hex => std::format!("{hash:016x}"),
+            Self::Base64 => {
+                // 8 bytes encode to 11 base64 characters plus one padding character
+                let bytes = hash.to_be_bytes();
+                let mut out = String::with_capacity(12);
+                for chunk in bytes.chunks(3) {
+                    let mut buf = [0u8; 3];
+                    buf[..chunk.len()].copy_from_slice(chunk);
+                    let n = u32::from_be_bytes([0, buf[0], buf[1], buf[2]]);
+                    for i in 0..=chunk.len() {
+                        out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+                    }
+                }
+                out.push('=');
+                out
+            }
+        }
+    }
+
+    /// Decode a hash from text, returning `None` if it is not valid in this encoding.
+    pub fn decode(self, hash: &str) -> Option<u64> {
+        match self {
+            Self::Decimal => hash.parse().ok(),
+            Self::Hex => u64::from_str_radix(hash, 16).ok(),
+            Self::Base64 => {
+                let hash = hash.strip_suffix('=')?;
+                if hash.len() != 11 {
+                    return None;
+                }
+                let mut n: u128 = 0;
+                for c in hash.bytes() {
+                    let value = BASE64.iter().position(|&b| b == c)?;
+                    n = (n << 6) | value as u128;
+                }
+                // 11 characters carry 66 bits, the final 2 bits are padding
+                if n & 0b11 != 0 {
+                    return None;
+                }
+                Some((n >> 2) as u64)
+            }
+        }
+    }
+}
+
+/// The layout of each manifest line.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LineStyle {
+    /// GNU coreutils style, `HASH  PATH`.
+    Gnu,
+    /// BSD style, `RAPIDHASH (PATH) = HASH`.
+    Bsd,
+}
+
+/// A single file listed in a [Manifest].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ManifestEntry {
+    /// The rapidhash of the file's contents.
+    pub hash: u64,
+    /// The file's path, relative to the manifest's root directory.
+    pub path: String,
+}
+
+/// Format a single manifest line, without a trailing newline.
+pub fn format_line(hash: u64, path: &str, encoding: HashEncoding, style: LineStyle) -> String {
+    let hash = encoding.encode(hash);
+    match style {
+        LineStyle::Gnu => std::format!("{hash}  {path}"),
+        LineStyle::Bsd => std::format!("RAPIDHASH ({path}) = {hash}"),
+    }
+}
+
+/// Parse a single manifest line in either [LineStyle], returning `None` if it is malformed.
+pub fn parse_line(line: &str, encoding: HashEncoding) -> Option<ManifestEntry> {
+    let (hash, path) = match line.strip_prefix("RAPIDHASH (") {
+        Some(rest) => {
+            let (path, hash) = rest.rsplit_once(") = ")?;
+            (hash, path)
+        }
+        None => line.split_once("  ")?,
+    };
+    Some(ManifestEntry { hash: encoding.decode(hash)?, path: path.to_string() })
+}
+
+/// The error returned by [Manifest::parse] for a malformed line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManifestParseError {
+    /// The 1-based line number of the first malformed line.
+    pub line: usize,
+}
+
+impl fmt::Display for ManifestParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed manifest line {}", self.line)
+    }
+}
+
+impl std::error::Error for ManifestParseError {}
+
+/// A list of files and their hashes. See the [module documentation](self) for an example.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Manifest {
+    /// The listed files, in manifest order.
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    /// Hash every file under `root`, recursively, into a manifest sorted by path.
+    ///
+    /// Symbolic links are listed with [crate::rapidhash_seeded] of their UTF-8 target path rather
+    /// than followed, matching [crate::rapidhash_dir], so a link to a directory isn't descended into
+    /// and a link to an ancestor can't loop. Any unreadable directory or file fails the whole
+    /// manifest.
+    pub fn from_dir(root: impl AsRef<Path>, seed: u64) -> io::Result<Self> {
+        let root = root.as_ref();
+        let mut files = Vec::new();
+        walk_dir(root, String::new(), &mut files)?;
+        files.sort();
+
+        let entries = files
+            .into_iter()
+            .map(|path| Ok(ManifestEntry { hash: hash_entry(&root.join(&path), seed)?, path }))
+            .collect::<io::Result<_>>()?;
+        Ok(Self { entries })
+    }
+
+    /// Parse a manifest, skipping blank lines.
+    pub fn parse(contents: &str, encoding: HashEncoding) -> Result<Self, ManifestParseError> {
+        let mut entries = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            entries.push(parse_line(line, encoding).ok_or(ManifestParseError { line: index + 1 })?);
+        }
+        Ok(Self { entries })
+    }
+
+    /// Write the manifest as one line per entry.
+    pub fn write(&self, mut writer: impl Write, encoding: HashEncoding, style: LineStyle) -> io::Result<()> {
+        for entry in &self.entries {
+            writeln!(writer, "{}", format_line(entry.hash, &entry.path, encoding, style))?;
+        }
+        writer.flush()
+    }
+
+    /// Re-hash every listed file relative to `root`, mirroring `sha256sum --check`.
+    ///
+    /// Files under `root` that aren't listed are ignored, use [Manifest::diff] with
+    /// [Manifest::from_dir] to find them. Symbolic links are checked by their target path, as in
+    /// [Manifest::from_dir].
+    pub fn verify(&self, root: impl AsRef<Path>, seed: u64) -> VerifyReport {
+        let root = root.as_ref();
+        let mut report = VerifyReport::default();
+        for entry in &self.entries {
+            match hash_entry(&root.join(&entry.path), seed) {
+                Ok(hash) if hash == entry.hash => report.ok.push(entry.path.clone()),
+                Ok(_) => report.mismatched.push(entry.path.clone()),
+                Err(err) => report.unreadable.push((entry.path.clone(), err)),
+            }
+        }
+        report
+    }
+
+    /// Compare this manifest with a newer one, such as a fresh [Manifest::from_dir].
+    ///
+    /// Each list in the result is sorted by path.
+    pub fn diff(&self, newer: &Manifest) -> ManifestDiff {
+        let old: BTreeMap<&str, u64> = self.entries.iter().map(|e| (e.path.as_str(), e.hash)).collect();
+        let new: BTreeMap<&str, u64> = newer.entries.iter().map(|e| (e.path.as_str(), e.hash)).collect();
+
+        let mut diff = ManifestDiff::default();
+        for (&path, &hash) in &new {
+            match old.get(path) {
+                None => diff.added.push(path.to_string()),
+                Some(&old_hash) if old_hash != hash => diff.changed.push(path.to_string()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = old.keys().filter(|path| !new.contains_key(*path)).map(|path| path.to_string()).collect();
+        diff
+    }
+}
+
+/// The result of [Manifest::verify].
+#[derive(Debug, Default)]
+pub struct VerifyReport {
+    /// Files whose hash matched.
+    pub ok: Vec<String>,
+    /// Files whose hash did not match.
+    pub mismatched: Vec<String>,
+    /// Files that were missing or could not be read, with the error.
+    pub unreadable: Vec<(String, io::Error)>,
+}
+
+impl VerifyReport {
+    /// Returns `true` if every listed file was read and matched.
+    pub fn is_ok(&self) -> bool {
+        self.mismatched.is_empty() && self.unreadable.is_empty()
+    }
+}
+
+/// The differences between two manifests, returned by [Manifest::diff].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ManifestDiff {
+    /// Paths only in the newer manifest.
+    pub added: Vec<String>,
+    /// Paths only in the older manifest.
+    pub removed: Vec<String>,
+    /// Paths in both manifests with different hashes.
+    pub changed: Vec<String>,
+}
+
+impl ManifestDiff {
+    /// Returns `true` if both manifests list the same files with the same hashes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Hash a listed path: a symbolic link by its target, and anything else by its contents.
+fn hash_entry(path: &Path, seed: u64) -> io::Result<u64> {
+    if std::fs::symlink_metadata(path)?.file_type().is_symlink() {
+        hash_symlink(path, seed)
+    } else {
+        rapidhash_file_seeded(path, seed)
+    }
+}
+
+/// Recursively collect the `/`-separated relative paths of every file and symbolic link under
+/// `dir`, without following links.
+fn walk_dir(dir: &Path, prefix: String, files: &mut Vec<String>) -> io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().into_string().map_err(|name| {
+            io::Error::new(io::ErrorKind::InvalidData, std::format!("non-UTF-8 file name {name:?}"))
+        })?;
+        let path = std::format!("{prefix}{name}");
+        // `DirEntry::file_type` doesn't follow symbolic links, so links are listed as entries
+        if entry.file_type()?.is_dir() {
+            walk_dir(&entry.path(), std::format!("{path}/"), files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RAPID_SEED;
+
+    #[test]
+    fn encodings_round_trip() {
+        for encoding in [HashEncoding::Decimal, HashEncoding::Hex, HashEncoding::Base64] {
+            for hash in [0, 1, 0x7690e478c17cc20a, u64::MAX] {
+                assert_eq!(encoding.decode(&encoding.encode(hash)), Some(hash));
+            }
+        }
+        assert_eq!(HashEncoding::Hex.encode(0x7690e478c17cc20a), "7690e478c17cc20a");
+        assert_eq!(HashEncoding::Base64.encode(0x7690e478c17cc20a), "dpDkeMF8wgo=");
+    }
+
+    #[test]
+    fn lines_round_trip() {
+        for style in [LineStyle::Gnu, LineStyle::Bsd] {
+            let line = format_line(42, "dir/a file.txt", HashEncoding::Decimal, style);
+            let entry = parse_line(&line, HashEncoding::Decimal).unwrap();
+            assert_eq!(entry, ManifestEntry { hash: 42, path: "dir/a file.txt".into() });
+        }
+        assert_eq!(parse_line("not a manifest line", HashEncoding::Decimal), None);
+        assert_eq!(Manifest::parse("1  a\n\nnope\n", HashEncoding::Decimal), Err(ManifestParseError { line: 3 }));
+    }
+
+    #[test]
+    fn directory_round_trip() {
+        let root = std::env::temp_dir().join(std::format!("rapidhash-manifest-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("a.txt"), b"hello").unwrap();
+        std::fs::write(root.join("sub/b.txt"), b"world").unwrap();
+
+        let manifest = Manifest::from_dir(&root, RAPID_SEED).unwrap();
+        let paths: Vec<_> = manifest.entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, ["a.txt", "sub/b.txt"]);
+        assert_eq!(manifest.entries[0].hash, crate::rapidhash(b"hello"));
+
+        let mut written = Vec::new();
+        manifest.write(&mut written, HashEncoding::Hex, LineStyle::Bsd).unwrap();
+        let parsed = Manifest::parse(std::str::from_utf8(&written).unwrap(), HashEncoding::Hex).unwrap();
+        assert_eq!(parsed, manifest);
+        assert!(parsed.verify(&root, RAPID_SEED).is_ok());
+
+        std::fs::write(root.join("a.txt"), b"changed").unwrap();
+        std::fs::remove_file(root.join("sub/b.txt")).unwrap();
+        std::fs::write(root.join("c.txt"), b"new").unwrap();
+
+        let report = parsed.verify(&root, RAPID_SEED);
+        assert_eq!(report.mismatched, ["a.txt"]);
+        assert_eq!(report.unreadable.len(), 1);
+
+        let diff = parsed.diff(&Manifest::from_dir(&root, RAPID_SEED).unwrap());
+        assert_eq!(diff.added, ["c.txt"]);
+        assert_eq!(diff.removed, ["sub/b.txt"]);
+        assert_eq!(diff.changed, ["a.txt"]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn lists_symlinks_without_following() {
+        let root = std::env::temp_dir().join(std::format!("rapidhash-manifest-links-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("a.txt"), b"hello").unwrap();
+        std::os::unix::fs::symlink("..", root.join("sub/up")).unwrap();
+        std::os::unix::fs::symlink("a.txt", root.join("link")).unwrap();
+
+        let manifest = Manifest::from_dir(&root, RAPID_SEED).unwrap();
+        assert_eq!(manifest.entries, [
+            ManifestEntry { hash: crate::rapidhash(b"hello"), path: "a.txt".into() },
+            ManifestEntry { hash: crate::rapidhash(b"a.txt"), path: "link".into() },
+            ManifestEntry { hash: crate::rapidhash(b".."), path: "sub/up".into() },
+        ]);
+        assert!(manifest.verify(&root, RAPID_SEED).is_ok());
+
+        // retargeting a link is a change, even when the new target has the same contents
+        std::fs::write(root.join("b.txt"), b"hello").unwrap();
+        std::fs::remove_file(root.join("link")).unwrap();
+        std::os::unix::fs::symlink("b.txt", root.join("link")).unwrap();
+        assert_eq!(manifest.verify(&root, RAPID_SEED).mismatched, ["link"]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
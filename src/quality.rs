@@ -0,0 +1,226 @@
+//! Reusable hash quality checks, for validating custom seeds, secrets, and hasher wrappers.
+//!
+//! Requires the `quality` feature.
+//!
+//! These are quick statistical smoke tests, not a substitute for SMHasher. They take a hash
+//! function as a closure over a byte slice, so they can check any hasher:
+//!
+//! ```
+//! use rapidhash::quality::{avalanche_score, bit_bias, collision_scan};
+//!
+//! let hash_fn = |bytes: &[u8]| rapidhash::rapidhash_seeded(bytes, 0x1234);
+//!
+//! let avalanche = avalanche_score(hash_fn);
+//! assert!(avalanche.score < 0.01);
+//! assert!(avalanche.min_flipped >= 10);
+//!
+//! assert!(bit_bias(hash_fn, 16, 10_000) < 0.01);
+//!
+//! let collisions = collision_scan((0..100_000u64).map(|i| hash_fn(&i.to_le_bytes())));
+//! assert_eq!(collisions.collisions, 0);
+//! ```
+//!
+//! Inputs are generated with a fixed-seed [crate::RapidRng], so the results are deterministic.
+
+use std::collections::HashSet;
+use crate::RapidRng;
+
+/// The input lengths checked by [avalanche_score].
+const AVALANCHE_LENGTHS: [usize; 8] = [1, 4, 8, 16, 17, 48, 100, 256];
+
+/// The number of random inputs per length checked by [avalanche_score].
+const AVALANCHE_ITERATIONS: usize = 50;
+
+/// The seed for generating random inputs.
+const INPUT_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// The result of an avalanche check.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AvalancheReport {
+    /// The number of single-bit input flips tested.
+    pub samples: usize,
+    /// The mean number of output bits that changed per input bit flip, ideally 32.
+    pub mean_flipped: f64,
+    /// The fewest output bits that changed for any single input bit flip.
+    pub min_flipped: u32,
+    /// The normalised deviation from the ideal, `|1 - mean_flipped / 32|`, where `0.0` is perfect.
+    pub score: f64,
+}
+
+/// The result of a collision scan.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CollisionReport {
+    /// The total number of hashes scanned.
+    pub total: usize,
+    /// The number of hashes that were equal to an earlier hash.
+    pub collisions: usize,
+}
+
+impl CollisionReport {
+    /// The fraction of hashes that collided with an earlier hash.
+    #[inline]
+    pub fn rate(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.collisions as f64 / self.total as f64
+        }
+    }
+}
+
+/// Check how many output bits change when flipping each bit of random inputs, over a range of input
+/// lengths that cover the different code paths of rapidhash.
+///
+/// Ideally every input bit flip changes half of the 64 output bits, giving a score of `0.0`.
+pub fn avalanche_score(mut hash_fn: impl FnMut(&[u8]) -> u64) -> AvalancheReport {
+    let mut total = Accumulator::default();
+    for len in AVALANCHE_LENGTHS {
+        total.merge(avalanche(&mut hash_fn, len, AVALANCHE_ITERATIONS));
+    }
+    total.report()
+}
+
+/// Check avalanche for random inputs of a single length, with a custom number of iterations.
+///
+/// See [avalanche_score] for details.
+pub fn avalanche_score_with(mut hash_fn: impl FnMut(&[u8]) -> u64, input_len: usize, iterations: usize) -> AvalancheReport {
+    avalanche(&mut hash_fn, input_len, iterations).report()
+}
+
+/// Check each output bit is set for half of the random inputs of a given length.
+///
+/// Returns the variance of the per-bit set frequency around `0.5`, normalised by the worst possible
+/// variance, so `0.0` is perfect and `1.0` means every bit is constant.
+///
+/// # Panics
+/// Panics if `iterations` is `0`, as there is no frequency to score.
+pub fn bit_bias(mut hash_fn: impl FnMut(&[u8]) -> u64, input_len: usize, iterations: usize) -> f64 {
+    assert!(iterations > 0, "bit_bias: iterations must be non-zero");
+    let mut rng = RapidRng::new(INPUT_SEED);
+    let mut input = std::vec![0u8; input_len];
+    let mut counts = [0usize; 64];
+
+    for _ in 0..iterations {
+        fill(&mut rng, &mut input);
+        let hash = hash_fn(&input);
+        for (bit, count) in counts.iter_mut().enumerate() {
+            *count += ((hash >> bit) & 1) as usize;
+        }
+    }
+
+    let variance = counts.iter()
+        .map(|&count| {
+            let diff = count as f64 / iterations as f64 - 0.5;
+            diff * diff
+        })
+        .sum::<f64>() / 64.0;
+
+    // the worst possible variance for frequencies between 0 and 1 around 0.5 is 0.25
+    variance / 0.25
+}
+
+/// Count the collisions in a sequence of hashes.
+pub fn collision_scan(hashes: impl IntoIterator<Item = u64>) -> CollisionReport {
+    let mut seen = HashSet::new();
+    let mut report = CollisionReport { total: 0, collisions: 0 };
+    for hash in hashes {
+        report.total += 1;
+        if !seen.insert(hash) {
+            report.collisions += 1;
+        }
+    }
+    report
+}
+
+#[derive(Default)]
+struct Accumulator {
+    samples: usize,
+    flipped: u64,
+    min_flipped: Option<u32>,
+}
+
+impl Accumulator {
+    fn merge(&mut self, other: Self) {
+        self.samples += other.samples;
+        self.flipped += other.flipped;
+        self.min_flipped = match (self.min_flipped, other.min_flipped) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    fn report(&self) -> AvalancheReport {
+        let mean_flipped = if self.samples == 0 { 0.0 } else { self.flipped as f64 / self.samples as f64 };
+        AvalancheReport {
+            samples: self.samples,
+            mean_flipped,
+            min_flipped: self.min_flipped.unwrap_or(0),
+            score: (1.0 - mean_flipped / 32.0).abs(),
+        }
+    }
+}
+
+fn avalanche(hash_fn: &mut impl FnMut(&[u8]) -> u64, len: usize, iterations: usize) -> Accumulator {
+    let mut rng = RapidRng::new(INPUT_SEED ^ len as u64);
+    let mut input = std::vec![0u8; len];
+    let mut result = Accumulator::default();
+
+    for _ in 0..iterations {
+        fill(&mut rng, &mut input);
+        let hash = hash_fn(&input);
+
+        for bit in 0..len * 8 {
+            input[bit / 8] ^= 1 << (bit % 8);
+            let flipped = (hash ^ hash_fn(&input)).count_ones();
+            input[bit / 8] ^= 1 << (bit % 8);
+
+            result.samples += 1;
+            result.flipped += flipped as u64;
+            result.min_flipped = Some(result.min_flipped.map_or(flipped, |min| min.min(flipped)));
+        }
+    }
+
+    result
+}
+
+fn fill(rng: &mut RapidRng, bytes: &mut [u8]) {
+    for chunk in bytes.chunks_mut(8) {
+        chunk.copy_from_slice(&rng.next().to_le_bytes()[..chunk.len()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rapidhash_passes() {
+        let avalanche = avalanche_score(crate::rapidhash);
+        assert!(avalanche.score < 0.005, "{avalanche:?}");
+        assert!(avalanche.min_flipped >= 10, "{avalanche:?}");
+
+        assert!(bit_bias(crate::rapidhash, 32, 10_000) < 0.01);
+        assert_eq!(collision_scan((0..100_000u64).map(|i| crate::rapidhash(&i.to_le_bytes()))).collisions, 0);
+    }
+
+    #[test]
+    fn bad_hashes_fail() {
+        let identity = |bytes: &[u8]| bytes.iter().fold(0u64, |acc, &b| acc.wrapping_add(b as u64));
+
+        let avalanche = avalanche_score_with(identity, 8, 10);
+        assert!(avalanche.score > 0.5);
+        assert_eq!(avalanche.samples, 640);
+
+        assert!(bit_bias(identity, 8, 1000) > 0.5);
+
+        let collisions = collision_scan([1, 2, 2, 3, 1]);
+        assert_eq!(collisions, CollisionReport { total: 5, collisions: 2 });
+        assert_eq!(collisions.rate(), 0.4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bit_bias_needs_iterations() {
+        let _ = bit_bias(crate::rapidhash, 8, 0);
+    }
+}
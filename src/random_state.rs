@@ -1,6 +1,7 @@
 use std::cell::Cell;
 use std::hash::BuildHasher;
-use crate::{rapidrng_fast, RapidHasher};
+use std::sync::OnceLock;
+use crate::{rapidrng_fast, EntropySource, RapidHasher};
 
 /// A [std::collections::hash_map::RandomState] compatible hasher that initializes the [RapidHasher]
 /// algorithm with a random seed.
@@ -8,6 +9,8 @@ use crate::{rapidrng_fast, RapidHasher};
 /// Note this is not sufficient to prevent HashDoS attacks. The rapidhash algorithm is not proven to
 /// be resistant, and the seed used is not wide enough.
 ///
+/// Requires the `random-state` feature.
+///
 /// # Example
 /// ```rust
 /// use std::collections::HashMap;
@@ -29,20 +32,11 @@ impl RapidRandomState {
     ///
     /// Without `rand` but with the `std` feature enabled, this will use [crate::rapidrng_time] to
     /// initialise the seed.
+    ///
+    /// A source registered with [crate::set_entropy_source] takes precedence over both.
     pub fn new() -> Self {
-        #[cfg(feature = "rand")]
-        thread_local! {
-            static RANDOM_SEED: Cell<u64> = {
-                Cell::new(rand::random())
-            }
-        }
-
-        #[cfg(all(feature = "std", not(feature = "rand")))]
         thread_local! {
-            static RANDOM_SEED: Cell<u64> = {
-                let mut seed = crate::RAPID_SEED;
-                Cell::new(crate::rapidrng_time(&mut seed))
-            }
+            static RANDOM_SEED: Cell<u64> = Cell::new(random_seed());
         }
 
         let mut seed = RANDOM_SEED.with(|cell| {
@@ -55,6 +49,56 @@ impl RapidRandomState {
             seed: rapidrng_fast(&mut seed),
         }
     }
+
+    /// Create a random state with a fixed seed, bypassing the random seed initialisation.
+    ///
+    /// Useful for test fixtures that need reproducible hashes and iteration order from a map
+    /// whose type uses [RapidRandomState]. The hashers it builds are equal to
+    /// [RapidHasher::new] with the same seed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use std::hash::BuildHasher;
+    /// use rapidhash::RapidRandomState;
+    ///
+    /// let state = RapidRandomState::with_seed(42);
+    /// assert_eq!(state.hash_one("key"), RapidRandomState::with_seed(42).hash_one("key"));
+    ///
+    /// let mut map = HashMap::with_hasher(state);
+    /// map.insert("key", "value");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Create a new random state seeded from an [EntropySource].
+    pub fn from_entropy(source: &mut impl EntropySource) -> Self {
+        let mut seed = source.entropy();
+        Self {
+            seed: rapidrng_fast(&mut seed),
+        }
+    }
+}
+
+/// Generate a random seed from the registered entropy source, the `rand` crate, or the time.
+fn random_seed() -> u64 {
+    if let Some(seed) = crate::rapid_entropy::registered_entropy() {
+        return seed;
+    }
+
+    #[cfg(feature = "rand")]
+    {
+        rand::random()
+    }
+
+    #[cfg(not(feature = "rand"))]
+    {
+        let mut seed = crate::RAPID_SEED;
+        crate::rapidrng_time(&mut seed)
+    }
 }
 
 impl Default for RapidRandomState {
@@ -71,6 +115,66 @@ impl BuildHasher for RapidRandomState {
     }
 }
 
+/// A [std::collections::hash_map::RandomState] compatible hasher with a random seed that is chosen
+/// once per process and shared by every thread.
+///
+/// [RapidRandomState] draws a different seed for every instance, so two threads hashing the same
+/// key get different hashes. Every [RapidGlobalRandomState] uses the same seed for the lifetime of
+/// the process, so worker threads can exchange precomputed hashes of the same keys, while the seed
+/// still changes between runs.
+///
+/// The seed is initialised on first use, in the same way as [RapidRandomState::new]. As the seed is
+/// shared across all maps, prefer [RapidRandomState] unless hashes need to be compared between
+/// threads or maps.
+///
+/// Requires the `random-state` feature.
+///
+/// # Example
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::hash::BuildHasher;
+/// use rapidhash::RapidGlobalRandomState;
+///
+/// let hash = RapidGlobalRandomState::new().hash_one("key");
+/// let other = std::thread::spawn(|| RapidGlobalRandomState::new().hash_one("key"));
+/// assert_eq!(hash, other.join().unwrap());
+///
+/// let mut map = HashMap::with_hasher(RapidGlobalRandomState::default());
+/// map.insert(42, "the answer");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct RapidGlobalRandomState {
+    seed: u64,
+}
+
+impl RapidGlobalRandomState {
+    /// Create a random state with the process-wide seed, initialising it on first use.
+    pub fn new() -> Self {
+        static GLOBAL_SEED: OnceLock<u64> = OnceLock::new();
+
+        let seed = *GLOBAL_SEED.get_or_init(|| {
+            let mut seed = random_seed();
+            rapidrng_fast(&mut seed)
+        });
+
+        Self { seed }
+    }
+}
+
+impl Default for RapidGlobalRandomState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for RapidGlobalRandomState {
+    type Hasher = RapidHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        RapidHasher::new(self.seed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::hash::{BuildHasher, Hasher, RandomState};
@@ -97,4 +201,20 @@ mod tests {
         assert_eq!(finish1a, finish1b);
         assert_ne!(finish1a, finish2a);
     }
+
+    #[test]
+    fn test_global_random_state() {
+        let state = crate::RapidGlobalRandomState::new();
+        let other = std::thread::spawn(crate::RapidGlobalRandomState::new).join().unwrap();
+        assert_eq!(state.hash_one(42u64), other.hash_one(42u64));
+        assert_ne!(state.hash_one(42u64), state.hash_one(43u64));
+    }
+
+    #[test]
+    fn test_with_seed() {
+        let state = crate::RapidRandomState::with_seed(7);
+        assert_eq!(state.hash_one(42u64), crate::RapidBuildHasher::new(7).hash_one(42u64));
+        assert_eq!(state.hash_one("a"), crate::RapidRandomState::with_seed(7).hash_one("a"));
+        assert_ne!(state.hash_one("a"), crate::RapidRandomState::with_seed(8).hash_one("a"));
+    }
 }
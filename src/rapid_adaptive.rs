@@ -0,0 +1,288 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::vec::Vec;
+use crate::RapidRandomState;
+
+/// The number of low-bit bucket counters used to detect collision attacks.
+const BUCKETS: usize = 4096;
+
+/// Collision tracking starts once the map holds this many entries, as small maps can't be
+/// meaningfully slowed down.
+const TRACK_AFTER: usize = 64;
+
+/// A [HashMap] wrapper using the [RapidRandomState] that detects pathological collision rates, and
+/// transparently rebuilds itself with a freshly randomized seed when they occur.
+///
+/// The standard library's [HashMap] exposes no probe statistics, so this wraps the map rather than
+/// the [BuildHasher]. Every insert and removal updates one of 4096 counters, indexed by the low bits
+/// of the key's hash, which are the bits a hash table uses to pick a bucket. With random hashes
+/// each counter holds about `len / 4096` entries. Keys crafted to collide all land on the same
+/// counter, and once any counter exceeds twice the average plus 32, the map is rehashed into a new
+/// [RapidRandomState]. The crafted keys no longer collide under the new seed.
+///
+/// If the collisions persist under the new seed, they come from the keys' [Hash] implementation
+/// rather than the seed, and tracking is disabled to avoid rehashing repeatedly.
+///
+/// This provides practical HashDoS resilience without paying the cost of SipHash up front, but is
+/// not a proof of resistance: rapidhash is not a keyed cryptographic hash. The counters cost 16 KiB
+/// once the map holds more than 64 entries, and each insert and removal hashes the key twice.
+///
+/// Requires the `random-state` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidAdaptiveMap;
+///
+/// let mut map = RapidAdaptiveMap::new();
+/// map.insert("hello", 42);
+/// assert_eq!(map.get("hello"), Some(&42));
+/// assert_eq!(map.reseeds(), 0);
+/// ```
+#[derive(Clone)]
+pub struct RapidAdaptiveMap<K, V> {
+    map: HashMap<K, V, RapidRandomState>,
+    counters: Vec<u32>,
+    reseeds: u32,
+    /// Set when collisions persist across a reseed, and so can't be fixed by changing the seed.
+    disabled: bool,
+}
+
+impl<K: Hash + Eq, V> RapidAdaptiveMap<K, V> {
+    /// Create an empty map with a random seed.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty map with a random seed and space for at least `capacity` entries.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity_and_hasher(capacity, RapidRandomState::new()),
+            counters: Vec::new(),
+            reseeds: 0,
+            disabled: false,
+        }
+    }
+
+    /// The number of entries in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The number of times the map has detected excessive collisions and switched to a new seed.
+    #[inline]
+    pub fn reseeds(&self) -> u32 {
+        self.reseeds
+    }
+
+    /// Get a reference to the underlying [HashMap].
+    #[inline]
+    pub fn as_map(&self) -> &HashMap<K, V, RapidRandomState> {
+        &self.map
+    }
+
+    /// Unwrap this map, returning the underlying [HashMap].
+    #[inline]
+    pub fn into_map(self) -> HashMap<K, V, RapidRandomState> {
+        self.map
+    }
+
+    /// Returns a reference to the value for the key.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key)
+    }
+
+    /// Returns a mutable reference to the value for the key.
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_mut(key)
+    }
+
+    /// Returns `true` if the map contains the key.
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Insert a key-value pair, returning the previous value if the key was present.
+    ///
+    /// May rebuild the map with a new seed if the insert reveals excessive collisions.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let bucket = self.bucket(&key);
+        let previous = self.map.insert(key, value);
+
+        if previous.is_none() {
+            if !self.counters.is_empty() {
+                self.counters[bucket] += 1;
+                if self.counters[bucket] > self.threshold() {
+                    self.reseed();
+                }
+            } else if self.map.len() >= TRACK_AFTER && !self.disabled {
+                self.recount();
+            }
+        }
+
+        previous
+    }
+
+    /// Remove a key from the map, returning its value if it was present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let value = self.map.remove(key)?;
+        if !self.counters.is_empty() {
+            let bucket = self.bucket(key);
+            self.counters[bucket] -= 1;
+        }
+        Some(value)
+    }
+
+    /// Remove all entries, keeping the allocated memory and the current seed.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.counters.clear();
+    }
+
+    /// An iterator over the entries of the map, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, K, V> {
+        self.map.iter()
+    }
+
+    /// The counter index for a key, from the low bits of its hash.
+    #[inline]
+    fn bucket<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        self.map.hasher().hash_one(key) as usize % BUCKETS
+    }
+
+    /// The counter value above which the collisions are considered pathological.
+    #[inline]
+    fn threshold(&self) -> u32 {
+        (2 * self.map.len() / BUCKETS) as u32 + 32
+    }
+
+    /// Rebuild the counters from the current entries.
+    fn recount(&mut self) {
+        self.counters.clear();
+        self.counters.resize(BUCKETS, 0);
+        for key in self.map.keys() {
+            let bucket = self.bucket(key);
+            self.counters[bucket] += 1;
+        }
+    }
+
+    /// Rehash every entry into a map with a fresh random seed.
+    fn reseed(&mut self) {
+        let mut map = HashMap::with_capacity_and_hasher(self.map.capacity(), RapidRandomState::new());
+        map.extend(self.map.drain());
+        self.map = map;
+        self.reseeds += 1;
+        self.recount();
+
+        let threshold = self.threshold();
+        if self.counters.iter().any(|&count| count > threshold) {
+            self.counters = Vec::new();
+            self.disabled = true;
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for RapidAdaptiveMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq, V> Extend<(K, V)> for RapidAdaptiveMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for RapidAdaptiveMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::Hasher;
+    use super::*;
+
+    #[test]
+    fn behaves_like_a_map() {
+        let mut map: RapidAdaptiveMap<u64, u64> = (0..10_000).map(|i| (i, i * 2)).collect();
+        assert_eq!(map.len(), 10_000);
+        assert_eq!(map.get(&5), Some(&10));
+        assert_eq!(map.insert(5, 0), Some(10));
+        assert_eq!(map.remove(&5), Some(0));
+        assert!(!map.contains_key(&5));
+        assert_eq!(map.reseeds(), 0, "random keys should not trigger a reseed");
+
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    /// A key whose colliding hashes don't depend on the seed, to exercise detection.
+    #[derive(PartialEq, Eq)]
+    struct Crafted(u64, bool);
+
+    impl Hash for Crafted {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            // colliding keys hash only a constant, others hash their value
+            match self.1 {
+                true => state.write_u64(0),
+                false => state.write_u64(self.0),
+            }
+        }
+    }
+
+    #[test]
+    fn detects_collisions() {
+        let mut map = RapidAdaptiveMap::new();
+        for i in 0..1000 {
+            map.insert(Crafted(i, false), i);
+        }
+        assert_eq!(map.reseeds(), 0);
+
+        for i in 1000..1500 {
+            map.insert(Crafted(i, true), i);
+        }
+
+        // the collisions survive the new seed, so tracking stops instead of reseeding every insert
+        assert!(map.reseeds() >= 1 && map.reseeds() <= 2, "reseeds: {}", map.reseeds());
+        assert_eq!(map.len(), 1500);
+        assert_eq!(map.get(&Crafted(1050, true)), Some(&1050));
+    }
+}
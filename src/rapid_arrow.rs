@@ -0,0 +1,163 @@
+use std::vec::Vec;
+
+use arrow_array::types::{ArrowPrimitiveType, ByteArrayType};
+use arrow_array::{Array, GenericByteArray, PrimitiveArray, UInt64Array};
+use arrow_buffer::{ArrowNativeType, ScalarBuffer, ToByteSlice};
+
+use crate::rapid_const::{rapidhash_inline, RAPID_SEED};
+
+/// Hash every row of an Arrow `StringArray`, `BinaryArray`, or their large variants into a
+/// `UInt64Array`, using the default seed.
+///
+/// See [rapidhash_arrow_bytes_seeded] for details. Requires the `arrow` feature.
+///
+/// # Example
+/// ```
+/// use arrow_array::{Array, StringArray};
+/// use rapidhash::{rapidhash, rapidhash_arrow_bytes};
+///
+/// let keys = StringArray::from(vec![Some("hello"), None, Some("world")]);
+/// let hashes = rapidhash_arrow_bytes(&keys);
+///
+/// assert_eq!(hashes.value(0), rapidhash(b"hello"));
+/// assert!(hashes.is_null(1));
+/// assert_eq!(hashes.value(2), rapidhash(b"world"));
+/// ```
+#[must_use]
+pub fn rapidhash_arrow_bytes<T: ByteArrayType>(array: &GenericByteArray<T>) -> UInt64Array {
+    rapidhash_arrow_bytes_seeded(array, RAPID_SEED)
+}
+
+/// Hash every row of an Arrow `StringArray`, `BinaryArray`, or their large variants into a
+/// `UInt64Array` with a custom seed.
+///
+/// Each row hashes to the same value as [crate::rapidhash_seeded] on its bytes. Rows are read
+/// straight from the offsets and value buffers, without constructing a `&str` or `&[u8]` through
+/// the array accessors for every row.
+///
+/// The output shares the input's null buffer, so null rows are null in the output. Their
+/// underlying values are always `0`, making the hashes safe to combine with other key columns
+/// without first checking validity.
+///
+/// Requires the `arrow` feature.
+#[must_use]
+pub fn rapidhash_arrow_bytes_seeded<T: ByteArrayType>(array: &GenericByteArray<T>, seed: u64) -> UInt64Array {
+    let offsets = array.value_offsets();
+    let data = array.value_data();
+
+    let hashes: Vec<u64> = offsets
+        .windows(2)
+        .map(|w| rapidhash_inline(&data[w[0].as_usize()..w[1].as_usize()], seed))
+        .collect();
+
+    finish(hashes, array)
+}
+
+/// Hash every value of an Arrow primitive array into a `UInt64Array`, using the default seed.
+///
+/// See [rapidhash_arrow_primitive_seeded] for details. Requires the `arrow` feature.
+///
+/// # Example
+/// ```
+/// use arrow_array::{Array, Int64Array};
+/// use rapidhash::{rapidhash, rapidhash_arrow_primitive};
+///
+/// let keys = Int64Array::from(vec![Some(42), None]);
+/// let hashes = rapidhash_arrow_primitive(&keys);
+///
+/// assert_eq!(hashes.value(0), rapidhash(&42i64.to_ne_bytes()));
+/// assert!(hashes.is_null(1));
+/// ```
+#[must_use]
+pub fn rapidhash_arrow_primitive<T: ArrowPrimitiveType>(array: &PrimitiveArray<T>) -> UInt64Array {
+    rapidhash_arrow_primitive_seeded(array, RAPID_SEED)
+}
+
+/// Hash every value of an Arrow primitive array into a `UInt64Array` with a custom seed.
+///
+/// Each value hashes to the same value as [crate::rapidhash_seeded] on its native-endian bytes,
+/// so hashes are not portable between platforms of different endianness.
+///
+/// The output shares the input's null buffer, so null rows are null in the output. Their
+/// underlying values are always `0`.
+///
+/// Requires the `arrow` feature.
+#[must_use]
+pub fn rapidhash_arrow_primitive_seeded<T: ArrowPrimitiveType>(array: &PrimitiveArray<T>, seed: u64) -> UInt64Array {
+    let hashes: Vec<u64> = array
+        .values()
+        .iter()
+        .map(|v| rapidhash_inline(v.to_byte_slice(), seed))
+        .collect();
+
+    finish(hashes, array)
+}
+
+/// Zero the hashes of null rows and attach the input's null buffer.
+///
+/// Every row is hashed unconditionally above, as branching on validity per row is slower than
+/// hashing the (valid, but meaningless) bytes behind a null slot.
+#[inline]
+fn finish(mut hashes: Vec<u64>, array: &dyn Array) -> UInt64Array {
+    let nulls = array.nulls().cloned();
+    if let Some(nulls) = &nulls {
+        for (hash, valid) in hashes.iter_mut().zip(nulls.iter()) {
+            if !valid {
+                *hash = 0;
+            }
+        }
+    }
+    UInt64Array::new(ScalarBuffer::from(hashes), nulls)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{BinaryArray, Float64Array, LargeStringArray, StringArray, UInt32Array};
+    use super::*;
+
+    #[test]
+    fn bytes_match_oneshot() {
+        let rows = [Some("a"), None, Some(""), Some("hello world, this string is longer than forty-eight bytes!"), Some("b")];
+        let strings = StringArray::from(rows.to_vec());
+        let large = LargeStringArray::from(rows.to_vec());
+        let binary = BinaryArray::from(rows.iter().map(|r| r.map(str::as_bytes)).collect::<Vec<_>>());
+
+        for hashes in [rapidhash_arrow_bytes_seeded(&strings, 7), rapidhash_arrow_bytes_seeded(&large, 7), rapidhash_arrow_bytes_seeded(&binary, 7)] {
+            assert_eq!(hashes.len(), rows.len());
+            for (i, row) in rows.iter().enumerate() {
+                match row {
+                    Some(row) => assert_eq!(hashes.value(i), crate::rapidhash_seeded(row.as_bytes(), 7)),
+                    None => {
+                        assert!(hashes.is_null(i));
+                        assert_eq!(hashes.values()[i], 0);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sliced_bytes() {
+        let strings = StringArray::from(vec!["skip", "one", "two", "three"]);
+        let sliced = strings.slice(1, 2);
+        let hashes = rapidhash_arrow_bytes(&sliced);
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(hashes.value(0), crate::rapidhash(b"one"));
+        assert_eq!(hashes.value(1), crate::rapidhash(b"two"));
+    }
+
+    #[test]
+    fn primitives_match_oneshot() {
+        let ints = UInt32Array::from(vec![Some(1), None, Some(u32::MAX)]);
+        let hashes = rapidhash_arrow_primitive(&ints);
+        assert_eq!(hashes.value(0), crate::rapidhash(&1u32.to_ne_bytes()));
+        assert!(hashes.is_null(1));
+        assert_eq!(hashes.values()[1], 0);
+        assert_eq!(hashes.value(2), crate::rapidhash(&u32::MAX.to_ne_bytes()));
+
+        let floats = Float64Array::from(vec![1.5, -0.0]);
+        let hashes = rapidhash_arrow_primitive_seeded(&floats, 3);
+        assert_eq!(hashes.null_count(), 0);
+        assert_eq!(hashes.value(1), crate::rapidhash_seeded(&(-0.0f64).to_ne_bytes(), 3));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::rapid_const::rapidhash_inline;
+use crate::RapidStreamHasher;
+
+/// The stack buffer size for folding case, inputs up to this length are hashed in one pass.
+pub(crate) const ASCII_FOLD_BUFFER: usize = 192;
+
+/// Hash `data` as if its ASCII letters were lowercase, without allocating a lowercase copy.
+///
+/// This is equal to [crate::rapidhash_seeded] of `data.to_ascii_lowercase()`, so `Content-Type`
+/// and `content-type` hash the same, for HTTP header names and case-insensitive identifiers.
+/// Non-ASCII bytes are hashed unchanged, so this doesn't fold Unicode case.
+///
+/// Inputs up to 192 bytes are lowercased into a stack buffer and hashed in one pass, and longer
+/// inputs are streamed through a [RapidStreamHasher] in 192-byte chunks.
+///
+/// See [crate::RapidAsciiLowercaseHasher] for a [core::hash::Hasher] that folds case.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash_ascii_lowercase, rapidhash_seeded};
+///
+/// let a = rapidhash_ascii_lowercase(b"Content-Type", 0);
+/// let b = rapidhash_ascii_lowercase(b"content-type", 0);
+/// assert_eq!(a, b);
+/// assert_eq!(a, rapidhash_seeded(b"content-type", 0));
+/// ```
+#[must_use]
+pub fn rapidhash_ascii_lowercase(data: &[u8], seed: u64) -> u64 {
+    let mut buffer = [0u8; ASCII_FOLD_BUFFER];
+
+    if data.len() <= ASCII_FOLD_BUFFER {
+        let lower = fold_ascii_lowercase(data, &mut buffer);
+        return rapidhash_inline(lower, seed);
+    }
+
+    let mut hasher = RapidStreamHasher::new_seeded(data.len() as u64, seed);
+    for chunk in data.chunks(ASCII_FOLD_BUFFER) {
+        hasher.write(fold_ascii_lowercase(chunk, &mut buffer));
+    }
+    hasher.finish()
+}
+
+/// Copy `chunk` into the front of `buffer` with ASCII letters lowercased, and return the copy.
+#[inline(always)]
+pub(crate) fn fold_ascii_lowercase<'a>(chunk: &[u8], buffer: &'a mut [u8; ASCII_FOLD_BUFFER]) -> &'a [u8] {
+    let lower = &mut buffer[..chunk.len()];
+    for (out, byte) in lower.iter_mut().zip(chunk) {
+        *out = byte.to_ascii_lowercase();
+    }
+    lower
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+    use super::*;
+
+    #[test]
+    fn matches_lowercased_oneshot() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 7 % 256) as u8).collect();
+        for len in [0, 1, 8, 16, 17, 96, 191, 192, 193, 384, 385, 1000] {
+            let input = &data[..len];
+            let expected = crate::rapidhash_seeded(&input.to_ascii_lowercase(), 7);
+            assert_eq!(rapidhash_ascii_lowercase(input, 7), expected, "len {len}");
+            assert_eq!(rapidhash_ascii_lowercase(&input.to_ascii_uppercase(), 7), expected, "len {len}");
+        }
+        assert_ne!(rapidhash_ascii_lowercase("Ä".as_bytes(), 0), rapidhash_ascii_lowercase("ä".as_bytes(), 0));
+    }
+}
@@ -0,0 +1,181 @@
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+use crate::{RapidArrayBuildHasher, RapidBufferedBuildHasher, RapidBuildHasher, RapidIntBuildHasher};
+
+/// Chooses the hashing strategy that an [AutoHashBuilder] uses for this key type.
+///
+/// This crate implements it for the common key types:
+/// - Integers, `char`, and `bool` use [RapidIntBuildHasher], a single folded multiply per key.
+/// - Strings and byte slices use [RapidBuildHasher], the full rapidhash of the bytes.
+/// - Byte arrays use [RapidArrayBuildHasher], specialised for the array length.
+/// - Tuples use [RapidBufferedBuildHasher], which packs their small fields together before
+///   hashing them.
+///
+/// References use the strategy of the type they refer to. Implement it for your own key types by
+/// picking one of the build hashers, usually [RapidBufferedBuildHasher] for structs of integers
+/// and [RapidBuildHasher] for structs holding strings.
+///
+/// Requires the `hasher` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::{AutoHashKey, AutoHashMap, RapidBufferedBuildHasher};
+///
+/// #[derive(Hash, PartialEq, Eq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl AutoHashKey for Point {
+///     type BuildHasher = RapidBufferedBuildHasher;
+/// }
+///
+/// let mut map: AutoHashMap<Point, &str> = AutoHashMap::default();
+/// map.insert(Point { x: 1, y: 2 }, "a");
+/// ```
+pub trait AutoHashKey: Hash {
+    /// The build hasher for maps and sets keyed by this type.
+    type BuildHasher: BuildHasher + Default + Clone;
+}
+
+/// A [BuildHasher] that statically picks the hashing strategy for the key type `K` through
+/// [AutoHashKey], so that a single map type is close to the fastest option for every key type.
+///
+/// Requires the `hasher` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::AutoHashMap;
+///
+/// // integer keys use RapidIntBuildHasher
+/// let mut ids: AutoHashMap<u64, &str> = AutoHashMap::default();
+/// ids.insert(42, "the answer");
+///
+/// // string keys use RapidBuildHasher
+/// let mut names: AutoHashMap<String, u64> = AutoHashMap::default();
+/// names.insert("the answer".to_string(), 42);
+/// ```
+pub struct AutoHashBuilder<K: AutoHashKey + ?Sized> {
+    build: K::BuildHasher,
+    _key: PhantomData<fn(&K)>,
+}
+
+/// A [std::collections::HashMap] type that uses the [AutoHashBuilder] for its key type.
+#[cfg(any(feature = "std", docsrs))]
+pub type AutoHashMap<K, V> = std::collections::HashMap<K, V, AutoHashBuilder<K>>;
+
+/// A [std::collections::HashSet] type that uses the [AutoHashBuilder] for its key type.
+#[cfg(any(feature = "std", docsrs))]
+pub type AutoHashSet<K> = std::collections::HashSet<K, AutoHashBuilder<K>>;
+
+impl<K: AutoHashKey + ?Sized> AutoHashBuilder<K> {
+    /// Wrap the build hasher that `K` selects, such as one with a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(build: K::BuildHasher) -> Self {
+        Self { build, _key: PhantomData }
+    }
+}
+
+impl<K: AutoHashKey + ?Sized> Default for AutoHashBuilder<K> {
+    /// Create the default build hasher that `K` selects.
+    #[inline]
+    fn default() -> Self {
+        Self::new(K::BuildHasher::default())
+    }
+}
+
+impl<K: AutoHashKey + ?Sized> Clone for AutoHashBuilder<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.build.clone())
+    }
+}
+
+impl<K: AutoHashKey + ?Sized> fmt::Debug for AutoHashBuilder<K>
+where
+    K::BuildHasher: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AutoHashBuilder").field(&self.build).finish()
+    }
+}
+
+impl<K: AutoHashKey + ?Sized> BuildHasher for AutoHashBuilder<K> {
+    type Hasher = <K::BuildHasher as BuildHasher>::Hasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        self.build.build_hasher()
+    }
+}
+
+macro_rules! impl_auto_hash_key {
+    ($build:ty => $($ty:ty),*) => {
+        $(
+            impl AutoHashKey for $ty {
+                type BuildHasher = $build;
+            }
+        )*
+    };
+}
+
+impl_auto_hash_key!(RapidIntBuildHasher => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool);
+impl_auto_hash_key!(RapidBuildHasher => str, [u8]);
+
+#[cfg(any(feature = "alloc", docsrs))]
+impl_auto_hash_key!(RapidBuildHasher => alloc::string::String, alloc::vec::Vec<u8>, alloc::boxed::Box<str>, alloc::boxed::Box<[u8]>);
+
+impl<const N: usize> AutoHashKey for [u8; N] {
+    type BuildHasher = RapidArrayBuildHasher<N>;
+}
+
+impl<T: AutoHashKey + ?Sized> AutoHashKey for &T {
+    type BuildHasher = T::BuildHasher;
+}
+
+macro_rules! impl_auto_hash_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Hash),+> AutoHashKey for ($($name,)+) {
+            type BuildHasher = RapidBufferedBuildHasher;
+        }
+    };
+}
+
+impl_auto_hash_tuple!(A, B);
+impl_auto_hash_tuple!(A, B, C);
+impl_auto_hash_tuple!(A, B, C, D);
+impl_auto_hash_tuple!(A, B, C, D, E);
+impl_auto_hash_tuple!(A, B, C, D, E, F);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_strategy_per_key() {
+        assert_eq!(AutoHashBuilder::<u64>::default().hash_one(42u64), RapidIntBuildHasher::default().hash_one(42u64));
+        assert_eq!(AutoHashBuilder::<str>::default().hash_one("key"), RapidBuildHasher::default().hash_one("key"));
+        assert_eq!(AutoHashBuilder::<&str>::default().hash_one("key"), RapidBuildHasher::default().hash_one("key"));
+        assert_eq!(AutoHashBuilder::<[u8; 4]>::default().hash_one([1u8; 4]), RapidArrayBuildHasher::<4>::default().hash_one([1u8; 4]));
+        assert_eq!(AutoHashBuilder::<(u32, u8)>::default().hash_one((1u32, 2u8)), RapidBufferedBuildHasher::default().hash_one((1u32, 2u8)));
+
+        let seeded = AutoHashBuilder::<u64>::new(RapidIntBuildHasher::new(7));
+        assert_eq!(seeded.hash_one(42u64), RapidIntBuildHasher::new(7).hash_one(42u64));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn maps_work() {
+        let mut map: AutoHashMap<(u32, std::string::String), u32> = AutoHashMap::default();
+        map.insert((1, "a".into()), 1);
+        map.insert((1, "b".into()), 2);
+        assert_eq!(map.get(&(1, "b".into())), Some(&2));
+
+        let set: AutoHashSet<i64> = (-100..100).collect();
+        assert!(set.contains(&-5));
+        assert_eq!(set.len(), 200);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::rapid_const::{rapid_mum, rapidhash_finish, rapidhash_read_short, rapidhash_seed, RAPID_SECRET};
+
+/// The number of keys hashed together in each interleaved group.
+const LANES: usize = 4;
+
+/// Hash many keys with the same seed, writing each hash to the matching position in `out`.
+///
+/// The keys are hashed in groups of four. When all four keys are at most 16 bytes, the group runs
+/// lane-wise: the input words of all four keys are loaded, then the four multiplies run, then the
+/// four hashes are finished. The multiplies for different keys don't depend on each other, so the
+/// CPU can overlap them instead of waiting on each key's chain of multiplies in turn. This helps
+/// when hashing millions of short strings for analytics workloads. Groups with a longer key fall
+/// back to hashing each key in turn, as the bulk loop dominates their cost.
+///
+/// Each output is equal to [crate::rapidhash_seeded] of the corresponding key.
+///
+/// # Panics
+/// Panics if `keys` and `out` have different lengths.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash_batch, rapidhash_seeded, RAPID_SEED};
+///
+/// let keys: [&[u8]; 5] = [b"a", b"bb", b"ccc", b"dddd", b"eeeee"];
+/// let mut hashes = [0u64; 5];
+/// rapidhash_batch(&keys, RAPID_SEED, &mut hashes);
+///
+/// assert_eq!(hashes[2], rapidhash_seeded(b"ccc", RAPID_SEED));
+/// ```
+pub fn rapidhash_batch(keys: &[&[u8]], seed: u64, out: &mut [u64]) {
+    assert_eq!(keys.len(), out.len(), "rapidhash_batch: keys and out must have the same length");
+
+    let mut key_groups = keys.chunks_exact(LANES);
+    let mut out_groups = out.chunks_exact_mut(LANES);
+    for (keys, out) in (&mut key_groups).zip(&mut out_groups) {
+        if keys.iter().all(|key| key.len() <= 16) {
+            hash_short_lanes(keys, seed, out);
+        } else {
+            for (key, out) in keys.iter().zip(out) {
+                *out = crate::rapidhash_seeded(key, seed);
+            }
+        }
+    }
+
+    for (key, out) in key_groups.remainder().iter().zip(out_groups.into_remainder()) {
+        *out = crate::rapidhash_seeded(key, seed);
+    }
+}
+
+/// Hash [LANES] keys of at most 16 bytes each, running every step for all lanes before the next.
+#[inline(always)]
+fn hash_short_lanes(keys: &[&[u8]], seed: u64, out: &mut [u64]) {
+    let mut a = [0u64; LANES];
+    let mut b = [0u64; LANES];
+    for lane in 0..LANES {
+        let lane_seed = rapidhash_seed(seed, keys[lane].len() as u64);
+        let (short_a, short_b) = rapidhash_read_short(keys[lane]);
+        a[lane] = short_a ^ RAPID_SECRET[1];
+        b[lane] = short_b ^ lane_seed;
+    }
+
+    for lane in 0..LANES {
+        (a[lane], b[lane]) = rapid_mum(a[lane], b[lane]);
+    }
+
+    for lane in 0..LANES {
+        out[lane] = rapidhash_finish(a[lane], b[lane], keys[lane].len() as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+    use super::*;
+
+    #[test]
+    fn batch_matches_oneshot() {
+        let data: Vec<u8> = (0..300u32).map(|i| i as u8).collect();
+        let keys: Vec<&[u8]> = (0..=data.len()).map(|len| &data[..len]).collect();
+
+        // lengths up to 16 run lane-wise, and the group of lengths 16..=19 falls back to scalar
+        for count in [0, 1, 3, 4, 5, 8, 17, 301] {
+            let keys = &keys[..count];
+            let mut out = std::vec![0u64; count];
+            rapidhash_batch(keys, 42, &mut out);
+            for (key, hash) in keys.iter().zip(&out) {
+                assert_eq!(*hash, crate::rapidhash_seeded(key, 42), "Mismatch for len {}", key.len());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_lengths_panic() {
+        rapidhash_batch(&[b"a", b"b"], 0, &mut [0u64; 1]);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::rapid_const::{rapidhash_inline, RAPID_SECRET, RAPID_SEED};
+
+/// Checksum a storage block or page, binding the checksum to the block's index.
+///
+/// A plain `rapidhash(page)` only detects corrupted bytes. A page that is intact but was written
+/// to, or read from, the wrong offset still verifies. Folding the block index into the seed means
+/// the checksum only verifies at the index it was computed for, so misdirected writes and reads are
+/// caught as well.
+///
+/// The index is multiplied by an odd constant and xored into the seed, which is a bijection, so no
+/// two indices share a seed. The result is therefore equal to [crate::rapidhash_seeded] with
+/// `RAPID_SEED ^ block_index.wrapping_mul(RAPID_SECRET[2])`.
+///
+/// # Example
+/// ```
+/// use rapidhash::{checksum_block, verify_block};
+///
+/// let page = [7u8; 4096];
+/// let checksum = checksum_block(&page, 12);
+///
+/// assert!(verify_block(&page, 12, checksum));
+/// assert!(!verify_block(&page, 13, checksum));
+/// ```
+#[inline]
+#[must_use]
+pub const fn checksum_block(data: &[u8], block_index: u64) -> u64 {
+    checksum_block_seeded(data, block_index, RAPID_SEED)
+}
+
+/// Checksum a storage block or page with a custom seed, binding the checksum to the block's index.
+///
+/// A per-file or per-volume seed additionally catches blocks copied between files. See
+/// [checksum_block] for more details.
+#[inline]
+#[must_use]
+pub const fn checksum_block_seeded(data: &[u8], block_index: u64, seed: u64) -> u64 {
+    rapidhash_inline(data, seed ^ block_index.wrapping_mul(RAPID_SECRET[2]))
+}
+
+/// Verify a block against a checksum from [checksum_block] at the same index.
+#[inline]
+#[must_use]
+pub const fn verify_block(data: &[u8], block_index: u64, checksum: u64) -> bool {
+    checksum_block(data, block_index) == checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_changes_checksum() {
+        let page = [0u8; 512];
+        let mut seen = [0u64; 64];
+        for (i, s) in seen.iter_mut().enumerate() {
+            *s = checksum_block(&page, i as u64);
+        }
+        for i in 0..seen.len() {
+            for j in i + 1..seen.len() {
+                assert_ne!(seen[i], seen[j]);
+            }
+        }
+
+        assert_eq!(checksum_block(&page, 0), crate::rapidhash(&page));
+        assert_ne!(checksum_block_seeded(&page, 3, 1), checksum_block(&page, 3));
+    }
+
+    #[test]
+    fn verify() {
+        let mut page = [1u8; 100];
+        let checksum = checksum_block(&page, 5);
+        assert!(verify_block(&page, 5, checksum));
+        assert!(!verify_block(&page, u64::MAX, checksum));
+        page[50] ^= 1;
+        assert!(!verify_block(&page, 5, checksum));
+    }
+}
@@ -0,0 +1,67 @@
+use bytes::Buf;
+use crate::rapid_const::RAPID_SEED;
+use crate::RapidStreamHasher;
+
+/// Hash the remaining bytes of a [bytes::Buf] with the default seed, walking its chunks without
+/// copying them into contiguous memory.
+///
+/// The result is equal to [crate::rapidhash] of the contiguous payload, so a network service can
+/// checksum a chained or segmented buffer without calling `copy_to_bytes` first.
+///
+/// The buffer is consumed as it is hashed. `Bytes` and `BytesMut` are cheap to clone, or pass a
+/// `&mut` reference to hash and advance a buffer in place.
+///
+/// Requires the `bytes` feature.
+///
+/// # Example
+/// ```
+/// use bytes::{Buf, Bytes};
+/// use rapidhash::{rapidhash, rapidhash_buf};
+///
+/// let rope = Bytes::from_static(b"hello ").chain(Bytes::from_static(b"world"));
+/// assert_eq!(rapidhash_buf(rope), rapidhash(b"hello world"));
+/// ```
+#[inline]
+pub fn rapidhash_buf(buf: impl Buf) -> u64 {
+    rapidhash_buf_seeded(buf, RAPID_SEED)
+}
+
+/// Hash the remaining bytes of a [bytes::Buf] with a custom seed, walking its chunks without
+/// copying them into contiguous memory.
+///
+/// The result is equal to [crate::rapidhash_seeded] of the contiguous payload. See
+/// [rapidhash_buf] for more details.
+pub fn rapidhash_buf_seeded(mut buf: impl Buf, seed: u64) -> u64 {
+    let mut hasher = RapidStreamHasher::new_seeded(buf.remaining() as u64, seed);
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+        let len = chunk.len();
+        hasher.write(chunk);
+        buf.advance(len);
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, Bytes};
+    use super::*;
+
+    #[test]
+    fn buf_matches_oneshot() {
+        let data: [u8; 300] = core::array::from_fn(|i| i as u8);
+        for split in [0, 1, 16, 47, 48, 97, 150, 299, 300] {
+            let (a, b) = data.split_at(split);
+            let rope = Bytes::copy_from_slice(a).chain(Bytes::copy_from_slice(b)).chain(&[][..]);
+            assert_eq!(rapidhash_buf_seeded(rope, 7), crate::rapidhash_seeded(&data, 7), "split {split}");
+        }
+        assert_eq!(rapidhash_buf(&b""[..]), crate::rapidhash(b""));
+    }
+
+    #[test]
+    fn buf_by_reference_advances() {
+        let mut buf = &b"hello world"[..];
+        assert_eq!(rapidhash_buf(&mut buf), crate::rapidhash(b"hello world"));
+        assert!(!buf.has_remaining());
+    }
+}
@@ -0,0 +1,275 @@
+use crate::rapid_const::{rapid_mix, rapidhash_inline, RAPID_SECRET, RAPID_SEED};
+
+/// The gear table used by the rolling hash, one pseudo-random u64 per byte value, derived from the
+/// rapid secrets.
+const GEAR: [u64; 256] = gear_table();
+
+const fn gear_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = rapid_mix(i as u64 ^ RAPID_SECRET[0], RAPID_SECRET[1]);
+        i += 1;
+    }
+    table
+}
+
+/// The default minimum chunk size, 2 KiB.
+const DEFAULT_MIN_SIZE: usize = 2 * 1024;
+
+/// The default average chunk size, 8 KiB.
+const DEFAULT_AVG_SIZE: usize = 8 * 1024;
+
+/// The default maximum chunk size, 64 KiB.
+const DEFAULT_MAX_SIZE: usize = 64 * 1024;
+
+/// A chunk of the input produced by the [RapidChunker].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RapidChunk {
+    /// The byte offset of this chunk in the input.
+    pub offset: usize,
+    /// The length of this chunk in bytes.
+    pub len: usize,
+    /// The [crate::rapidhash_seeded] of the chunk's bytes.
+    pub hash: u64,
+}
+
+impl RapidChunk {
+    /// The byte range of this chunk in the input.
+    #[inline]
+    pub const fn range(&self) -> core::ops::Range<usize> {
+        self.offset..self.offset + self.len
+    }
+}
+
+/// A FastCDC-style content-defined chunker, which splits the input into variable-sized chunks and
+/// hashes each chunk with rapidhash.
+///
+/// Chunk boundaries are chosen by a gear rolling hash over the content, rather than at fixed
+/// offsets, so inserting or removing bytes only changes the chunks around the edit. This makes it a
+/// building block for deduplication and incremental sync tools, where only chunks with unseen
+/// hashes need to be stored or transferred.
+///
+/// Chunks are between the minimum and maximum sizes, except for the final chunk which may be
+/// smaller than the minimum. The chunk boundaries are stable across versions of this crate, as the
+/// gear table and boundary condition are derived from the fixed rapid secrets. The seed only
+/// affects the per-chunk hashes, not the boundaries.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash, RapidChunker};
+///
+/// let data = vec![7u8; 100_000];
+///
+/// let mut offset = 0;
+/// for chunk in RapidChunker::new(&data) {
+///     assert_eq!(chunk.offset, offset);
+///     assert_eq!(chunk.hash, rapidhash(&data[chunk.range()]));
+///     offset += chunk.len;
+/// }
+/// assert_eq!(offset, data.len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RapidChunker<'a> {
+    data: &'a [u8],
+    offset: usize,
+    seed: u64,
+    min_size: usize,
+    avg_size: usize,
+    max_size: usize,
+    /// The stricter mask, used before reaching the average size.
+    mask_small: u64,
+    /// The looser mask, used after reaching the average size.
+    mask_large: u64,
+}
+
+impl<'a> RapidChunker<'a> {
+    /// Create a new [RapidChunker] with the default seed and chunk sizes of 2 KiB minimum, 8 KiB
+    /// average, and 64 KiB maximum.
+    #[inline]
+    #[must_use]
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self::new_seeded(data, RAPID_SEED)
+    }
+
+    /// Create a new [RapidChunker] with a custom seed for the per-chunk hashes, and the default
+    /// chunk sizes.
+    #[inline]
+    #[must_use]
+    pub const fn new_seeded(data: &'a [u8], seed: u64) -> Self {
+        Self {
+            data,
+            offset: 0,
+            seed,
+            min_size: 0,
+            avg_size: 0,
+            max_size: 0,
+            mask_small: 0,
+            mask_large: 0,
+        }.with_sizes(DEFAULT_MIN_SIZE, DEFAULT_AVG_SIZE, DEFAULT_MAX_SIZE)
+    }
+
+    /// Set custom minimum, average, and maximum chunk sizes in bytes.
+    ///
+    /// The average size is rounded down to a power of two when choosing the boundary condition.
+    ///
+    /// # Panics
+    /// Panics unless `0 < min_size <= avg_size <= max_size` and `64 <= avg_size < 2^62`.
+    #[must_use]
+    pub const fn with_sizes(mut self, min_size: usize, avg_size: usize, max_size: usize) -> Self {
+        assert!(min_size > 0 && min_size <= avg_size && avg_size <= max_size, "RapidChunker: invalid chunk sizes");
+        assert!(avg_size >= 64, "RapidChunker: average chunk size must be at least 64 bytes");
+
+        // normalized chunking: a stricter mask before the average size and a looser one after it
+        // pulls the chunk size distribution towards the average
+        let bits = avg_size.ilog2();
+        // the stricter mask needs `bits + 2` bits of the 64-bit gear hash
+        assert!(bits + 2 < u64::BITS, "RapidChunker: average chunk size must be less than 2^62 bytes");
+        self.min_size = min_size;
+        self.avg_size = avg_size;
+        self.max_size = max_size;
+        self.mask_small = !(u64::MAX >> (bits + 2));
+        self.mask_large = !(u64::MAX >> (bits - 2));
+        self
+    }
+
+    /// Find the length of the next chunk at the start of `data`.
+    #[inline]
+    fn cut_point(&self, data: &[u8]) -> usize {
+        if data.len() <= self.min_size {
+            return data.len();
+        }
+
+        let normal = data.len().min(self.avg_size);
+        let max = data.len().min(self.max_size);
+
+        // the gear hash shifts left, so the high bits depend on the most recent 64 bytes, and a
+        // boundary is found when they match the secret
+        let target = RAPID_SECRET[2];
+        let mut fingerprint = 0u64;
+        let mut i = self.min_size;
+
+        while i < normal {
+            fingerprint = (fingerprint << 1).wrapping_add(GEAR[data[i] as usize]);
+            if (fingerprint ^ target) & self.mask_small == 0 {
+                return i + 1;
+            }
+            i += 1;
+        }
+
+        while i < max {
+            fingerprint = (fingerprint << 1).wrapping_add(GEAR[data[i] as usize]);
+            if (fingerprint ^ target) & self.mask_large == 0 {
+                return i + 1;
+            }
+            i += 1;
+        }
+
+        max
+    }
+}
+
+impl Iterator for RapidChunker<'_> {
+    type Item = RapidChunk;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = &self.data[self.offset..];
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let len = self.cut_point(remaining);
+        let chunk = RapidChunk {
+            offset: self.offset,
+            len,
+            hash: rapidhash_inline(&remaining[..len], self.seed),
+        };
+        self.offset += len;
+        Some(chunk)
+    }
+}
+
+impl core::iter::FusedIterator for RapidChunker<'_> {}
+
+#[cfg(all(test, feature = "rng"))]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+    use super::*;
+    use crate::{rapidhash_seeded, RapidRng};
+
+    fn random_bytes(len: usize, seed: u64) -> Vec<u8> {
+        let mut rng = RapidRng::new(seed);
+        (0..len).map(|_| rng.next() as u8).collect()
+    }
+
+    #[test]
+    fn chunks_cover_input() {
+        let data = random_bytes(1_000_000, 1);
+
+        let mut offset = 0;
+        let mut count = 0;
+        for chunk in RapidChunker::new_seeded(&data, 5) {
+            assert_eq!(chunk.offset, offset);
+            assert!(chunk.len <= DEFAULT_MAX_SIZE);
+            assert!(chunk.len >= DEFAULT_MIN_SIZE || chunk.offset + chunk.len == data.len());
+            assert_eq!(chunk.hash, rapidhash_seeded(&data[chunk.range()], 5));
+            offset += chunk.len;
+            count += 1;
+        }
+        assert_eq!(offset, data.len());
+
+        // the average chunk size should be in the right ballpark
+        let average = data.len() / count;
+        assert!(average > DEFAULT_AVG_SIZE / 2 && average < DEFAULT_AVG_SIZE * 2, "average chunk size {average}");
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(RapidChunker::new(&[]).next(), None);
+    }
+
+    /// Inserting bytes near the start should only change the chunks around the edit.
+    #[test]
+    fn boundaries_resynchronise_after_edit() {
+        let data = random_bytes(500_000, 2);
+        let mut edited = data.clone();
+        edited.splice(1000..1000, [1, 2, 3, 4, 5]);
+
+        let original: std::collections::BTreeSet<u64> = RapidChunker::new(&data).map(|c| c.hash).collect();
+        let chunks: Vec<RapidChunk> = RapidChunker::new(&edited).collect();
+        let changed = chunks.iter().filter(|c| !original.contains(&c.hash)).count();
+
+        assert!(changed <= 2, "{changed} of {} chunks changed", chunks.len());
+    }
+
+    #[test]
+    fn custom_sizes() {
+        let data = random_bytes(100_000, 3);
+        for chunk in RapidChunker::new(&data).with_sizes(64, 256, 1024) {
+            assert!(chunk.len <= 1024);
+            assert!(chunk.len >= 64 || chunk.offset + chunk.len == data.len());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_sizes_panic() {
+        let _ = RapidChunker::new(&[]).with_sizes(1024, 512, 2048);
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    #[should_panic(expected = "less than 2^62")]
+    fn huge_average_size_panics() {
+        let _ = RapidChunker::new(&[]).with_sizes(64, 1 << 62, usize::MAX);
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn largest_average_size() {
+        let _ = RapidChunker::new(&[]).with_sizes(64, (1 << 62) - 1, usize::MAX);
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::io::{Read, Write};
+use std::vec::Vec;
+use crate::rapid_const::RAPID_SEED;
+use crate::{RapidHashingReader, RapidHashingWriter};
+
+/// The size of the little-endian `u64` length header, and of the hash trailer.
+pub(crate) const FRAME_WORD: usize = 8;
+
+/// The error returned when reading or verifying a checksummed frame.
+#[derive(Debug)]
+pub enum ChecksumError {
+    /// The input ended before the end of the frame.
+    Truncated,
+    /// The payload doesn't match the hash in its trailer, so the frame has been corrupted.
+    Mismatch {
+        /// The hash stored in the frame's trailer.
+        expected: u64,
+        /// The hash of the payload that was read.
+        actual: u64,
+    },
+    /// The frame's length header exceeds the configured maximum. Only returned by the
+    /// `RapidFrameCodec` of the `tokio-codec` feature.
+    TooLong {
+        /// The payload length in the frame's header.
+        len: u64,
+        /// The maximum payload length.
+        max: usize,
+    },
+    /// Reading from the underlying reader failed.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "checksummed frame is truncated"),
+            Self::Mismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected {expected:#018x}, got {actual:#018x}",
+            ),
+            Self::TooLong { len, max } => write!(
+                f,
+                "checksummed frame of {len} bytes exceeds the maximum of {max}",
+            ),
+            Self::Io(err) => write!(f, "failed to read checksummed frame: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ChecksumError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ChecksumError {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::UnexpectedEof => Self::Truncated,
+            _ => Self::Io(err),
+        }
+    }
+}
+
+/// Write `bytes` as a checksummed frame with the default seed: the payload length as a
+/// little-endian `u64`, the payload, and then the [crate::rapidhash] of the payload as a
+/// little-endian `u64` trailer.
+///
+/// Read the frame back with [read_checksummed], or check an in-memory frame with
+/// [verify_checksummed]. Frames are self-delimiting, so several can be written to the same file or
+/// socket. This detects accidental corruption such as truncated writes and flipped bits, but as
+/// rapidhash is not a cryptographic hash it doesn't protect against deliberate tampering.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::{read_checksummed, write_checksummed, ChecksumError};
+///
+/// let mut file = Vec::new();
+/// write_checksummed(&mut file, b"first").unwrap();
+/// write_checksummed(&mut file, b"second").unwrap();
+///
+/// let mut reader = &file[..];
+/// assert_eq!(read_checksummed(&mut reader).unwrap(), b"first");
+/// assert_eq!(read_checksummed(&mut reader).unwrap(), b"second");
+///
+/// // flip a bit in the first payload
+/// file[8] ^= 1;
+/// assert!(matches!(read_checksummed(&file[..]), Err(ChecksumError::Mismatch { .. })));
+/// ```
+#[inline]
+pub fn write_checksummed(writer: impl Write, bytes: &[u8]) -> std::io::Result<()> {
+    write_checksummed_seeded(writer, bytes, RAPID_SEED)
+}
+
+/// Write `bytes` as a checksummed frame with a custom seed. See [write_checksummed].
+pub fn write_checksummed_seeded(mut writer: impl Write, bytes: &[u8], seed: u64) -> std::io::Result<()> {
+    writer.write_all(&(bytes.len() as u64).to_le_bytes())?;
+
+    let mut payload = RapidHashingWriter::new_seeded(&mut writer, bytes.len() as u64, seed);
+    payload.write_all(bytes)?;
+    let hash = payload.hash().expect("the whole payload was written");
+
+    writer.write_all(&hash.to_le_bytes())
+}
+
+/// Read one checksummed frame written by [write_checksummed] with the default seed, and return
+/// its payload once it's been verified.
+///
+/// The payload is hashed as it's read, and exactly one frame is consumed from the reader. The
+/// buffer grows as the payload arrives rather than trusting the length header up front, so a
+/// corrupted header can't trigger a huge allocation.
+///
+/// Requires the `std` feature.
+#[inline]
+pub fn read_checksummed(reader: impl Read) -> Result<Vec<u8>, ChecksumError> {
+    read_checksummed_seeded(reader, RAPID_SEED)
+}
+
+/// Read one checksummed frame written with a custom seed. See [read_checksummed].
+pub fn read_checksummed_seeded(mut reader: impl Read, seed: u64) -> Result<Vec<u8>, ChecksumError> {
+    let mut word = [0u8; FRAME_WORD];
+    reader.read_exact(&mut word)?;
+    let len = u64::from_le_bytes(word);
+
+    let mut payload = RapidHashingReader::new_seeded(&mut reader, len, seed);
+    let mut bytes = Vec::new();
+    payload.read_to_end(&mut bytes)?;
+    let actual = payload.hash().ok_or(ChecksumError::Truncated)?;
+
+    reader.read_exact(&mut word)?;
+    let expected = u64::from_le_bytes(word);
+    match expected == actual {
+        true => Ok(bytes),
+        false => Err(ChecksumError::Mismatch { expected, actual }),
+    }
+}
+
+/// Verify a checksummed frame held in memory, such as a memory-mapped file, and return its
+/// payload without copying.
+///
+/// `frame` must be exactly one frame written by [write_checksummed] with the default seed.
+///
+/// Requires the `std` feature.
+#[inline]
+pub fn verify_checksummed(frame: &[u8]) -> Result<&[u8], ChecksumError> {
+    verify_checksummed_seeded(frame, RAPID_SEED)
+}
+
+/// Verify a checksummed frame written with a custom seed. See [verify_checksummed].
+pub fn verify_checksummed_seeded(frame: &[u8], seed: u64) -> Result<&[u8], ChecksumError> {
+    let Some(body) = frame.len().checked_sub(2 * FRAME_WORD) else {
+        return Err(ChecksumError::Truncated);
+    };
+
+    let (header, rest) = frame.split_at(FRAME_WORD);
+    let (payload, trailer) = rest.split_at(body);
+    if u64::from_le_bytes(header.try_into().unwrap()) != body as u64 {
+        return Err(ChecksumError::Truncated);
+    }
+
+    let expected = u64::from_le_bytes(trailer.try_into().unwrap());
+    let actual = crate::rapidhash_seeded(payload, seed);
+    match expected == actual {
+        true => Ok(payload),
+        false => Err(ChecksumError::Mismatch { expected, actual }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut frame = Vec::new();
+        write_checksummed_seeded(&mut frame, b"hello world", 7).unwrap();
+        assert_eq!(frame.len(), 11 + 16);
+        assert_eq!(&frame[..8], 11u64.to_le_bytes());
+        assert_eq!(&frame[19..], crate::rapidhash_seeded(b"hello world", 7).to_le_bytes());
+
+        assert_eq!(read_checksummed_seeded(&frame[..], 7).unwrap(), b"hello world");
+        assert_eq!(verify_checksummed_seeded(&frame, 7).unwrap(), b"hello world");
+        assert!(matches!(verify_checksummed(&frame), Err(ChecksumError::Mismatch { .. })));
+
+        let mut empty = Vec::new();
+        write_checksummed(&mut empty, b"").unwrap();
+        assert_eq!(read_checksummed(&empty[..]).unwrap(), b"");
+        assert_eq!(verify_checksummed(&empty).unwrap(), b"");
+    }
+
+    #[test]
+    fn detects_corruption() {
+        let mut frame = Vec::new();
+        write_checksummed(&mut frame, b"hello world").unwrap();
+
+        for end in 0..frame.len() {
+            assert!(matches!(read_checksummed(&frame[..end]), Err(ChecksumError::Truncated)), "end {end}");
+            assert!(matches!(verify_checksummed(&frame[..end]), Err(ChecksumError::Truncated)), "end {end}");
+        }
+
+        let mut flipped = frame.clone();
+        flipped[12] ^= 0x10;
+        assert!(matches!(read_checksummed(&flipped[..]), Err(ChecksumError::Mismatch { .. })));
+        assert!(matches!(verify_checksummed(&flipped), Err(ChecksumError::Mismatch { .. })));
+
+        // a corrupted length header reads too little or too much
+        let mut header = frame.clone();
+        header[0] = 10;
+        assert!(read_checksummed(&header[..]).is_err());
+        assert!(matches!(verify_checksummed(&header), Err(ChecksumError::Truncated)));
+    }
+}
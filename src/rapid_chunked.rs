@@ -0,0 +1,335 @@
+use std::collections::BTreeSet;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::Path;
+use std::vec::Vec;
+use crate::rapid_const::{rapidhash_inline, RAPID_SEED};
+
+/// The hashes of consecutive fixed-size chunks of a file or buffer, plus a combined root hash.
+///
+/// Comparing chunk lists tells rsync-style tools which ranges of a large file differ, and since
+/// every chunk is hashed independently, verification can be split across threads. Unlike the
+/// content-defined [crate::RapidChunker], chunk boundaries are at fixed offsets, so an insertion
+/// shifts every later chunk, but a chunk's byte range can be computed from its index alone.
+///
+/// Each chunk hash is [crate::rapidhash_seeded] of the chunk's bytes. Every chunk is `chunk_size`
+/// bytes, except the last which may be shorter. An empty input has no chunks. The [root](Self::root)
+/// is [crate::rapidhash_seeded] of the chunk size and the total length as little-endian `u64`s,
+/// followed by every chunk hash as a little-endian `u64`.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidChunkedHash;
+///
+/// let old = RapidChunkedHash::from_bytes(&[0u8; 10_000], 4096);
+///
+/// let mut data = [0u8; 10_000];
+/// data[5000] = 1;
+/// let new = RapidChunkedHash::from_bytes(&data, 4096);
+///
+/// assert_eq!(old.chunks().len(), 3);
+/// assert_ne!(old.root(), new.root());
+/// assert_eq!(old.changed_chunks(&new), vec![1]);
+/// assert_eq!(new.chunk_range(1), 4096..8192);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RapidChunkedHash {
+    chunk_size: u64,
+    len: u64,
+    seed: u64,
+    chunks: Vec<u64>,
+}
+
+impl RapidChunkedHash {
+    /// Hash an in-memory buffer in chunks of `chunk_size` bytes, with the default seed.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is `0`.
+    #[must_use]
+    pub fn from_bytes(data: &[u8], chunk_size: usize) -> Self {
+        Self::from_bytes_seeded(data, chunk_size, RAPID_SEED)
+    }
+
+    /// Hash an in-memory buffer in chunks of `chunk_size` bytes, with a custom seed.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is `0`.
+    #[must_use]
+    pub fn from_bytes_seeded(data: &[u8], chunk_size: usize, seed: u64) -> Self {
+        assert!(chunk_size > 0, "RapidChunkedHash: chunk_size must be non-zero");
+        Self {
+            chunk_size: chunk_size as u64,
+            len: data.len() as u64,
+            seed,
+            chunks: data.chunks(chunk_size).map(|chunk| rapidhash_inline(chunk, seed)).collect(),
+        }
+    }
+
+    /// Hash a file in chunks of `chunk_size` bytes, with the default seed, holding one chunk in
+    /// memory at a time.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is `0`.
+    pub fn from_file(path: impl AsRef<Path>, chunk_size: usize) -> io::Result<Self> {
+        Self::from_file_seeded(path, chunk_size, RAPID_SEED)
+    }
+
+    /// Hash a file in chunks of `chunk_size` bytes, with a custom seed.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is `0`.
+    pub fn from_file_seeded(path: impl AsRef<Path>, chunk_size: usize, seed: u64) -> io::Result<Self> {
+        Self::from_reader_seeded(std::fs::File::open(path)?, chunk_size, seed)
+    }
+
+    /// Hash everything read from `reader` until EOF in chunks of `chunk_size` bytes, with the
+    /// default seed. The total length doesn't need to be known up front.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is `0`.
+    pub fn from_reader(reader: impl Read, chunk_size: usize) -> io::Result<Self> {
+        Self::from_reader_seeded(reader, chunk_size, RAPID_SEED)
+    }
+
+    /// Hash everything read from `reader` until EOF in chunks of `chunk_size` bytes, with a custom
+    /// seed.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is `0`.
+    pub fn from_reader_seeded(mut reader: impl Read, chunk_size: usize, seed: u64) -> io::Result<Self> {
+        assert!(chunk_size > 0, "RapidChunkedHash: chunk_size must be non-zero");
+        let mut buffer = std::vec![0u8; chunk_size];
+        let mut chunks = Vec::new();
+        let mut len = 0;
+
+        loop {
+            let filled = read_full(&mut reader, &mut buffer)?;
+            if filled == 0 {
+                break;
+            }
+            chunks.push(rapidhash_inline(&buffer[..filled], seed));
+            len += filled as u64;
+            if filled < chunk_size {
+                break;
+            }
+        }
+
+        Ok(Self { chunk_size: chunk_size as u64, len, seed, chunks })
+    }
+
+    /// The size of every chunk except the last.
+    #[inline]
+    pub fn chunk_size(&self) -> u64 {
+        self.chunk_size
+    }
+
+    /// The total length of the hashed input in bytes.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the hashed input was empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The seed used to hash each chunk and the root.
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The hash of each chunk, in order.
+    #[inline]
+    pub fn chunks(&self) -> &[u64] {
+        &self.chunks
+    }
+
+    /// The byte range of chunk `index` in the input.
+    pub fn chunk_range(&self, index: usize) -> Range<u64> {
+        let start = index as u64 * self.chunk_size;
+        start..(start + self.chunk_size).min(self.len)
+    }
+
+    /// The combined root hash of the chunk size, total length, and every chunk hash.
+    pub fn root(&self) -> u64 {
+        let mut records = Vec::with_capacity(16 + 8 * self.chunks.len());
+        records.extend_from_slice(&self.chunk_size.to_le_bytes());
+        records.extend_from_slice(&self.len.to_le_bytes());
+        for hash in &self.chunks {
+            records.extend_from_slice(&hash.to_le_bytes());
+        }
+        rapidhash_inline(&records, self.seed)
+    }
+
+    /// Bring the chunk hashes up to date with a modified file, re-reading only the chunks that
+    /// overlap the `changed` byte ranges, and return the indices of the re-hashed chunks.
+    ///
+    /// The ranges are offsets in the file's new contents, such as the writes reported by a file
+    /// watcher. If the file's length changed, the chunks from the old end of the file onwards are
+    /// re-hashed too, so appends and truncations don't need to be listed. Every other chunk is
+    /// assumed unchanged and is not read, so the result is only correct if `changed` covers every
+    /// modified byte. The [root](Self::root) reflects the update once this returns.
+    ///
+    /// For edits that insert or remove bytes in the middle of a file, every later chunk shifts, and
+    /// the range from the edit to the end of the file must be listed.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use rapidhash::RapidChunkedHash;
+    ///
+    /// let mut data = vec![0u8; 100_000];
+    /// let mut chunked = RapidChunkedHash::from_bytes(&data, 4096);
+    ///
+    /// data[50_000] = 1;
+    /// let rehashed = chunked.update(Cursor::new(&data), &[50_000..50_001]).unwrap();
+    ///
+    /// assert_eq!(rehashed, vec![12]);
+    /// assert_eq!(chunked, RapidChunkedHash::from_bytes(&data, 4096));
+    /// ```
+    pub fn update(&mut self, mut source: impl Read + Seek, changed: &[Range<u64>]) -> io::Result<Vec<usize>> {
+        let new_len = source.seek(SeekFrom::End(0))?;
+        let new_count = ((new_len + self.chunk_size - 1) / self.chunk_size) as usize;
+
+        let mut affected = BTreeSet::new();
+        for range in changed {
+            let end = range.end.min(new_len);
+            if range.start < end {
+                let first = (range.start / self.chunk_size) as usize;
+                let last = ((end - 1) / self.chunk_size) as usize;
+                affected.extend(first..=last);
+            }
+        }
+        if new_len != self.len {
+            // the old final chunk may have been partial, and every chunk after it is new
+            let first = (self.len.min(new_len) / self.chunk_size) as usize;
+            affected.extend(first..new_count);
+        }
+
+        self.chunks.resize(new_count, 0);
+        self.len = new_len;
+
+        let mut buffer = std::vec![0u8; self.chunk_size as usize];
+        for &index in &affected {
+            let range = self.chunk_range(index);
+            let chunk = &mut buffer[..(range.end - range.start) as usize];
+            source.seek(SeekFrom::Start(range.start))?;
+            source.read_exact(chunk)?;
+            self.chunks[index] = rapidhash_inline(chunk, self.seed);
+        }
+
+        Ok(affected.into_iter().collect())
+    }
+
+    /// Bring the chunk hashes up to date with a modified file on disk. See [RapidChunkedHash::update].
+    pub fn update_file(&mut self, path: impl AsRef<Path>, changed: &[Range<u64>]) -> io::Result<Vec<usize>> {
+        self.update(std::fs::File::open(path)?, changed)
+    }
+
+    /// The indices of chunks that differ from `other`, including chunks present in only one of the
+    /// two, for deciding which ranges to transfer.
+    ///
+    /// Both must use the same chunk size and seed for the comparison to be meaningful.
+    pub fn changed_chunks(&self, other: &Self) -> Vec<usize> {
+        let count = self.chunks.len().max(other.chunks.len());
+        (0..count).filter(|&i| self.chunks.get(i) != other.chunks.get(i)).collect()
+    }
+}
+
+/// Read until `buffer` is full or the reader is exhausted, returning the number of bytes read.
+fn read_full(reader: &mut impl Read, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reader returning at most 7 bytes per read, to exercise short reads.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(7).min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn chunks_match_oneshot() {
+        let data: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+        let chunked = RapidChunkedHash::from_bytes(&data, 300);
+        assert_eq!(chunked.chunks().len(), 4);
+        for (i, hash) in chunked.chunks().iter().enumerate() {
+            let range = chunked.chunk_range(i);
+            assert_eq!(*hash, crate::rapidhash(&data[range.start as usize..range.end as usize]));
+        }
+        assert_eq!(chunked.chunk_range(3), 900..1000);
+
+        let read = RapidChunkedHash::from_reader(Trickle(&data), 300).unwrap();
+        assert_eq!(read, chunked);
+        assert_eq!(read.root(), chunked.root());
+    }
+
+    #[test]
+    fn root_depends_on_layout() {
+        let data = [5u8; 1000];
+        assert_ne!(RapidChunkedHash::from_bytes(&data, 100).root(), RapidChunkedHash::from_bytes(&data, 200).root());
+        assert_ne!(RapidChunkedHash::from_bytes(&data, 100).root(), RapidChunkedHash::from_bytes_seeded(&data, 100, 1).root());
+
+        let empty = RapidChunkedHash::from_bytes(&[], 100);
+        assert!(empty.is_empty());
+        assert!(empty.chunks().is_empty());
+    }
+
+    #[test]
+    fn update_rehashes_affected_chunks() {
+        let mut data: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+        let mut chunked = RapidChunkedHash::from_bytes(&data, 100);
+
+        data[150] ^= 1;
+        data[420] ^= 1;
+        data[499] ^= 1;
+        let rehashed = chunked.update(io::Cursor::new(&data), &[150..151, 420..500]).unwrap();
+        assert_eq!(rehashed, [1, 4]);
+        assert_eq!(chunked, RapidChunkedHash::from_bytes(&data, 100));
+
+        // appending re-hashes the old partial chunk and the new chunks
+        data.truncate(950);
+        let mut chunked = RapidChunkedHash::from_bytes(&data, 100);
+        data.extend_from_slice(&[9u8; 200]);
+        assert_eq!(chunked.update(io::Cursor::new(&data), &[]).unwrap(), [9, 10, 11]);
+        assert_eq!(chunked, RapidChunkedHash::from_bytes(&data, 100));
+
+        // truncating re-hashes the new final chunk
+        data.truncate(420);
+        assert_eq!(chunked.update(io::Cursor::new(&data), &[]).unwrap(), [4]);
+        assert_eq!(chunked, RapidChunkedHash::from_bytes(&data, 100));
+        assert_eq!(chunked.root(), RapidChunkedHash::from_bytes(&data, 100).root());
+    }
+
+    #[test]
+    fn changed_chunks() {
+        let a = RapidChunkedHash::from_bytes(&[0u8; 1000], 100);
+        let mut data = [0u8; 1100];
+        data[250] = 1;
+        let b = RapidChunkedHash::from_bytes(&data, 100);
+        assert_eq!(a.changed_chunks(&b), [2, 10]);
+    }
+}
@@ -0,0 +1,188 @@
+use bytes::{Buf, BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+use crate::rapid_checksum::FRAME_WORD;
+use crate::rapid_const::RAPID_SEED;
+use crate::ChecksumError;
+
+/// The default maximum payload length of a [RapidFrameCodec], 8 MiB.
+const DEFAULT_MAX_FRAME: usize = 8 * 1024 * 1024;
+
+/// A [tokio_util::codec] encoder and decoder for length-prefixed frames with a rapidhash checksum,
+/// for corruption detection on internal TCP links.
+///
+/// Frames use the same format as [crate::write_checksummed]: the payload length as a little-endian
+/// `u64`, the payload, and then the [crate::rapidhash_seeded] of the payload as a little-endian
+/// `u64` trailer. Decoding a frame whose payload doesn't match its trailer returns
+/// [ChecksumError::Mismatch], and a length header above the maximum returns
+/// [ChecksumError::TooLong] before any of the payload is buffered.
+///
+/// Rapidhash is not a cryptographic hash, so this detects accidental corruption but not deliberate
+/// tampering. Use TLS on untrusted links.
+///
+/// Requires the `tokio-codec` feature.
+///
+/// # Example
+/// ```
+/// use bytes::BytesMut;
+/// use tokio_util::codec::{Decoder, Encoder};
+/// use rapidhash::RapidFrameCodec;
+///
+/// let mut codec = RapidFrameCodec::new();
+/// let mut buf = BytesMut::new();
+/// codec.encode(&b"hello world"[..], &mut buf).unwrap();
+///
+/// let frame = codec.decode(&mut buf).unwrap().unwrap();
+/// assert_eq!(&frame[..], b"hello world");
+///
+/// // with a socket, wrap it in a Framed stream and sink instead:
+/// // let framed = tokio_util::codec::Framed::new(socket, RapidFrameCodec::new());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidFrameCodec {
+    seed: u64,
+    max_frame_len: usize,
+}
+
+impl RapidFrameCodec {
+    /// Create a codec with the default seed and a maximum payload length of 8 MiB.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::new_seeded(RAPID_SEED)
+    }
+
+    /// Create a codec with a custom seed and a maximum payload length of 8 MiB. Both ends of a
+    /// link must use the same seed.
+    #[inline]
+    #[must_use]
+    pub const fn new_seeded(seed: u64) -> Self {
+        Self { seed, max_frame_len: DEFAULT_MAX_FRAME }
+    }
+
+    /// Set the maximum payload length. Longer frames fail to encode or decode with
+    /// [ChecksumError::TooLong].
+    #[inline]
+    #[must_use]
+    pub const fn max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.max_frame_len = max_frame_len;
+        self
+    }
+
+    /// The maximum payload length.
+    #[inline]
+    pub const fn get_max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+}
+
+impl Default for RapidFrameCodec {
+    /// Create a codec with the default seed and a maximum payload length of 8 MiB.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for RapidFrameCodec {
+    type Item = BytesMut;
+    type Error = ChecksumError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if src.len() < FRAME_WORD {
+            return Ok(None);
+        }
+
+        let len = u64::from_le_bytes(src[..FRAME_WORD].try_into().unwrap());
+        if len > self.max_frame_len as u64 {
+            return Err(ChecksumError::TooLong { len, max: self.max_frame_len });
+        }
+
+        let frame_len = 2 * FRAME_WORD + len as usize;
+        if src.len() < frame_len {
+            src.reserve(frame_len - src.len());
+            return Ok(None);
+        }
+
+        let mut payload = src.split_to(frame_len);
+        payload.advance(FRAME_WORD);
+        let trailer = payload.split_off(len as usize);
+
+        let expected = u64::from_le_bytes(trailer[..].try_into().unwrap());
+        let actual = crate::rapidhash_seeded(&payload, self.seed);
+        match expected == actual {
+            true => Ok(Some(payload)),
+            false => Err(ChecksumError::Mismatch { expected, actual }),
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Encoder<B> for RapidFrameCodec {
+    type Error = ChecksumError;
+
+    fn encode(&mut self, item: B, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let payload = item.as_ref();
+        if payload.len() > self.max_frame_len {
+            return Err(ChecksumError::TooLong { len: payload.len() as u64, max: self.max_frame_len });
+        }
+
+        dst.reserve(2 * FRAME_WORD + payload.len());
+        dst.put_u64_le(payload.len() as u64);
+        dst.put_slice(payload);
+        dst.put_u64_le(crate::rapidhash_seeded(payload, self.seed));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_checksummed_frames() {
+        let mut codec = RapidFrameCodec::new_seeded(7);
+        let mut buf = BytesMut::new();
+        codec.encode(&b"hello world"[..], &mut buf).unwrap();
+
+        let mut frame = std::vec::Vec::new();
+        crate::write_checksummed_seeded(&mut frame, b"hello world", 7).unwrap();
+        assert_eq!(&buf[..], &frame[..]);
+    }
+
+    #[test]
+    fn decodes_partial_and_multiple_frames() {
+        let mut codec = RapidFrameCodec::new();
+        let mut encoded = BytesMut::new();
+        codec.encode(&b"first"[..], &mut encoded).unwrap();
+        codec.encode(std::vec![7u8; 100], &mut encoded).unwrap();
+        codec.encode(&b""[..], &mut encoded).unwrap();
+
+        // feed the stream a byte at a time
+        let mut src = BytesMut::new();
+        let mut frames = std::vec::Vec::new();
+        for byte in encoded.iter() {
+            src.put_u8(*byte);
+            while let Some(frame) = codec.decode(&mut src).unwrap() {
+                frames.push(frame);
+            }
+        }
+        assert_eq!(frames.len(), 3);
+        assert_eq!(&frames[0][..], b"first");
+        assert_eq!(&frames[1][..], [7u8; 100]);
+        assert!(frames[2].is_empty());
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn rejects_corrupt_and_oversized_frames() {
+        let mut codec = RapidFrameCodec::new().max_frame_len(16);
+        let mut buf = BytesMut::new();
+        codec.encode(&b"hello world"[..], &mut buf).unwrap();
+        buf[10] ^= 1;
+        assert!(matches!(codec.decode(&mut buf), Err(ChecksumError::Mismatch { .. })));
+
+        let mut buf = BytesMut::new();
+        assert!(matches!(codec.encode(&[0u8; 17][..], &mut buf), Err(ChecksumError::TooLong { len: 17, max: 16 })));
+        RapidFrameCodec::new().encode(&[0u8; 17][..], &mut buf).unwrap();
+        assert!(matches!(codec.decode(&mut buf), Err(ChecksumError::TooLong { len: 17, max: 16 })));
+    }
+}
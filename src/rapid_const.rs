@@ -1,6 +1,20 @@
 /// The rapidhash default seed.
 pub const RAPID_SEED: u64 = 0xbdd89aa982704029;
-pub(crate) const RAPID_SECRET: [u64; 3] = [0x2d358dccaa6c78a5, 0x8bb84b93962eacc9, 0x4b33a62ed433d4a3];
+/// The default seed of [crate::RapidHasher] and the other `Hasher` types.
+///
+/// This is [RAPID_SEED], unless the `const-random-seed` feature is enabled, in which case it's
+/// generated at compile time and differs between builds.
+#[cfg(all(any(feature = "hasher", docsrs), not(feature = "const-random-seed")))]
+pub(crate) const RAPID_HASHER_SEED: u64 = RAPID_SEED;
+
+/// The default seed of [crate::RapidHasher] and the other `Hasher` types.
+///
+/// Generated at compile time by the `const-random-seed` feature, so it differs between builds.
+#[cfg(all(any(feature = "hasher", docsrs), feature = "const-random-seed"))]
+pub(crate) const RAPID_HASHER_SEED: u64 = const_random::const_random!(u64);
+
+/// The rapidhash default secrets, used as the mixing constants throughout the algorithm.
+pub const RAPID_SECRET: [u64; 3] = [0x2d358dccaa6c78a5, 0x8bb84b93962eacc9, 0x4b33a62ed433d4a3];
 
 /// Rapidhash a single byte stream, matching the C++ implementation.
 #[inline]
@@ -25,82 +39,99 @@ pub const fn rapidhash_inline(data: &[u8], mut seed: u64) -> u64 {
     rapidhash_finish(a, b, data.len() as u64)
 }
 
+/// Rapidhash a fixed-size byte array, such as a UUID, digest, or IP address.
+///
+/// The length is a const generic, so every length branch is resolved at compile time and the hash
+/// compiles down to a straight line of reads and multiplies. Equal to [rapidhash] of the same bytes.
+#[inline(always)]
+pub const fn rapidhash_array<const N: usize>(data: &[u8; N]) -> u64 {
+    rapidhash_inline(data, RAPID_SEED)
+}
+
+/// Rapidhash a fixed-size byte array with a custom seed. Equal to [rapidhash_seeded] of the same
+/// bytes.
+#[inline(always)]
+pub const fn rapidhash_array_seeded<const N: usize>(data: &[u8; N], seed: u64) -> u64 {
+    rapidhash_inline(data, seed)
+}
+
+/// Derive a 64-bit seed from a string key, such as a namespace, by hashing it with the default seed.
+#[cfg(feature = "hasher")]
+#[inline]
+pub(crate) const fn rapidhash_key_seed(key: &str) -> u64 {
+    rapidhash_inline(key.as_bytes(), RAPID_SEED)
+}
+
+/// Multiply `a` and `b` into a 128-bit product, returning the low and high 64 bits.
+#[cfg(not(target_arch = "wasm32"))]
 #[inline(always)]
 pub const fn rapid_mum(a: u64, b: u64) -> (u64, u64) {
     let r = a as u128 * b as u128;
     (r as u64, (r >> 64) as u64)
 }
 
+/// Multiply `a` and `b` into a 128-bit product, returning the low and high 64 bits.
+///
+/// On wasm32 a `u128` multiply is lowered to a call to the `__multi3` compiler builtin, which
+/// performs a full 128x128-bit product and can't be inlined into the hashing loop. This instead
+/// computes the product from four native `i64.mul` instructions on the 32-bit halves.
+///
+/// SIMD128 isn't used as it has no 64-bit high-multiply, and its widening `extmul` would only
+/// replace two of the four scalar multiplies while adding lane shuffles. The intrinsics also can't
+/// be called from a `const fn`.
+#[cfg(target_arch = "wasm32")]
+#[inline(always)]
+pub const fn rapid_mum(a: u64, b: u64) -> (u64, u64) {
+    rapid_mum_split(a, b)
+}
+
+/// Portable 64x64 to 128-bit multiply using 32-bit halves, without `u128` arithmetic.
+#[cfg(any(target_arch = "wasm32", test))]
+#[inline(always)]
+const fn rapid_mum_split(a: u64, b: u64) -> (u64, u64) {
+    const LO: u64 = 0xffff_ffff;
+    let (a_lo, a_hi) = (a & LO, a >> 32);
+    let (b_lo, b_hi) = (b & LO, b >> 32);
+
+    let ll = a_lo * b_lo;
+    let lh = a_lo * b_hi;
+    let hl = a_hi * b_lo;
+    let hh = a_hi * b_hi;
+
+    // at most 3 * (2^32 - 1), so this can't overflow
+    let mid = (ll >> 32) + (lh & LO) + (hl & LO);
+    let lo = (ll & LO) | (mid << 32);
+    let hi = hh + (lh >> 32) + (hl >> 32) + (mid >> 32);
+    (lo, hi)
+}
+
+/// Multiply `a` and `b` into a 128-bit product, and fold it into 64 bits by xoring the low and high
+/// halves.
 #[inline(always)]
 pub const fn rapid_mix(a: u64, b: u64) -> u64 {
     let (a, b) = rapid_mum(a, b);
     a ^ b
 }
 
+/// Mix the total input length into the seed, which must be done before any data is absorbed.
 #[inline(always)]
-pub(crate) const fn rapidhash_seed(seed: u64, len: u64) -> u64 {
+pub const fn rapidhash_seed(seed: u64, len: u64) -> u64 {
     seed ^ rapid_mix(seed ^ RAPID_SECRET[0], RAPID_SECRET[1]) ^ len
 }
 
-#[inline(always)]
-pub(crate) const fn rapidhash_core(mut a: u64, mut b: u64, mut seed: u64, data: &[u8]) -> (u64, u64, u64) {
+/// Absorb `data` into the `a`, `b`, and `seed` state, returning the updated state.
+///
+/// The oneshot [rapidhash_inline] calls this once with `a = b = 0` and the seed from
+/// [rapidhash_seed], followed by [rapidhash_finish].
+#[cfg_attr(not(feature = "tiny"), inline(always))]
+#[cfg_attr(feature = "tiny", inline)]
+pub const fn rapidhash_core(mut a: u64, mut b: u64, mut seed: u64, data: &[u8]) -> (u64, u64, u64) {
     if data.len() <= 16 {
-        // deviation from the C++ impl computes delta as follows
-        // let delta = (data.len() & 24) >> (data.len() >> 3);
-        // this is equivalent to "match {..8=>0, 8..=>4}"
-        // and so using the extra if-else statement is equivalent and allows the compiler to skip
-        // some unnecessary bounds checks while still being safe rust.
-        if data.len() >= 8 {
-            // len is 4..=16
-            let plast = data.len() - 4;
-            let delta = 4;
-            a ^= read_u32_combined(data, 0, plast);
-            b ^= read_u32_combined(data, delta, plast - delta);
-        } else if data.len() >= 4 {
-            let plast = data.len() - 4;
-            let delta = 0;
-            a ^= read_u32_combined(data, 0, plast);
-            b ^= read_u32_combined(data, delta, plast - delta);
-        } else if data.len() > 0 {
-            // len is 1..=3
-            let len = data.len();
-            a ^= ((data[0] as u64) << 56) | ((data[len >> 1] as u64) << 32) | data[len - 1] as u64;
-            // b = 0;
-        }
+        let (short_a, short_b) = rapidhash_read_short(data);
+        a ^= short_a;
+        b ^= short_b;
     } else {
-        let mut slice = data;
-
-        // most CPUs appear to benefit from this unrolled loop
-        let mut see1 = seed;
-        let mut see2 = seed;
-        while slice.len() >= 96 {
-            seed = rapid_mix(read_u64(slice, 0) ^ RAPID_SECRET[0], read_u64(slice, 8) ^ seed);
-            see1 = rapid_mix(read_u64(slice, 16) ^ RAPID_SECRET[1], read_u64(slice, 24) ^ see1);
-            see2 = rapid_mix(read_u64(slice, 32) ^ RAPID_SECRET[2], read_u64(slice, 40) ^ see2);
-            seed = rapid_mix(read_u64(slice , 48) ^ RAPID_SECRET[0], read_u64(slice, 56) ^ seed);
-            see1 = rapid_mix(read_u64(slice, 64) ^ RAPID_SECRET[1], read_u64(slice, 72) ^ see1);
-            see2 = rapid_mix(read_u64(slice, 80) ^ RAPID_SECRET[2], read_u64(slice, 88) ^ see2);
-            let (_, split) = slice.split_at(96);
-            slice = split;
-        }
-        if slice.len() >= 48 {
-            seed = rapid_mix(read_u64(slice, 0) ^ RAPID_SECRET[0], read_u64(slice, 8) ^ seed);
-            see1 = rapid_mix(read_u64(slice, 16) ^ RAPID_SECRET[1], read_u64(slice, 24) ^ see1);
-            see2 = rapid_mix(read_u64(slice, 32) ^ RAPID_SECRET[2], read_u64(slice, 40) ^ see2);
-            let (_, split) = slice.split_at(48);
-            slice = split;
-        }
-        seed ^= see1 ^ see2;
-
-        if slice.len() > 16 {
-            seed = rapid_mix(read_u64(slice, 0) ^ RAPID_SECRET[2], read_u64(slice, 8) ^ seed ^ RAPID_SECRET[1]);
-            if slice.len() > 32 {
-                seed = rapid_mix(read_u64(slice, 16) ^ RAPID_SECRET[2], read_u64(slice, 24) ^ seed);
-            }
-        }
-
-        a ^= read_u64(data, data.len() - 16);
-        b ^= read_u64(data, data.len() - 8);
+        (a, b, seed) = rapidhash_core_large(a, b, seed, data);
     }
 
     a ^= RAPID_SECRET[1];
@@ -112,16 +143,154 @@ pub(crate) const fn rapidhash_core(mut a: u64, mut b: u64, mut seed: u64, data:
     (a, b, seed)
 }
 
+/// Read the `a` and `b` words of an input of at most 16 bytes, which [rapidhash_core] XORs into
+/// its state. Both are zero for an empty input.
+#[inline(always)]
+pub(crate) const fn rapidhash_read_short(data: &[u8]) -> (u64, u64) {
+    let mut a = 0;
+    let mut b = 0;
+    // deviation from the C++ impl computes delta as follows
+    // let delta = (data.len() & 24) >> (data.len() >> 3);
+    // this is equivalent to "match {..8=>0, 8..=>4}"
+    // and so using the extra if-else statement is equivalent and allows the compiler to skip
+    // some unnecessary bounds checks while still being safe rust.
+    if data.len() >= 8 {
+        // len is 4..=16
+        let plast = data.len() - 4;
+        let delta = 4;
+        a = read_u32_combined(data, 0, plast);
+        b = read_u32_combined(data, delta, plast - delta);
+    } else if data.len() >= 4 {
+        let plast = data.len() - 4;
+        let delta = 0;
+        a = read_u32_combined(data, 0, plast);
+        b = read_u32_combined(data, delta, plast - delta);
+    } else if !data.is_empty() {
+        // len is 1..=3
+        let len = data.len();
+        a = ((data[0] as u64) << 56) | ((data[len >> 1] as u64) << 32) | data[len - 1] as u64;
+    }
+    (a, b)
+}
+
+/// Absorb more than 16 bytes of `data` into the state, before the final mix of [rapidhash_core].
+///
+/// With the `cold-large` feature this is outlined and marked `#[cold]`, so callers that mostly hash
+/// short keys only inline the `<= 16` byte path.
+#[cfg_attr(all(not(feature = "tiny"), not(feature = "cold-large")), inline(always))]
+#[cfg_attr(all(feature = "tiny", not(feature = "cold-large")), inline)]
+#[cfg_attr(feature = "cold-large", cold, inline(never))]
+const fn rapidhash_core_large(mut a: u64, mut b: u64, mut seed: u64, data: &[u8]) -> (u64, u64, u64) {
+    let mut slice = data;
+
+    // most CPUs appear to benefit from this unrolled loop
+    let mut see1 = seed;
+    let mut see2 = seed;
+    #[cfg(all(not(feature = "tiny"), not(rapidhash_legacy_read)))]
+    #[allow(clippy::incompatible_msrv)]  // only compiled on rustc 1.77+, see build.rs
+    while let Some((chunk, rest)) = slice.split_first_chunk::<96>() {
+        (seed, see1, see2) = rapidhash_absorb_96(seed, see1, see2, chunk);
+        slice = rest;
+    }
+    #[cfg(all(not(feature = "tiny"), rapidhash_legacy_read))]
+    while slice.len() >= 96 {
+        (seed, see1, see2) = rapidhash_absorb_48(seed, see1, see2, slice, 0);
+        (seed, see1, see2) = rapidhash_absorb_48(seed, see1, see2, slice, 48);
+        let (_, split) = slice.split_at(96);
+        slice = split;
+    }
+    // the compact loop absorbs the same 48-byte blocks in the same order, so hashes are equal
+    #[cfg(feature = "tiny")]
+    while slice.len() >= 48 {
+        (seed, see1, see2) = rapidhash_absorb_48(seed, see1, see2, slice, 0);
+        let (_, split) = slice.split_at(48);
+        slice = split;
+    }
+    #[cfg(not(feature = "tiny"))]
+    if slice.len() >= 48 {
+        (seed, see1, see2) = rapidhash_absorb_48(seed, see1, see2, slice, 0);
+        let (_, split) = slice.split_at(48);
+        slice = split;
+    }
+    seed ^= see1 ^ see2;
+    seed = rapidhash_absorb_remainder(seed, slice);
+
+    a ^= read_u64(data, data.len() - 16);
+    b ^= read_u64(data, data.len() - 8);
+    (a, b, seed)
+}
+
+/// Absorb the 48 bytes of `slice` starting at `offset` into the three independent lanes of the
+/// bulk loop. The unrolled 96-byte loop is two consecutive calls.
 #[inline(always)]
-pub(crate) const fn rapidhash_finish(a: u64, b: u64, len: u64) -> u64 {
+pub(crate) const fn rapidhash_absorb_48(seed: u64, see1: u64, see2: u64, slice: &[u8], offset: usize) -> (u64, u64, u64) {
+    (
+        rapid_mix(read_u64(slice, offset) ^ RAPID_SECRET[0], read_u64(slice, offset + 8) ^ seed),
+        rapid_mix(read_u64(slice, offset + 16) ^ RAPID_SECRET[1], read_u64(slice, offset + 24) ^ see1),
+        rapid_mix(read_u64(slice, offset + 32) ^ RAPID_SECRET[2], read_u64(slice, offset + 40) ^ see2),
+    )
+}
+
+/// Absorb a 96-byte block of the unrolled bulk loop, equivalent to two [rapidhash_absorb_48]
+/// calls.
+///
+/// Taking a fixed-size array lets every read use constant indices, which need no bounds checks at
+/// runtime and are far cheaper in const evaluation than a `split_at` and `first_chunk` call per
+/// read. This keeps compile-time hashing of large `include_bytes!` assets within the const-eval
+/// step limits.
+#[cfg(all(not(feature = "tiny"), not(rapidhash_legacy_read)))]
+#[inline(always)]
+const fn rapidhash_absorb_96(mut seed: u64, mut see1: u64, mut see2: u64, chunk: &[u8; 96]) -> (u64, u64, u64) {
+    let mut offset = 0;
+    while offset < 96 {
+        seed = rapid_mix(read_u64_array(chunk, offset) ^ RAPID_SECRET[0], read_u64_array(chunk, offset + 8) ^ seed);
+        see1 = rapid_mix(read_u64_array(chunk, offset + 16) ^ RAPID_SECRET[1], read_u64_array(chunk, offset + 24) ^ see1);
+        see2 = rapid_mix(read_u64_array(chunk, offset + 32) ^ RAPID_SECRET[2], read_u64_array(chunk, offset + 40) ^ see2);
+        offset += 48;
+    }
+    (seed, see1, see2)
+}
+
+/// Read a little-endian u64 from a fixed-size array. With constant offsets after inlining, the
+/// compiler removes all bounds checks.
+#[cfg(all(not(feature = "tiny"), not(rapidhash_legacy_read)))]
+#[inline(always)]
+const fn read_u64_array<const N: usize>(chunk: &[u8; N], offset: usize) -> u64 {
+    u64::from_le_bytes([
+        chunk[offset], chunk[offset + 1], chunk[offset + 2], chunk[offset + 3],
+        chunk[offset + 4], chunk[offset + 5], chunk[offset + 6], chunk[offset + 7],
+    ])
+}
+
+/// Absorb the final `0..48` bytes left over by the bulk loop, after the lanes have been folded into
+/// the seed. Only the bytes beyond the first 16 are read here, the last 16 bytes of the input are
+/// read separately.
+#[inline(always)]
+pub(crate) const fn rapidhash_absorb_remainder(mut seed: u64, slice: &[u8]) -> u64 {
+    if slice.len() > 16 {
+        seed = rapid_mix(read_u64(slice, 0) ^ RAPID_SECRET[2], read_u64(slice, 8) ^ seed ^ RAPID_SECRET[1]);
+        if slice.len() > 32 {
+            seed = rapid_mix(read_u64(slice, 16) ^ RAPID_SECRET[2], read_u64(slice, 24) ^ seed);
+        }
+    }
+    seed
+}
+
+/// Produce the final 64-bit hash from the `a` and `b` state and the total input length.
+#[inline(always)]
+pub const fn rapidhash_finish(a: u64, b: u64, len: u64) -> u64 {
     rapid_mix(a ^ RAPID_SECRET[0] ^ len, b ^ RAPID_SECRET[1])
 }
 
 /// Hacky const-friendly memory-safe unaligned bytes to u64. Compiler can't seem to remove the
 /// bounds check, and so we have an unsafe version behind the `unsafe` feature flag.
-#[cfg(not(feature = "unsafe"))]
+///
+/// Miri always uses the memory-safe reads, even with the `unsafe` feature enabled, so downstream
+/// crates that enable the feature can still run their test suites under Miri.
+#[cfg(all(any(not(feature = "unsafe"), miri), not(rapidhash_legacy_read)))]
+#[allow(clippy::incompatible_msrv)]  // only compiled on rustc 1.77+, see build.rs
 #[inline(always)]
-const fn read_u64(slice: &[u8], offset: usize) -> u64 {
+pub(crate) const fn read_u64(slice: &[u8], offset: usize) -> u64 {
     // equivalent to slice[offset..offset+8].try_into().unwrap(), but const-friendly
     let maybe_buf = slice.split_at(offset).1.first_chunk::<8>();
     let buf = match maybe_buf {
@@ -133,7 +302,8 @@ const fn read_u64(slice: &[u8], offset: usize) -> u64 {
 
 /// Hacky const-friendly memory-safe unaligned bytes to u64. Compiler can't seem to remove the
 /// bounds check, and so we have an unsafe version behind the `unsafe` feature flag.
-#[cfg(not(feature = "unsafe"))]
+#[cfg(all(any(not(feature = "unsafe"), miri), not(rapidhash_legacy_read)))]
+#[allow(clippy::incompatible_msrv)]  // only compiled on rustc 1.77+, see build.rs
 #[inline(always)]
 const fn read_u32(slice: &[u8], offset: usize) -> u32 {
     // equivalent to slice[offset..offset+4].try_into().unwrap(), but const-friendly
@@ -145,17 +315,41 @@ const fn read_u32(slice: &[u8], offset: usize) -> u32 {
     u32::from_le_bytes(buf)
 }
 
+/// Memory-safe unaligned bytes to u64 for rustc older than 1.77, where `first_chunk` is not
+/// available. Enabled automatically by the build script.
+///
+/// Indexing the last byte first lets the compiler drop the remaining bounds checks, and unlike
+/// `try_into` this is still usable in const contexts.
+#[cfg(all(any(not(feature = "unsafe"), miri), rapidhash_legacy_read))]
+#[inline(always)]
+pub(crate) const fn read_u64(slice: &[u8], offset: usize) -> u64 {
+    let _ = slice[offset + 7];
+    u64::from_le_bytes([
+        slice[offset], slice[offset + 1], slice[offset + 2], slice[offset + 3],
+        slice[offset + 4], slice[offset + 5], slice[offset + 6], slice[offset + 7],
+    ])
+}
+
+/// Memory-safe unaligned bytes to u32 for rustc older than 1.77, where `first_chunk` is not
+/// available. Enabled automatically by the build script.
+#[cfg(all(any(not(feature = "unsafe"), miri), rapidhash_legacy_read))]
+#[inline(always)]
+const fn read_u32(slice: &[u8], offset: usize) -> u32 {
+    let _ = slice[offset + 3];
+    u32::from_le_bytes([slice[offset], slice[offset + 1], slice[offset + 2], slice[offset + 3]])
+}
+
 /// Unsafe but const-friendly unaligned bytes to u64. The compiler can't seem to remove the bounds
 /// checks for small integers because we do some funky bit shifting in the indexing.
 ///
 /// SAFETY: `slice` must be at least `offset+8` bytes long, which we guarantee in this rapidhash
 /// implementation.
-#[cfg(feature = "unsafe")]
+#[cfg(all(feature = "unsafe", not(miri)))]
 #[inline(always)]
-const fn read_u64(slice: &[u8], offset: usize) -> u64 {
+pub(crate) const fn read_u64(slice: &[u8], offset: usize) -> u64 {
     debug_assert!(offset as isize >= 0);
     debug_assert!(slice.len() >= 8 + offset);
-    let val = unsafe { std::ptr::read_unaligned(slice.as_ptr().offset(offset as isize) as *const u64) };
+    let val = unsafe { core::ptr::read_unaligned(slice.as_ptr().add(offset) as *const u64) };
     val.to_le()  // swap bytes on big-endian systems to get the same u64 value
 }
 
@@ -164,12 +358,12 @@ const fn read_u64(slice: &[u8], offset: usize) -> u64 {
 ///
 /// SAFETY: `slice` must be at least `offset+8` bytes long, which we guarantee in this rapidhash
 /// implementation.
-#[cfg(feature = "unsafe")]
+#[cfg(all(feature = "unsafe", not(miri)))]
 #[inline(always)]
 const fn read_u32(slice: &[u8], offset: usize) -> u32 {
     debug_assert!(offset as isize >= 0);
     debug_assert!(slice.len() >= 4 + offset);
-    let val = unsafe { std::ptr::read_unaligned(slice.as_ptr().offset(offset as isize) as *const u32) };
+    let val = unsafe { core::ptr::read_unaligned(slice.as_ptr().add(offset) as *const u32) };
     val.to_le()  // swap bytes on big-endian systems to get the same u64 value
 }
 
@@ -268,4 +462,33 @@ mod tests {
         assert_eq!(a, u64::MAX - 1);
         assert_eq!(b, 1);
     }
+
+    #[test]
+    #[cfg(all(not(feature = "tiny"), not(rapidhash_legacy_read)))]
+    fn test_const_eval_large_input() {
+        // exceeded the default const-eval step limit before the 96-byte loop used array reads
+        static DATA: [u8; 512 * 1024] = [7u8; 512 * 1024];
+        const HASH: u64 = rapidhash(&[7u8; 512 * 1024]);
+        assert_eq!(HASH, rapidhash(core::hint::black_box(&DATA)));
+    }
+
+    #[test]
+    fn test_rapid_mum_split() {
+        let edges = [0, 1, 2, 0xffff_ffff, 0x1_0000_0000, u64::MAX - 1, u64::MAX, RAPID_SECRET[0], RAPID_SECRET[1]];
+        for a in edges {
+            for b in edges {
+                let r = a as u128 * b as u128;
+                assert_eq!(rapid_mum_split(a, b), (r as u64, (r >> 64) as u64), "{a} * {b}");
+            }
+        }
+
+        let mut x = RAPID_SEED;
+        for _ in 0..10_000 {
+            let a = rapid_mix(x, RAPID_SECRET[0]);
+            let b = rapid_mix(x, RAPID_SECRET[2]);
+            let r = a as u128 * b as u128;
+            assert_eq!(rapid_mum_split(a, b), (r as u64, (r >> 64) as u64));
+            x = x.wrapping_add(RAPID_SECRET[1]);
+        }
+    }
 }
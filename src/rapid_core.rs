@@ -0,0 +1,198 @@
+use crate::rapid_const::{rapid_mum, rapidhash_absorb_48, rapidhash_absorb_remainder, rapidhash_core, rapidhash_finish, rapidhash_seed, read_u64, RAPID_SECRET};
+
+/// The number of bytes absorbed by [RapidCore::absorb_block].
+pub const RAPID_BLOCK: usize = 96;
+
+/// The raw rapidhash state machine, for frameworks that drive the algorithm block by block.
+///
+/// [crate::RapidStreamHasher] buffers arbitrary writes into blocks. This instead exposes the
+/// algorithm's own steps, so callers that already own their buffers, such as ring-buffer parsers
+/// or async frame decoders, can absorb blocks in place without copying:
+///
+/// 1. [RapidCore::new] with the seed and the total input length. Rapidhash mixes the length into
+///    the seed before reading any data, so it must be known up front.
+/// 2. [RapidCore::absorb_block] for each 96-byte block, while at least 96 bytes remain.
+/// 3. [RapidCore::absorb_tail] once with the final `0..96` bytes.
+/// 4. [RapidCore::finalize] to get the hash, equal to [crate::rapidhash_seeded] of the input.
+///
+/// Use [RapidCore::blocks] to compute how many blocks an input of a given length has.
+///
+/// # Example
+/// ```
+/// use rapidhash::raw::{RapidCore, RAPID_BLOCK};
+///
+/// let data = [7u8; 300];
+///
+/// let mut core = RapidCore::new(data.len() as u64, 42);
+/// let mut chunks = data.chunks_exact(RAPID_BLOCK);
+/// for block in &mut chunks {
+///     core.absorb_block(block.try_into().unwrap());
+/// }
+/// core.absorb_tail(chunks.remainder());
+///
+/// assert_eq!(RapidCore::blocks(300), 3);
+/// assert_eq!(core.finalize(), rapidhash::rapidhash_seeded(&data, 42));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidCore {
+    seed: u64,
+    see1: u64,
+    see2: u64,
+    len: u64,
+    absorbed: u64,
+    /// The last 16 bytes of the most recent block, which the finish step re-reads when the tail is
+    /// shorter than 16 bytes.
+    last: [u8; 16],
+    /// The finished `a` and `b` state, set by [RapidCore::absorb_tail].
+    finished: Option<(u64, u64)>,
+}
+
+impl RapidCore {
+    /// Start hashing an input of exactly `len` bytes with `seed`.
+    #[inline]
+    #[must_use]
+    pub const fn new(len: u64, seed: u64) -> Self {
+        let seed = rapidhash_seed(seed, len);
+        Self {
+            seed,
+            see1: seed,
+            see2: seed,
+            len,
+            absorbed: 0,
+            last: [0; 16],
+            finished: None,
+        }
+    }
+
+    /// The number of [RapidCore::absorb_block] calls for an input of `len` bytes, after which
+    /// `len % 96` bytes remain for [RapidCore::absorb_tail].
+    #[inline]
+    #[must_use]
+    pub const fn blocks(len: u64) -> u64 {
+        len / RAPID_BLOCK as u64
+    }
+
+    /// The total input length declared in [RapidCore::new].
+    #[inline]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the declared input length is zero.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bytes absorbed so far.
+    #[inline]
+    pub const fn absorbed(&self) -> u64 {
+        self.absorbed
+    }
+
+    /// Absorb the next 96-byte block of the input.
+    ///
+    /// # Panics
+    /// Panics if fewer than 96 bytes of the declared length remain, or after
+    /// [RapidCore::absorb_tail].
+    #[inline(always)]
+    pub fn absorb_block(&mut self, block: &[u8; RAPID_BLOCK]) {
+        assert!(
+            self.finished.is_none() && self.len - self.absorbed >= RAPID_BLOCK as u64,
+            "RapidCore: absorb_block called without a full block remaining",
+        );
+        let (seed, see1, see2) = rapidhash_absorb_48(self.seed, self.see1, self.see2, block, 0);
+        (self.seed, self.see1, self.see2) = rapidhash_absorb_48(seed, see1, see2, block, 48);
+        self.last.copy_from_slice(&block[RAPID_BLOCK - 16..]);
+        self.absorbed += RAPID_BLOCK as u64;
+    }
+
+    /// Absorb the final bytes of the input, which must be exactly the `len % 96` bytes left after
+    /// the blocks.
+    ///
+    /// # Panics
+    /// Panics if `tail` isn't exactly the remaining length, or if called twice.
+    pub fn absorb_tail(&mut self, tail: &[u8]) {
+        assert!(
+            self.finished.is_none() && self.len - self.absorbed == tail.len() as u64 && tail.len() < RAPID_BLOCK,
+            "RapidCore: absorb_tail must be called once with the final len % 96 bytes",
+        );
+        self.absorbed += tail.len() as u64;
+
+        if self.len <= 16 {
+            let (a, b, _) = rapidhash_core(0, 0, self.seed, tail);
+            self.finished = Some((a, b));
+            return;
+        }
+
+        let mut seed = self.seed;
+        let mut see1 = self.see1;
+        let mut see2 = self.see2;
+        let mut slice = tail;
+        if slice.len() >= 48 {
+            (seed, see1, see2) = rapidhash_absorb_48(seed, see1, see2, slice, 0);
+            slice = &slice[48..];
+        }
+        seed ^= see1 ^ see2;
+        seed = rapidhash_absorb_remainder(seed, slice);
+
+        // the last 16 bytes of the input may straddle the final block and the tail
+        let mut last = [0u8; 16];
+        if tail.len() >= 16 {
+            last.copy_from_slice(&tail[tail.len() - 16..]);
+        } else {
+            last[..16 - tail.len()].copy_from_slice(&self.last[tail.len()..]);
+            last[16 - tail.len()..].copy_from_slice(tail);
+        }
+
+        let a = read_u64(&last, 0) ^ RAPID_SECRET[1];
+        let b = read_u64(&last, 8) ^ seed;
+        self.finished = Some(rapid_mum(a, b));
+    }
+
+    /// Compute the final hash, equal to [crate::rapidhash_seeded] of the whole input.
+    ///
+    /// # Panics
+    /// Panics if [RapidCore::absorb_tail] hasn't been called.
+    #[inline]
+    #[must_use]
+    pub fn finalize(&self) -> u64 {
+        let (a, b) = self.finished.expect("RapidCore: finalize called before absorb_tail");
+        rapidhash_finish(a, b, self.len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_matches_oneshot() {
+        let data: [u8; 400] = core::array::from_fn(|i| (i as u32).wrapping_mul(2654435761) as u8);
+        for len in 0..=data.len() {
+            let data = &data[..len];
+            let mut core = RapidCore::new(len as u64, 42);
+            let mut chunks = data.chunks_exact(RAPID_BLOCK);
+            for block in &mut chunks {
+                core.absorb_block(block.try_into().unwrap());
+            }
+            assert_eq!(RapidCore::blocks(len as u64), (len / RAPID_BLOCK) as u64);
+            core.absorb_tail(chunks.remainder());
+            assert_eq!(core.absorbed(), len as u64);
+            assert_eq!(core.finalize(), crate::rapidhash_seeded(data, 42), "len {len}");
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn tail_must_match_remaining_length() {
+        let mut core = RapidCore::new(100, 0);
+        core.absorb_tail(&[0; 50]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn finalize_requires_tail() {
+        let _ = RapidCore::new(0, 0).finalize();
+    }
+}
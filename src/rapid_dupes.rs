@@ -0,0 +1,218 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::Metadata;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::vec::Vec;
+use crate::rapid_const::RAPID_SEED;
+use crate::rapidhash_file_seeded;
+
+/// A group of files with the same size and contents hash, found by [find_duplicates].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DuplicateGroup {
+    /// The size of each file in bytes.
+    pub len: u64,
+    /// The [crate::rapidhash_file_seeded] of each file.
+    pub hash: u64,
+    /// The paths of the files, in ascending order. There are always at least two.
+    pub paths: Vec<PathBuf>,
+}
+
+/// Find groups of duplicate files under `paths` with the default seed.
+///
+/// Each path may be a file or a directory, which is walked recursively. Files are first grouped by
+/// size, which only needs their metadata, and then the files that share a size with another file
+/// are hashed with [crate::rapidhash_file] to group them by contents. Most files have a unique
+/// size, so most files are never read.
+///
+/// Empty files are skipped, and a file reached through more than one path, such as a hard link or
+/// a path listed twice, is only reported once, under its first path in ascending order. Symbolic
+/// links found while walking a directory are skipped, so a link is never reported as a duplicate
+/// of its target and a link to an ancestor directory can't loop; links passed directly in `paths`
+/// are followed. The groups are sorted by their first path. Returns an error if any path can't be
+/// read.
+///
+/// Files in a group match with the probability of a 64-bit hash collision, which is negligible for
+/// accidental duplicates, but rapidhash is not a cryptographic hash: compare the bytes before
+/// deleting files that an attacker could have crafted.
+///
+/// To hash files in parallel, take the size groups from [duplicate_candidates] and hash each file
+/// with [crate::rapidhash_file] on a thread pool instead.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+/// ```no_run
+/// use rapidhash::find_duplicates;
+///
+/// for group in find_duplicates(["photos", "backup/photos"]).unwrap() {
+///     println!("{} bytes: {:?}", group.len, group.paths);
+/// }
+/// ```
+#[inline]
+pub fn find_duplicates(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> io::Result<Vec<DuplicateGroup>> {
+    find_duplicates_seeded(paths, RAPID_SEED)
+}
+
+/// Find groups of duplicate files under `paths` with a custom seed. See [find_duplicates].
+pub fn find_duplicates_seeded(
+    paths: impl IntoIterator<Item = impl AsRef<Path>>,
+    seed: u64,
+) -> io::Result<Vec<DuplicateGroup>> {
+    let mut groups = Vec::new();
+    for (len, candidates) in candidates_by_size(paths)? {
+        let mut by_hash: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        for path in candidates {
+            let hash = rapidhash_file_seeded(&path, seed)?;
+            by_hash.entry(hash).or_default().push(path);
+        }
+
+        groups.extend(by_hash.into_iter()
+            .filter(|(_, paths)| paths.len() > 1)
+            .map(|(hash, paths)| DuplicateGroup { len, hash, paths }));
+    }
+
+    groups.sort_unstable_by(|a, b| a.paths[0].cmp(&b.paths[0]));
+    Ok(groups)
+}
+
+/// Walk `paths` and group the non-empty files that share a size with at least one other file, as
+/// the candidates that [find_duplicates] hashes.
+///
+/// Each group's paths are in ascending order, and the groups are sorted by their first path.
+///
+/// Requires the `std` feature.
+pub fn duplicate_candidates(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> io::Result<Vec<Vec<PathBuf>>> {
+    let mut groups: Vec<Vec<PathBuf>> = candidates_by_size(paths)?.into_values().collect();
+    groups.sort_unstable_by(|a, b| a[0].cmp(&b[0]));
+    Ok(groups)
+}
+
+/// Walk `paths`, and return the sorted paths of the non-empty files by size, for each size shared
+/// by at least two files.
+fn candidates_by_size(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> io::Result<BTreeMap<u64, Vec<PathBuf>>> {
+    let mut files = Vec::new();
+    let mut visited_dirs = HashSet::new();
+    for path in paths {
+        let path = path.as_ref();
+        walk(path, std::fs::metadata(path)?, &mut files, &mut visited_dirs)?;
+    }
+
+    // keep the first path of each file, so hard links and repeated paths aren't self-duplicates
+    files.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    let mut seen = HashSet::new();
+    let mut by_size: BTreeMap<u64, Vec<PathBuf>> = BTreeMap::new();
+    for (path, id, len) in files {
+        if len > 0 && seen.insert(id) {
+            by_size.entry(len).or_default().push(path);
+        }
+    }
+
+    by_size.retain(|_, paths| paths.len() > 1);
+    Ok(by_size)
+}
+
+/// Push every regular file under `path` to `files` with its [FileId] and size, skipping symbolic
+/// links and directories that were already walked.
+fn walk(
+    path: &Path,
+    metadata: Metadata,
+    files: &mut Vec<(PathBuf, FileId, u64)>,
+    visited_dirs: &mut HashSet<FileId>,
+) -> io::Result<()> {
+    if metadata.is_dir() {
+        if !visited_dirs.insert(file_id(path, &metadata)) {
+            return Ok(());
+        }
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            if entry.file_type()?.is_symlink() {
+                continue;
+            }
+            // `DirEntry::metadata` doesn't follow symbolic links
+            walk(&entry.path(), entry.metadata()?, files, visited_dirs)?;
+        }
+    } else if metadata.is_file() {
+        files.push((path.to_path_buf(), file_id(path, &metadata), metadata.len()));
+    }
+    Ok(())
+}
+
+/// Identifies a file independently of the path it was reached through.
+#[cfg(unix)]
+type FileId = (u64, u64);
+
+/// Identifies a file by its device and inode numbers.
+#[cfg(unix)]
+fn file_id(_path: &Path, metadata: &Metadata) -> FileId {
+    use std::os::unix::fs::MetadataExt;
+    (metadata.dev(), metadata.ino())
+}
+
+/// Identifies a file independently of the path it was reached through.
+#[cfg(not(unix))]
+type FileId = PathBuf;
+
+/// Identifies a file by its canonical path, where inode numbers aren't available.
+#[cfg(not(unix))]
+fn file_id(path: &Path, _metadata: &Metadata) -> FileId {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_duplicates() {
+        let root = std::env::temp_dir().join(std::format!("rapidhash-dupes-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("a"), b"hello").unwrap();
+        std::fs::write(root.join("sub/b"), b"hello").unwrap();
+        std::fs::write(root.join("c"), b"world").unwrap();
+        std::fs::write(root.join("d"), b"unique size").unwrap();
+        std::fs::write(root.join("e"), b"").unwrap();
+        std::fs::write(root.join("f"), b"").unwrap();
+
+        let candidates = duplicate_candidates([&root]).unwrap();
+        assert_eq!(candidates, [std::vec![root.join("a"), root.join("c"), root.join("sub/b")]]);
+
+        // listing a file twice doesn't make it a duplicate of itself
+        let groups = find_duplicates([root.clone(), root.join("a")]).unwrap();
+        assert_eq!(groups, [DuplicateGroup {
+            len: 5,
+            hash: crate::rapidhash(b"hello"),
+            paths: std::vec![root.join("a"), root.join("sub/b")],
+        }]);
+
+        assert!(find_duplicates([root.join("c")]).unwrap().is_empty());
+        assert!(find_duplicates([root.join("missing")]).is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_symlinks_and_hard_links() {
+        let root = std::env::temp_dir().join(std::format!("rapidhash-dupes-links-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("a"), b"hello").unwrap();
+        std::fs::write(root.join("b"), b"unique size").unwrap();
+        std::os::unix::fs::symlink(root.join("a"), root.join("link")).unwrap();
+        std::os::unix::fs::symlink(&root, root.join("sub/ancestor")).unwrap();
+        std::fs::hard_link(root.join("b"), root.join("sub/hard")).unwrap();
+
+        // neither the file link, the ancestor link nor the hard link is a duplicate
+        assert!(duplicate_candidates([&root]).unwrap().is_empty());
+        assert!(find_duplicates([&root]).unwrap().is_empty());
+
+        // a real copy still is, and a link passed directly is followed to its target
+        std::fs::write(root.join("sub/copy"), b"hello").unwrap();
+        assert_eq!(find_duplicates([root.join("sub/ancestor")]).unwrap(), [DuplicateGroup {
+            len: 5,
+            hash: crate::rapidhash(b"hello"),
+            paths: std::vec![root.join("sub/ancestor/a"), root.join("sub/ancestor/sub/copy")],
+        }]);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(all(not(feature = "std"), feature = "unsafe", target_has_atomic = "ptr"))]
+use core::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+
+/// A source of random seed material, such as a hardware RNG peripheral or ADC noise.
+///
+/// `no_std` targets have no system time or OS randomness, so without a source [crate::RapidRng]
+/// and [crate::RapidRandomState] fall back to the fixed [crate::RAPID_SEED]. Pass a source to
+/// [crate::RapidRng::from_entropy] to seed a single generator, or register a global one with
+/// [set_entropy_source] so that `default()` uses it too.
+///
+/// Implemented for any `FnMut() -> u64` closure. Requires the `rng` feature.
+///
+/// # Example
+/// ```rust
+/// use rapidhash::{EntropySource, RapidRng};
+///
+/// struct Trng;
+///
+/// impl EntropySource for Trng {
+///     fn entropy(&mut self) -> u64 {
+///         // read the hardware random number generator's data register
+///         0x1234_5678_9abc_def0
+///     }
+/// }
+///
+/// let mut rng = RapidRng::from_entropy(&mut Trng);
+/// println!("{}", rng.next());
+/// ```
+pub trait EntropySource {
+    /// Return 64 bits of seed material.
+    fn entropy(&mut self) -> u64;
+}
+
+impl<F: FnMut() -> u64> EntropySource for F {
+    #[inline]
+    fn entropy(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// The registered global entropy source, if any.
+#[cfg(feature = "std")]
+static ENTROPY_SOURCE: RwLock<Option<fn() -> u64>> = RwLock::new(None);
+
+/// The registered global entropy source, as a `fn() -> u64` cast to a data pointer, or null.
+///
+/// `no_std` targets have no lock to guard a function pointer with, so the pointer is stored in an
+/// atomic and cast back with `unsafe`, which is only compiled with the `unsafe` feature.
+#[cfg(all(not(feature = "std"), feature = "unsafe", target_has_atomic = "ptr"))]
+static ENTROPY_SOURCE: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Register a global entropy source, used by [crate::RapidRng::default] and
+/// [crate::RapidRandomState::new] in place of their usual seeding.
+///
+/// Register the source at startup, before any generator or map is created. `RapidRandomState`
+/// seeds each thread once and then derives further seeds from it, so it only calls the source
+/// once per thread. Registering again replaces the previous source.
+///
+/// Requires the `rng` feature, and either the `std` feature, or the `unsafe` feature on a target
+/// with pointer-sized atomics.
+///
+/// # Example
+/// ```rust
+/// use rapidhash::{set_entropy_source, RapidRng};
+///
+/// fn read_trng() -> u64 {
+///     // read the hardware random number generator's data register
+///     0x1234_5678_9abc_def0
+/// }
+///
+/// set_entropy_source(read_trng);
+/// assert_eq!(RapidRng::default(), RapidRng::new(read_trng()));
+/// ```
+#[cfg(any(feature = "std", all(feature = "unsafe", target_has_atomic = "ptr")))]
+pub fn set_entropy_source(source: fn() -> u64) {
+    #[cfg(feature = "std")]
+    {
+        *ENTROPY_SOURCE.write().unwrap_or_else(PoisonError::into_inner) = Some(source);
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        ENTROPY_SOURCE.store(source as *mut (), Ordering::Release);
+    }
+}
+
+/// Seed material from the registered global entropy source, if any.
+#[inline]
+pub(crate) fn registered_entropy() -> Option<u64> {
+    #[cfg(feature = "std")]
+    {
+        // copy the source out so that it isn't called with the lock held
+        let source = *ENTROPY_SOURCE.read().unwrap_or_else(PoisonError::into_inner);
+        source.map(|source| source())
+    }
+
+    #[cfg(all(not(feature = "std"), feature = "unsafe", target_has_atomic = "ptr"))]
+    {
+        let ptr = ENTROPY_SOURCE.load(Ordering::Acquire);
+        if ptr.is_null() {
+            return None;
+        }
+        // SAFETY: the only non-null value ever stored is a `fn() -> u64` cast with `as` in
+        // set_entropy_source, and `transmute` refuses to compile if the two pointer types differ
+        // in size, so this casts the same function pointer back.
+        let source = unsafe { core::mem::transmute::<*mut (), fn() -> u64>(ptr) };
+        Some(source())
+    }
+
+    #[cfg(not(any(feature = "std", all(feature = "unsafe", target_has_atomic = "ptr"))))]
+    {
+        None
+    }
+}
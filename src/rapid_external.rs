@@ -0,0 +1,270 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::hash_map::RandomState;
+use std::fs::{File, OpenOptions};
+use std::hash::BuildHasher;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::vec::Vec;
+use crate::rapid_const::RAPID_SEED;
+
+/// A counter to give every spilled run in this process a unique file name.
+static RUN_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A set of rapidhashes for deduplicating datasets larger than memory, which spills to temporary
+/// files once a memory budget is reached.
+///
+/// Hashes are kept in memory until the budget is full, then sorted, deduplicated, and written to a
+/// temporary file as a sorted run. [ExternalRapidSet::finish] merges the runs with the in-memory
+/// hashes, and yields every distinct hash exactly once in ascending order, reading each run
+/// sequentially. Only the in-memory budget and a small read buffer per run are held in memory.
+///
+/// Each hash takes 8 bytes of memory and disk. Distinct inputs collide with the usual 64-bit
+/// probability of around `n^2 / 2^65` for `n` distinct inputs, so a billion distinct inputs have
+/// roughly a 3% chance of one false duplicate.
+///
+/// The temporary files are deleted when the set, or the iterator returned by `finish`, is dropped.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::ExternalRapidSet;
+///
+/// let mut set = ExternalRapidSet::new(64 * 1024 * 1024);
+/// for line in ["a", "b", "a", "c", "b"].iter().cycle().take(100) {
+///     set.insert(line.as_bytes()).unwrap();
+/// }
+///
+/// let distinct: Vec<u64> = set.finish().unwrap().collect::<Result<_, _>>().unwrap();
+/// assert_eq!(distinct.len(), 3);
+/// ```
+pub struct ExternalRapidSet {
+    seed: u64,
+    dir: PathBuf,
+    capacity: usize,
+    hashes: Vec<u64>,
+    runs: Vec<RunFile>,
+}
+
+impl ExternalRapidSet {
+    /// Create a set that holds up to `memory_budget` bytes of hashes in memory, and spills to the
+    /// system temporary directory.
+    #[must_use]
+    pub fn new(memory_budget: usize) -> Self {
+        Self::with_dir(memory_budget, std::env::temp_dir())
+    }
+
+    /// Create a set that holds up to `memory_budget` bytes of hashes in memory, and spills to the
+    /// directory `dir`.
+    #[must_use]
+    pub fn with_dir(memory_budget: usize, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            seed: RAPID_SEED,
+            dir: dir.into(),
+            capacity: (memory_budget / 8).max(1),
+            hashes: Vec::new(),
+            runs: Vec::new(),
+        }
+    }
+
+    /// Hash the inputs with a custom seed instead of the default.
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Hash `data` and add it to the set, spilling to disk if the memory budget is full.
+    #[inline]
+    pub fn insert(&mut self, data: &[u8]) -> std::io::Result<()> {
+        self.insert_hash(crate::rapidhash_seeded(data, self.seed))
+    }
+
+    /// Add a precomputed hash to the set, spilling to disk if the memory budget is full.
+    pub fn insert_hash(&mut self, hash: u64) -> std::io::Result<()> {
+        if self.hashes.len() == self.capacity {
+            self.hashes.sort_unstable();
+            self.hashes.dedup();
+
+            // only spill if deduplicating didn't free up a useful amount of memory
+            if self.hashes.len() > self.capacity / 2 {
+                self.spill()?;
+            }
+        }
+
+        self.hashes.push(hash);
+        Ok(())
+    }
+
+    /// The number of sorted runs spilled to disk so far.
+    #[inline]
+    pub fn spilled_runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Merge the spilled runs with the in-memory hashes, and iterate over the distinct hashes in
+    /// ascending order.
+    pub fn finish(mut self) -> std::io::Result<ExternalRapidHashes> {
+        self.hashes.sort_unstable();
+        self.hashes.dedup();
+
+        let mut sources = Vec::with_capacity(self.runs.len() + 1);
+        for run in self.runs.drain(..) {
+            let reader = BufReader::new(File::open(&run.path)?);
+            sources.push(RunSource::File { reader, _run: run });
+        }
+        sources.push(RunSource::Memory(core::mem::take(&mut self.hashes).into_iter()));
+
+        let mut heap = BinaryHeap::with_capacity(sources.len());
+        for (index, source) in sources.iter_mut().enumerate() {
+            if let Some(hash) = source.next()? {
+                heap.push(Reverse((hash, index)));
+            }
+        }
+
+        Ok(ExternalRapidHashes { sources, heap, last: None })
+    }
+
+    /// Write the sorted, deduplicated in-memory hashes to a new run file.
+    ///
+    /// The name has a random component from std's [RandomState], which is seeded by the OS, so
+    /// other users of a shared temp dir can't predict it. The file is only ever created new, and
+    /// never follows a link planted at the path.
+    fn spill(&mut self) -> std::io::Result<()> {
+        let counter = RUN_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let name = std::format!(
+            "rapidhash-{}-{}-{:016x}.run",
+            std::process::id(),
+            counter,
+            RandomState::new().hash_one(counter),
+        );
+        let path = self.dir.join(name);
+        let file = OpenOptions::new().write(true).create_new(true).open(&path)?;
+        // only created once the file is ours, so a failed open never deletes someone else's file
+        let run = RunFile { path };
+
+        let mut writer = BufWriter::new(file);
+        for hash in &self.hashes {
+            writer.write_all(&hash.to_le_bytes())?;
+        }
+        writer.flush()?;
+
+        self.runs.push(run);
+        self.hashes.clear();
+        Ok(())
+    }
+}
+
+/// The distinct hashes of an [ExternalRapidSet] in ascending order, merged from its spilled runs.
+///
+/// Yields an error if reading a run fails. The run files are deleted when this is dropped.
+pub struct ExternalRapidHashes {
+    sources: Vec<RunSource>,
+    heap: BinaryHeap<Reverse<(u64, usize)>>,
+    last: Option<u64>,
+}
+
+impl Iterator for ExternalRapidHashes {
+    type Item = std::io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Reverse((hash, index))) = self.heap.pop() {
+            match self.sources[index].next() {
+                Ok(Some(next)) => self.heap.push(Reverse((next, index))),
+                Ok(None) => {}
+                Err(err) => {
+                    self.heap.clear();
+                    return Some(Err(err));
+                }
+            }
+
+            if self.last != Some(hash) {
+                self.last = Some(hash);
+                return Some(Ok(hash));
+            }
+        }
+        None
+    }
+}
+
+/// A sorted run of hashes, either spilled to disk or left in memory.
+enum RunSource {
+    /// The run file is held to delete it once the merge is dropped.
+    File { reader: BufReader<File>, _run: RunFile },
+    Memory(std::vec::IntoIter<u64>),
+}
+
+impl RunSource {
+    fn next(&mut self) -> std::io::Result<Option<u64>> {
+        match self {
+            Self::File { reader, .. } => {
+                let mut word = [0u8; 8];
+                match reader.read_exact(&mut word) {
+                    Ok(()) => Ok(Some(u64::from_le_bytes(word))),
+                    Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => Ok(None),
+                    Err(err) => Err(err),
+                }
+            }
+            Self::Memory(hashes) => Ok(hashes.next()),
+        }
+    }
+}
+
+/// A spilled run file, deleted when dropped.
+struct RunFile {
+    path: PathBuf,
+}
+
+impl Drop for RunFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use super::*;
+
+    #[test]
+    fn merges_spilled_runs() {
+        let mut set = ExternalRapidSet::new(800).seed(7);
+        let mut expected = BTreeSet::new();
+        for i in 0..5000u32 {
+            let data = (i % 1234).to_le_bytes();
+            set.insert(&data).unwrap();
+            expected.insert(crate::rapidhash_seeded(&data, 7));
+        }
+        assert!(set.spilled_runs() > 1);
+
+        let paths: Vec<PathBuf> = set.runs.iter().map(|run| run.path.clone()).collect();
+        assert!(paths.iter().all(|path| path.exists()));
+
+        let hashes = set.finish().unwrap();
+        let distinct: Vec<u64> = hashes.collect::<Result<_, _>>().unwrap();
+        assert_eq!(distinct, expected.into_iter().collect::<Vec<_>>());
+        assert!(paths.iter().all(|path| !path.exists()));
+    }
+
+    #[test]
+    fn spill_into_missing_dir_fails() {
+        let dir = std::env::temp_dir().join(std::format!("rapidhash-missing-{}", std::process::id()));
+        let mut set = ExternalRapidSet::with_dir(8, &dir);
+        let result = (0..100u64).try_for_each(|hash| set.insert_hash(hash));
+        assert!(result.is_err());
+        assert_eq!(set.spilled_runs(), 0);
+    }
+
+    #[test]
+    fn in_memory_only() {
+        let mut set = ExternalRapidSet::new(1 << 20);
+        for hash in [3, 1, 2, 3, 1] {
+            set.insert_hash(hash).unwrap();
+        }
+        assert_eq!(set.spilled_runs(), 0);
+        let distinct: Vec<u64> = set.finish().unwrap().map(Result::unwrap).collect();
+        assert_eq!(distinct, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,173 @@
+use core::hash::{Hash, Hasher};
+use crate::rapid_const::{rapidhash_inline, RAPID_SEED};
+
+/// The bits of `value` after canonicalisation: `-0.0` becomes `0.0`, and every NaN becomes
+/// [f64::NAN].
+#[inline(always)]
+#[must_use]
+pub fn canonical_f64_bits(value: f64) -> u64 {
+    if value.is_nan() {
+        f64::NAN.to_bits()
+    } else if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+/// The bits of `value` after canonicalisation: `-0.0` becomes `0.0`, and every NaN becomes
+/// [f32::NAN].
+#[inline(always)]
+#[must_use]
+pub fn canonical_f32_bits(value: f32) -> u32 {
+    if value.is_nan() {
+        f32::NAN.to_bits()
+    } else if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Hash an `f64` with the default seed, treating `0.0` and `-0.0` as equal, and all NaNs as equal.
+///
+/// Equal to [crate::rapidhash] of the little-endian bytes of [canonical_f64_bits].
+///
+/// # Example
+/// ```
+/// use rapidhash::hash_f64;
+///
+/// assert_eq!(hash_f64(0.0), hash_f64(-0.0));
+/// assert_eq!(hash_f64(f64::NAN), hash_f64(-f64::NAN));
+/// assert_ne!(hash_f64(1.0), hash_f64(-1.0));
+/// ```
+#[inline]
+#[must_use]
+pub fn hash_f64(value: f64) -> u64 {
+    hash_f64_seeded(value, RAPID_SEED)
+}
+
+/// Hash an `f64` with a custom seed, treating `0.0` and `-0.0` as equal, and all NaNs as equal.
+#[inline]
+#[must_use]
+pub fn hash_f64_seeded(value: f64, seed: u64) -> u64 {
+    rapidhash_inline(&canonical_f64_bits(value).to_le_bytes(), seed)
+}
+
+/// Hash an `f32` with the default seed, treating `0.0` and `-0.0` as equal, and all NaNs as equal.
+///
+/// Equal to [crate::rapidhash] of the little-endian bytes of [canonical_f32_bits].
+#[inline]
+#[must_use]
+pub fn hash_f32(value: f32) -> u64 {
+    hash_f32_seeded(value, RAPID_SEED)
+}
+
+/// Hash an `f32` with a custom seed, treating `0.0` and `-0.0` as equal, and all NaNs as equal.
+#[inline]
+#[must_use]
+pub fn hash_f32_seeded(value: f32, seed: u64) -> u64 {
+    rapidhash_inline(&canonical_f32_bits(value).to_le_bytes(), seed)
+}
+
+/// An `f64` map key whose equality and hash treat `0.0` and `-0.0` as equal, and all NaNs as equal
+/// to each other.
+///
+/// `f64` doesn't implement [Eq] or [Hash], as NaN isn't equal to itself and `0.0 == -0.0` despite
+/// their different bits. This wrapper compares and hashes the [canonical_f64_bits] instead, so it
+/// can be used as a key in a [crate::RapidHashMap] or any other map.
+///
+/// # Example
+/// ```
+/// use rapidhash::{CanonicalF64, RapidHashMap};
+///
+/// let mut map = RapidHashMap::default();
+/// map.insert(CanonicalF64(-0.0), "zero");
+/// map.insert(CanonicalF64(f64::NAN), "nan");
+///
+/// assert_eq!(map.get(&CanonicalF64(0.0)), Some(&"zero"));
+/// assert_eq!(map.get(&CanonicalF64(-f64::NAN)), Some(&"nan"));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CanonicalF64(pub f64);
+
+/// An `f32` map key whose equality and hash treat `0.0` and `-0.0` as equal, and all NaNs as equal
+/// to each other. See [CanonicalF64].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CanonicalF32(pub f32);
+
+impl PartialEq for CanonicalF64 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        canonical_f64_bits(self.0) == canonical_f64_bits(other.0)
+    }
+}
+
+impl Eq for CanonicalF64 {}
+
+impl Hash for CanonicalF64 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(canonical_f64_bits(self.0));
+    }
+}
+
+impl From<f64> for CanonicalF64 {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq for CanonicalF32 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        canonical_f32_bits(self.0) == canonical_f32_bits(other.0)
+    }
+}
+
+impl Eq for CanonicalF32 {}
+
+impl Hash for CanonicalF32 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(canonical_f32_bits(self.0));
+    }
+}
+
+impl From<f32> for CanonicalF32 {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_bits() {
+        let payload_nan = f64::from_bits(f64::NAN.to_bits() | 0x1234);
+        assert!(payload_nan.is_nan());
+        assert_eq!(canonical_f64_bits(payload_nan), f64::NAN.to_bits());
+        assert_eq!(canonical_f64_bits(-0.0), 0);
+        assert_eq!(canonical_f64_bits(1.5), 1.5f64.to_bits());
+        assert_eq!(hash_f64(-0.0), crate::rapidhash(&0u64.to_le_bytes()));
+
+        let payload_nan = f32::from_bits(f32::NAN.to_bits() | 0x12);
+        assert_eq!(canonical_f32_bits(-payload_nan), f32::NAN.to_bits());
+        assert_eq!(canonical_f32_bits(-0.0), 0);
+        assert_eq!(hash_f32_seeded(-0.0, 3), hash_f32_seeded(0.0, 3));
+        assert_ne!(hash_f32(0.0), hash_f32(f32::MIN_POSITIVE));
+    }
+
+    #[test]
+    fn wrapper_equality() {
+        assert_eq!(CanonicalF64(f64::NAN), CanonicalF64(-f64::NAN));
+        assert_eq!(CanonicalF64(0.0), CanonicalF64(-0.0));
+        assert_ne!(CanonicalF64(1.0), CanonicalF64(-1.0));
+        assert_eq!(CanonicalF32(f32::NAN), CanonicalF32::from(f32::NAN));
+        assert_ne!(CanonicalF32(0.0), CanonicalF32(f32::NAN));
+    }
+}
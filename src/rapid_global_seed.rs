@@ -0,0 +1,61 @@
+use crate::rapid_const::RAPID_HASHER_SEED;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+/// The application's global seed, set once by [set_global_seed].
+#[cfg(feature = "std")]
+static GLOBAL_SEED: OnceLock<u64> = OnceLock::new();
+
+/// Set the seed used by the `Default` impls of [crate::RapidBuildHasher] and the other build
+/// hashers, and so by `RapidHashMap::default()` and friends.
+///
+/// This lets an application seed all of its maps from its own secret management at startup,
+/// without threading a custom `BuildHasher` through every collection. The seed can only be set
+/// once, and returns `Err` with the existing seed if it was already set.
+///
+/// Set the seed before creating any maps: build hashers copy the seed when they're created, so
+/// maps created earlier keep the previous default seed. The `const` constructors such as
+/// [crate::RapidBuildHasher::default_const], and [crate::RapidHasher::default], aren't affected.
+///
+/// Requires the `std` and `hasher` features.
+///
+/// # Example
+/// ```rust
+/// use std::hash::BuildHasher;
+/// use rapidhash::{global_seed, set_global_seed, RapidBuildHasher, RapidHashMap};
+///
+/// // for example, read from the application's secret store
+/// let secret = 0x5eed_5eed_5eed_5eed;
+/// set_global_seed(secret).unwrap();
+///
+/// assert_eq!(global_seed(), Some(secret));
+/// assert_eq!(set_global_seed(42), Err(secret));
+///
+/// let map: RapidHashMap<u32, &str> = RapidHashMap::default();
+/// assert_eq!(map.hasher(), &RapidBuildHasher::new(secret));
+/// ```
+#[cfg(feature = "std")]
+pub fn set_global_seed(seed: u64) -> Result<(), u64> {
+    GLOBAL_SEED.set(seed).map_err(|_| global_seed().unwrap_or(seed))
+}
+
+/// The seed set by [set_global_seed], if any.
+///
+/// Requires the `std` and `hasher` features.
+#[cfg(feature = "std")]
+#[inline]
+pub fn global_seed() -> Option<u64> {
+    GLOBAL_SEED.get().copied()
+}
+
+/// The seed for the `Default` build hashers: the global seed if set, otherwise the default hasher
+/// seed.
+#[inline]
+pub(crate) fn default_build_seed() -> u64 {
+    #[cfg(feature = "std")]
+    if let Some(seed) = global_seed() {
+        return seed;
+    }
+
+    RAPID_HASHER_SEED
+}
@@ -0,0 +1,325 @@
+use crate::rapid_const::{rapidhash_inline, RAPID_SEED};
+use crate::RapidStreamHasher;
+
+/// Encodings up to this many bytes are assembled on the stack and hashed in one oneshot call.
+const STACK_BUFFER: usize = 128;
+
+/// Hash a composite value in a single rapidhash call, by encoding all of its fields into one buffer
+/// rather than calling [core::hash::Hasher::write] once per field.
+///
+/// The [Hash](core::hash::Hash) trait feeds each field of a tuple or struct to the hasher
+/// separately, and rapidhash then runs its full mixing for every write. This trait instead encodes
+/// the whole value first, into a 128-byte stack buffer, and hashes it once. Values that encode to
+/// more than 128 bytes are streamed through the [RapidStreamHasher], which produces the same result
+/// without a heap allocation.
+///
+/// The encoding, and so the hash, is defined as:
+///
+/// - Integers and floats: their little-endian bytes. `usize` and `isize` are widened to 64 bits so
+///   that hashes are equal across platforms.
+/// - `bool`: one byte, `0` or `1`. `char`: its `u32` scalar value.
+/// - `&str`, `[u8]`, `String`, and `Vec<u8>`: the length as a little-endian `u64`, then the bytes.
+/// - `[T; N]`: each element in order, with no length prefix.
+/// - `Option<T>`: a `0` byte for `None`, or a `1` byte followed by the value.
+/// - Tuples of up to 8 elements: each element in order.
+/// - References: the referenced value.
+///
+/// [RapidHashOne::rapid_hash_one_seeded] is then [crate::rapidhash_seeded] of the encoding.
+/// Variable-length fields are prefixed with their length, so two different tuples of the same type
+/// never share an encoding.
+///
+/// # Example
+/// ```
+/// use std::hash::{Hash, Hasher};
+/// use rapidhash::{RapidHashOne, RapidHashMap};
+///
+/// let key = (42u64, "user", Some(7u32));
+/// let hash = key.rapid_hash_one();
+///
+/// // use it as the whole hash of a map key
+/// #[derive(PartialEq, Eq)]
+/// struct Key(u64, &'static str, Option<u32>);
+///
+/// impl Hash for Key {
+///     fn hash<H: Hasher>(&self, state: &mut H) {
+///         state.write_u64((self.0, self.1, self.2).rapid_hash_one());
+///     }
+/// }
+///
+/// let mut map = RapidHashMap::default();
+/// map.insert(Key(42, "user", Some(7)), "value");
+/// ```
+pub trait RapidHashOne {
+    /// The exact number of bytes written by [RapidHashOne::encode].
+    fn encoded_len(&self) -> usize;
+
+    /// Write the value's encoding to `encoder`, writing exactly [RapidHashOne::encoded_len] bytes.
+    fn encode(&self, encoder: &mut RapidEncoder<'_>);
+
+    /// Hash the value with the default seed.
+    #[inline(always)]
+    fn rapid_hash_one(&self) -> u64 {
+        self.rapid_hash_one_seeded(RAPID_SEED)
+    }
+
+    /// Hash the value with a custom seed.
+    #[inline(always)]
+    fn rapid_hash_one_seeded(&self, seed: u64) -> u64 {
+        let len = self.encoded_len();
+        if len <= STACK_BUFFER {
+            let mut buf = [0u8; STACK_BUFFER];
+            let mut encoder = RapidEncoder(Sink::Stack { buf: &mut buf, pos: 0 });
+            self.encode(&mut encoder);
+            let pos = encoder.written();
+            debug_assert_eq!(pos, len, "RapidHashOne: encoded_len doesn't match encode");
+            rapidhash_inline(&buf[..pos], seed)
+        } else {
+            let mut hasher = RapidStreamHasher::new_seeded(len as u64, seed);
+            self.encode(&mut RapidEncoder(Sink::Stream(&mut hasher)));
+            hasher.finish()
+        }
+    }
+}
+
+/// The destination of a [RapidHashOne] encoding, either a stack buffer or a streaming hasher.
+pub struct RapidEncoder<'a>(Sink<'a>);
+
+enum Sink<'a> {
+    Stack { buf: &'a mut [u8; STACK_BUFFER], pos: usize },
+    Stream(&'a mut RapidStreamHasher),
+}
+
+impl RapidEncoder<'_> {
+    /// Append bytes to the encoding.
+    ///
+    /// # Panics
+    /// Panics if more bytes are written than [RapidHashOne::encoded_len] reported.
+    #[inline(always)]
+    pub fn write(&mut self, bytes: &[u8]) {
+        match &mut self.0 {
+            Sink::Stack { buf, pos } => {
+                buf[*pos..*pos + bytes.len()].copy_from_slice(bytes);
+                *pos += bytes.len();
+            }
+            Sink::Stream(hasher) => hasher.write(bytes),
+        }
+    }
+
+    #[inline(always)]
+    fn written(&self) -> usize {
+        match &self.0 {
+            Sink::Stack { pos, .. } => *pos,
+            Sink::Stream(hasher) => hasher.written() as usize,
+        }
+    }
+}
+
+macro_rules! impl_int {
+    ($($ty:ty => $as:ty),* $(,)?) => {$(
+        impl RapidHashOne for $ty {
+            #[inline(always)]
+            fn encoded_len(&self) -> usize {
+                core::mem::size_of::<$as>()
+            }
+
+            #[inline(always)]
+            fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+                encoder.write(&(*self as $as).to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_int!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => u64,
+    i8 => i8, i16 => i16, i32 => i32, i64 => i64, i128 => i128, isize => i64,
+    f32 => f32, f64 => f64,
+);
+
+impl RapidHashOne for bool {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        1
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        encoder.write(&[*self as u8]);
+    }
+}
+
+impl RapidHashOne for char {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        4
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        encoder.write(&(*self as u32).to_le_bytes());
+    }
+}
+
+impl RapidHashOne for [u8] {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        8 + self.len()
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        encoder.write(&(self.len() as u64).to_le_bytes());
+        encoder.write(self);
+    }
+}
+
+impl RapidHashOne for str {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        self.as_bytes().encoded_len()
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        self.as_bytes().encode(encoder);
+    }
+}
+
+#[cfg(any(feature = "alloc", docsrs))]
+impl RapidHashOne for alloc::string::String {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        self.as_bytes().encoded_len()
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        self.as_bytes().encode(encoder);
+    }
+}
+
+#[cfg(any(feature = "alloc", docsrs))]
+impl RapidHashOne for alloc::vec::Vec<u8> {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        self.as_slice().encoded_len()
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        self.as_slice().encode(encoder);
+    }
+}
+
+impl<T: RapidHashOne, const N: usize> RapidHashOne for [T; N] {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        self.iter().map(T::encoded_len).sum()
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        for item in self {
+            item.encode(encoder);
+        }
+    }
+}
+
+impl<T: RapidHashOne> RapidHashOne for Option<T> {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        1 + self.as_ref().map_or(0, T::encoded_len)
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        match self {
+            None => encoder.write(&[0]),
+            Some(value) => {
+                encoder.write(&[1]);
+                value.encode(encoder);
+            }
+        }
+    }
+}
+
+impl<T: RapidHashOne + ?Sized> RapidHashOne for &T {
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        (**self).encoded_len()
+    }
+
+    #[inline(always)]
+    fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+        (**self).encode(encoder);
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident)+) => {
+        impl<$($name: RapidHashOne),+> RapidHashOne for ($($name,)+) {
+            #[inline(always)]
+            #[allow(non_snake_case)]
+            fn encoded_len(&self) -> usize {
+                let ($($name,)+) = self;
+                0 $(+ $name.encoded_len())+
+            }
+
+            #[inline(always)]
+            #[allow(non_snake_case)]
+            fn encode(&self, encoder: &mut RapidEncoder<'_>) {
+                let ($($name,)+) = self;
+                $($name.encode(encoder);)+
+            }
+        }
+    };
+}
+
+impl_tuple!(A);
+impl_tuple!(A B);
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+impl_tuple!(A B C D E);
+impl_tuple!(A B C D E F);
+impl_tuple!(A B C D E F G);
+impl_tuple!(A B C D E F G H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_documented_encoding() {
+        assert_eq!(42u64.rapid_hash_one(), crate::rapidhash(&42u64.to_le_bytes()));
+        assert_eq!(42usize.rapid_hash_one(), crate::rapidhash(&42u64.to_le_bytes()));
+        assert_eq!(true.rapid_hash_one_seeded(3), crate::rapidhash_seeded(&[1], 3));
+
+        let mut expected = [0u8; 8 + 8 + 2 + 1 + 1 + 4];
+        expected[..8].copy_from_slice(&7u64.to_le_bytes());
+        expected[8..16].copy_from_slice(&2u64.to_le_bytes());
+        expected[16..18].copy_from_slice(b"hi");
+        expected[18] = 0;
+        expected[19] = 1;
+        expected[20..].copy_from_slice(&9u32.to_le_bytes());
+        assert_eq!((7u64, "hi", None::<u8>, Some(9u32)).rapid_hash_one(), crate::rapidhash(&expected));
+
+        assert_eq!([1u16, 2].rapid_hash_one(), crate::rapidhash(&[1, 0, 2, 0]));
+    }
+
+    #[test]
+    fn large_values_stream() {
+        let data = [3u8; 300];
+        let mut expected = [0u8; 308];
+        expected[..8].copy_from_slice(&300u64.to_le_bytes());
+        expected[8..].copy_from_slice(&data);
+        assert_eq!(data.as_slice().rapid_hash_one(), crate::rapidhash(&expected));
+        let expected: [u8; 256] = core::array::from_fn(|i| if i % 8 == 0 { 5 } else { 0 });
+        assert_eq!([[5u64; 8]; 4].rapid_hash_one(), crate::rapidhash(&expected));
+    }
+
+    #[test]
+    fn length_prefix_separates_fields() {
+        assert_ne!(("ab", "c").rapid_hash_one(), ("a", "bc").rapid_hash_one());
+        assert_ne!((Some(0u8),).rapid_hash_one(), (None::<u8>, 0u8).rapid_hash_one());
+    }
+}
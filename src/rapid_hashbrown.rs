@@ -0,0 +1,86 @@
+use core::hash::{BuildHasher, Hash};
+use crate::RapidBuildHasher;
+
+/// A [hashbrown::HashMap] type that uses the [RapidBuildHasher].
+///
+/// Requires the `hashbrown` feature.
+pub type RapidHashbrownMap<K, V> = hashbrown::HashMap<K, V, RapidBuildHasher>;
+
+/// A [hashbrown::HashSet] type that uses the [RapidBuildHasher].
+///
+/// Requires the `hashbrown` feature.
+pub type RapidHashbrownSet<K> = hashbrown::HashSet<K, RapidBuildHasher>;
+
+/// Probe a [hashbrown::HashMap], such as a [RapidHashbrownMap], with a precomputed hash instead of
+/// rehashing the key.
+///
+/// Systems that already carry the hash of a key, for example one received from a network peer
+/// alongside the key bytes, can use these to look the key up without hashing it again. The hash
+/// must be the one the map itself would compute, `map.hasher().hash_one(&key)` or
+/// [crate::RapidHashKey::hash_key], so both sides need to agree on the seed. A hash from
+/// [crate::rapidhash] of the key bytes won't match, as [Hash] impls such as `str`'s also write a
+/// length or terminator.
+///
+/// Requires the `hashbrown` feature.
+///
+/// # Example
+/// ```
+/// use std::hash::BuildHasher;
+/// use rapidhash::{RapidBuildHasher, RapidHashbrownMap, RapidRawHashGet};
+///
+/// let build = RapidBuildHasher::new(0x1234);
+/// let mut map = RapidHashbrownMap::with_hasher(build);
+/// map.insert("user:42".to_string(), 7);
+///
+/// // computed by a peer that shares the seed
+/// let hash = RapidBuildHasher::new(0x1234).hash_one("user:42");
+///
+/// assert!(map.contains_hash(hash));
+/// assert_eq!(map.get_by_hash(hash, |key| key == "user:42"), Some((&"user:42".to_string(), &7)));
+/// assert_eq!(map.get_by_hash(hash, |key| key == "user:43"), None);
+/// ```
+pub trait RapidRawHashGet<K, V> {
+    /// Find the entry with the precomputed `hash` whose key satisfies `eq`.
+    ///
+    /// Only the keys that the map can't rule out from the hash alone are passed to `eq`, so it's
+    /// usually called once, if at all.
+    fn get_by_hash<F: FnMut(&K) -> bool>(&self, hash: u64, eq: F) -> Option<(&K, &V)>;
+
+    /// Whether the map holds a key with the precomputed `hash`.
+    ///
+    /// The map only stores 7 bits of each key's hash, so the few candidate keys that share them
+    /// are rehashed to confirm an exact match.
+    fn contains_hash(&self, hash: u64) -> bool;
+}
+
+impl<K: Hash, V, S: BuildHasher> RapidRawHashGet<K, V> for hashbrown::HashMap<K, V, S> {
+    #[inline]
+    fn get_by_hash<F: FnMut(&K) -> bool>(&self, hash: u64, eq: F) -> Option<(&K, &V)> {
+        self.raw_entry().from_hash(hash, eq)
+    }
+
+    #[inline]
+    fn contains_hash(&self, hash: u64) -> bool {
+        let build = self.hasher();
+        self.raw_entry().from_hash(hash, |key| build.hash_one(key) == hash).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probes_by_hash() {
+        let map: RapidHashbrownMap<u64, u64> = (0..1000).map(|i| (i, i * 2)).collect();
+        for key in 0..2000u64 {
+            let hash = map.hasher().hash_one(key);
+            assert_eq!(map.contains_hash(hash), key < 1000, "key {key}");
+            assert_eq!(map.get_by_hash(hash, |k| *k == key).map(|(_, v)| *v), map.get(&key).copied());
+        }
+
+        // the same key hashed with another seed isn't found
+        let other = RapidBuildHasher::new(1).hash_one(5u64);
+        assert!(!map.contains_hash(other));
+    }
+}
@@ -0,0 +1,164 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use crate::rapid_const::RAPID_HASHER_SEED;
+use crate::RapidHasher;
+
+/// A value paired with its precomputed rapidhash, for keys that are expensive to hash and are
+/// inserted into or looked up in many collections.
+///
+/// The value is hashed once with [RapidHasher] on construction. The [Hash] implementation then
+/// writes only the cached `u64`, and [PartialEq] compares the cached hashes before falling back to
+/// comparing the values, so unequal keys are usually rejected without touching the value at all.
+///
+/// `Hashed<T>` deliberately doesn't implement `DerefMut` or `Borrow<T>`. Mutating the value would
+/// leave a stale hash, and a `Borrow<T>` lookup would hash the `T` differently to the `Hashed<T>`.
+///
+/// Requires the `hasher` feature.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use rapidhash::Hashed;
+///
+/// let key = Hashed::new(String::from("a long and expensive key"));
+///
+/// let mut a = HashMap::new();
+/// let mut b = HashMap::new();
+/// a.insert(key.clone(), 1);
+/// b.insert(key.clone(), 2);
+///
+/// assert_eq!(a[&key], 1);
+/// assert_eq!(key.len(), 24);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Hashed<T> {
+    hash: u64,
+    value: T,
+}
+
+impl<T: Hash> Hashed<T> {
+    /// Wrap `value`, hashing it with the default seed.
+    #[inline]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self::new_seeded(value, RAPID_HASHER_SEED)
+    }
+
+    /// Wrap `value`, hashing it with a custom seed.
+    ///
+    /// Values wrapped with different seeds never compare equal, even when the values are equal.
+    #[inline]
+    #[must_use]
+    pub fn new_seeded(value: T, seed: u64) -> Self {
+        let mut hasher = RapidHasher::new(seed);
+        value.hash(&mut hasher);
+        Self { hash: hasher.finish(), value }
+    }
+}
+
+impl<T> Hashed<T> {
+    /// The cached hash of the value.
+    #[inline]
+    pub fn cached_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// A reference to the wrapped value.
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Unwrap the value, discarding the cached hash.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Hashed<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> Hash for Hashed<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl<T: PartialEq> PartialEq for Hashed<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Hashed<T> {}
+
+impl<T: Hash> From<T> for Hashed<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Hashed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hashed")
+            .field("hash", &self.hash)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use super::*;
+
+    struct Counted<'a>(u32, &'a Cell<u32>);
+
+    impl Hash for Counted<'_> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.1.set(self.1.get() + 1);
+            self.0.hash(state);
+        }
+    }
+
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    #[test]
+    fn hashes_once() {
+        let count = Cell::new(0);
+        let key = Hashed::new(Counted(7, &count));
+        assert_eq!(count.get(), 1);
+
+        let mut hasher = RapidHasher::default();
+        key.hash(&mut hasher);
+        key.hash(&mut hasher);
+        assert_eq!(count.get(), 1);
+
+        let mut expected = RapidHasher::default();
+        7u32.hash(&mut expected);
+        assert_eq!(key.cached_hash(), expected.finish());
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Hashed::new("a"), Hashed::new("a"));
+        assert_ne!(Hashed::new("a"), Hashed::new("b"));
+        assert_ne!(Hashed::new_seeded("a", 1), Hashed::new_seeded("a", 2));
+        assert_eq!(*Hashed::from(3u8), 3);
+    }
+}
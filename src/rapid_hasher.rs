@@ -1,5 +1,6 @@
-use core::hash::Hasher;
-use crate::rapid_const::{RAPID_SEED};
+use core::hash::{BuildHasher, Hasher};
+use crate::rapid_const::{rapidhash_key_seed, RAPID_HASHER_SEED};
+use crate::rapid_global_seed::default_build_seed;
 use crate::RapidInlineHasher;
 
 /// A [Hasher] trait compatible hasher that uses the [rapidhash](https://github.com/Nicoshev/rapidhash) algorithm.
@@ -19,11 +20,16 @@ use crate::RapidInlineHasher;
 /// let hash = hasher.finish();
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct RapidHasher(RapidInlineHasher);
 
 /// A [std::hash::BuildHasher] trait compatible hasher that uses the [RapidHasher] algorithm.
 ///
-/// This is an alias for [`std::hash::BuildHasherDefault<RapidHasher>`] with a static seed.
+/// Uses the default seed unless constructed with [RapidBuildHasher::new]. Both constructors are
+/// `const`, so a build hasher can live in a `static` without lazy initialisation. With `std`,
+/// `default()` uses the seed from [crate::set_global_seed] instead, if one was set.
 ///
 /// See [RapidInlineHasher] for an `#[inline(always)]` version of this hasher, which can deliver
 /// speed improvements of around 30% when hashing complex objects.
@@ -34,13 +40,22 @@ pub struct RapidHasher(RapidInlineHasher);
 /// # Example
 /// ```
 /// use std::collections::HashMap;
-/// use std::hash::Hasher;
-/// use rapidhash::RapidHashBuilder;
+/// use std::hash::BuildHasher;
+/// use rapidhash::RapidBuildHasher;
 ///
-/// let mut map = HashMap::with_hasher(RapidHashBuilder::default());
+/// let mut map = HashMap::with_hasher(RapidBuildHasher::default());
 /// map.insert(42, "the answer");
+///
+/// static SEEDED: RapidBuildHasher = RapidBuildHasher::new(0x1234);
+/// let hash = SEEDED.hash_one("the answer");
 /// ```
-pub type RapidBuildHasher = core::hash::BuildHasherDefault<RapidHasher>;
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RapidBuildHasher {
+    seed: u64,
+}
 
 /// Deprecated and renamed to [RapidBuildHasher].
 #[deprecated(since = "1.1.0", note = "Renamed to `RapidBuildHasher`")]
@@ -85,7 +100,10 @@ pub type RapidHashSet<K> = std::collections::HashSet<K, RapidBuildHasher>;
 
 impl RapidHasher {
     /// Default `RapidHasher` seed.
-    pub const DEFAULT_SEED: u64 = RAPID_SEED;
+    ///
+    /// This is [crate::RAPID_SEED], unless the `const-random-seed` feature is enabled, in which
+    /// case it's generated at compile time so it differs between builds.
+    pub const DEFAULT_SEED: u64 = RAPID_HASHER_SEED;
 
     /// Create a new [RapidHasher] with a custom seed.
     #[inline]
@@ -101,6 +119,41 @@ impl RapidHasher {
         Self::new(Self::DEFAULT_SEED)
     }
 
+    /// Create a new [RapidHasher] with a seed derived from a string key, such as `"user-cache"`, to
+    /// namespace hashes without choosing ad-hoc seed constants.
+    ///
+    /// The seed is the [crate::rapidhash] of the key, and so is stable across builds and platforms.
+    #[inline]
+    #[must_use]
+    pub const fn with_key(key: &str) -> Self {
+        Self::new(rapidhash_key_seed(key))
+    }
+
+    /// Create a new [RapidHasher] using the default seed, which has already absorbed a
+    /// length-prefixed `domain` tag.
+    ///
+    /// Values hashed under different domains are framed as distinct inputs, see
+    /// [crate::tagged_hash].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::hash::{Hash, Hasher};
+    /// use rapidhash::RapidHasher;
+    ///
+    /// let mut users = RapidHasher::with_domain(b"user");
+    /// 42u64.hash(&mut users);
+    ///
+    /// let mut orders = RapidHasher::with_domain(b"order");
+    /// 42u64.hash(&mut orders);
+    ///
+    /// assert_ne!(users.finish(), orders.finish());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_domain(domain: &[u8]) -> Self {
+        Self(RapidInlineHasher::with_domain(domain))
+    }
+
     /// Const equivalent to [Hasher::write].
     ///
     /// # Example
@@ -127,6 +180,59 @@ impl RapidHasher {
     }
 }
 
+impl RapidBuildHasher {
+    /// Create a new [RapidBuildHasher] whose hashers use a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Create a new [RapidBuildHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(RapidHasher::DEFAULT_SEED)
+    }
+
+    /// Create a new [RapidBuildHasher] with a seed derived from a string key, equivalent to
+    /// [RapidHasher::with_key].
+    ///
+    /// # Example
+    /// ```
+    /// use std::hash::BuildHasher;
+    /// use rapidhash::RapidBuildHasher;
+    ///
+    /// static USERS: RapidBuildHasher = RapidBuildHasher::with_key("user-cache");
+    /// static URLS: RapidBuildHasher = RapidBuildHasher::with_key("url-dedup");
+    ///
+    /// assert_ne!(USERS.hash_one("hello"), URLS.hash_one("hello"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_key(key: &str) -> Self {
+        Self::new(rapidhash_key_seed(key))
+    }
+}
+
+impl Default for RapidBuildHasher {
+    /// Create a new [RapidBuildHasher] with the seed set by [crate::set_global_seed], or the default
+    /// seed if none was set.
+    #[inline]
+    fn default() -> Self {
+        Self::new(default_build_seed())
+    }
+}
+
+impl BuildHasher for RapidBuildHasher {
+    type Hasher = RapidHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        RapidHasher::new(self.seed)
+    }
+}
+
 impl Default for RapidHasher {
     /// Create a new [RapidHasher] with the default seed.
     ///
@@ -134,7 +240,7 @@ impl Default for RapidHasher {
     /// seed.
     #[inline]
     fn default() -> Self {
-        Self::new(RAPID_SEED)
+        Self::new(Self::DEFAULT_SEED)
     }
 }
 
@@ -242,4 +348,77 @@ mod tests {
             assert_eq!(a, b, "Mismatching hash for u64 with input {int}");
         }
     }
+
+    #[test]
+    fn test_const_build_hasher() {
+        static SEEDED: RapidBuildHasher = RapidBuildHasher::new(42);
+        const DEFAULT: RapidBuildHasher = RapidBuildHasher::default_const();
+
+        let mut hasher = RapidHasher::new(42);
+        hasher.write_u64(7);
+        assert_eq!(SEEDED.hash_one(7u64), hasher.finish());
+
+        let mut hasher = RapidHasher::default();
+        hasher.write_u64(7);
+        assert_eq!(DEFAULT.hash_one(7u64), hasher.finish());
+        assert_eq!(RapidBuildHasher::default().hash_one(7u64), hasher.finish());
+    }
+
+    #[test]
+    fn test_with_key() {
+        assert!(RapidHasher::with_key("user-cache") == RapidHasher::new(crate::rapidhash(b"user-cache")));
+        assert!(RapidBuildHasher::with_key("user-cache").build_hasher() == RapidHasher::with_key("user-cache"));
+        assert_ne!(RapidBuildHasher::with_key("a").hash_one(1u64), RapidBuildHasher::with_key("b").hash_one(1u64));
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn defmt_formats_state() {
+        fn format<T: defmt::Format>() {}
+        format::<crate::RapidId>();
+        format::<RapidHasher>();
+        format::<RapidBuildHasher>();
+        format::<crate::RapidInlineHasher>();
+        format::<crate::RapidInlineBuildHasher>();
+        #[cfg(feature = "rng")]
+        format::<crate::RapidRng>();
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh_resumes_hasher_state() {
+        let mut hasher = RapidBuildHasher::new(7).build_hasher();
+        hasher.write(b"hello ");
+
+        let bytes = borsh::to_vec(&hasher).unwrap();
+        let mut restored: RapidHasher = borsh::from_slice(&bytes).unwrap();
+        restored.write(b"world");
+        hasher.write(b"world");
+        assert_eq!(restored.finish(), hasher.finish());
+
+        let build = RapidBuildHasher::new(7);
+        assert_eq!(borsh::from_slice::<RapidBuildHasher>(&borsh::to_vec(&build).unwrap()).unwrap(), build);
+        let id = crate::RapidId::new("id");
+        assert_eq!(borsh::to_vec(&id).unwrap(), id.as_u64().to_le_bytes());
+    }
+
+    #[cfg(all(feature = "rkyv", feature = "alloc"))]
+    #[test]
+    fn rkyv_resumes_hasher_state() {
+        use rkyv::rancor::Error;
+
+        let mut hasher = RapidBuildHasher::new(7).build_hasher();
+        hasher.write(b"hello ");
+
+        let bytes = rkyv::to_bytes::<Error>(&hasher).unwrap();
+        let mut restored: RapidHasher = rkyv::from_bytes::<RapidHasher, Error>(&bytes).unwrap();
+        restored.write(b"world");
+        hasher.write(b"world");
+        assert_eq!(restored.finish(), hasher.finish());
+
+        let id = crate::RapidId::new("id");
+        let bytes = rkyv::to_bytes::<Error>(&id).unwrap();
+        let archived = rkyv::access::<crate::ArchivedRapidId, Error>(&bytes).unwrap();
+        assert_eq!(archived.0, id.as_u64());
+    }
 }
@@ -0,0 +1,194 @@
+use core::hash::{BuildHasher, Hasher};
+use crate::rapid_const::{rapid_mix, rapidhash_array_seeded, rapidhash_inline, RAPID_HASHER_SEED, RAPID_SECRET};
+use crate::rapid_global_seed::default_build_seed;
+
+/// A [Hasher] specialised for `[u8; N]` keys, such as UUIDs, digests, and IP addresses, that hashes
+/// each key with the const-generic [crate::rapidhash_array] oneshot.
+///
+/// [crate::RapidHasher] only sees a `&[u8]` of unknown length, so it has to branch on the length
+/// of every key. This hasher knows `N` at compile time, so once inlined the hash of a key is a
+/// straight line of reads and multiplies.
+///
+/// The `Hash` impl for `[u8; N]` writes the length before the bytes. A length equal to `N` is
+/// skipped, as it's the same for every key, so hashing a key with [RapidArrayBuildHasher] is equal
+/// to [crate::rapidhash_seeded] of its bytes. Other writes are still hashed correctly, but slower.
+///
+/// # Example
+/// ```
+/// use std::hash::BuildHasher;
+/// use rapidhash::{rapidhash_seeded, RapidArrayBuildHasher, RAPID_SEED};
+///
+/// let uuid = [7u8; 16];
+/// let hash = RapidArrayBuildHasher::<16>::new(RAPID_SEED).hash_one(uuid);
+/// assert_eq!(hash, rapidhash_seeded(&uuid, RAPID_SEED));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidArrayHasher<const N: usize> {
+    hash: u64,
+}
+
+/// A [std::hash::BuildHasher] trait compatible hasher that uses the [RapidArrayHasher] algorithm
+/// for `[u8; N]` keys.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use rapidhash::RapidArrayBuildHasher;
+///
+/// let mut map = HashMap::with_hasher(RapidArrayBuildHasher::<4>::default());
+/// map.insert([127u8, 0, 0, 1], "localhost");
+///
+/// static SEEDED: RapidArrayBuildHasher<16> = RapidArrayBuildHasher::new(0x1234);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidArrayBuildHasher<const N: usize> {
+    seed: u64,
+}
+
+/// A [std::collections::HashMap] type keyed by `[u8; N]` that uses the [RapidArrayBuildHasher]
+/// hasher.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidArrayHashMap;
+/// let mut map = RapidArrayHashMap::<16, _>::default();
+/// map.insert([0u8; 16], "nil uuid");
+/// ```
+#[cfg(any(feature = "std", docsrs))]
+pub type RapidArrayHashMap<const N: usize, V> = std::collections::HashMap<[u8; N], V, RapidArrayBuildHasher<N>>;
+
+/// A [std::collections::HashSet] type of `[u8; N]` that uses the [RapidArrayBuildHasher] hasher.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidArrayHashSet;
+/// let mut set = RapidArrayHashSet::<32>::default();
+/// set.insert([0xffu8; 32]);
+/// ```
+#[cfg(any(feature = "std", docsrs))]
+pub type RapidArrayHashSet<const N: usize> = std::collections::HashSet<[u8; N], RapidArrayBuildHasher<N>>;
+
+impl<const N: usize> RapidArrayHasher<N> {
+    /// Default `RapidArrayHasher` seed.
+    pub const DEFAULT_SEED: u64 = RAPID_HASHER_SEED;
+
+    /// Create a new [RapidArrayHasher] with a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { hash: seed }
+    }
+
+    /// Create a new [RapidArrayHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+
+    /// Const equivalent to hashing a `[u8; N]` key, returning the updated hasher.
+    #[inline(always)]
+    #[must_use]
+    pub const fn write_array_const(&self, key: &[u8; N]) -> Self {
+        Self { hash: rapidhash_array_seeded(key, self.hash) }
+    }
+
+    /// Const equivalent to [Hasher::finish].
+    #[inline(always)]
+    #[must_use]
+    pub const fn finish_const(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl<const N: usize> Default for RapidArrayHasher<N> {
+    /// Create a new [RapidArrayHasher] with the default seed.
+    #[inline]
+    fn default() -> Self {
+        Self::default_const()
+    }
+}
+
+impl<const N: usize> RapidArrayBuildHasher<N> {
+    /// Create a new [RapidArrayBuildHasher] whose hashers use a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Create a new [RapidArrayBuildHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(RapidArrayHasher::<N>::DEFAULT_SEED)
+    }
+}
+
+impl<const N: usize> Default for RapidArrayBuildHasher<N> {
+    /// Create a new [RapidArrayBuildHasher] with the seed set by [crate::set_global_seed], or the
+    /// default seed if none was set.
+    #[inline]
+    fn default() -> Self {
+        Self::new(default_build_seed())
+    }
+}
+
+impl<const N: usize> BuildHasher for RapidArrayBuildHasher<N> {
+    type Hasher = RapidArrayHasher<N>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        RapidArrayHasher::new(self.seed)
+    }
+}
+
+impl<const N: usize> Hasher for RapidArrayHasher<N> {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.finish_const()
+    }
+
+    /// Writes of exactly `N` bytes take the const-generic [crate::rapidhash_array] path. When
+    /// inlined into a `[u8; N]` key's `Hash` impl, the length check is resolved at compile time.
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        match <&[u8; N]>::try_from(bytes) {
+            Ok(key) => *self = self.write_array_const(key),
+            Err(_) => self.hash = rapidhash_inline(bytes, self.hash),
+        }
+    }
+
+    /// The length prefix of a `[u8; N]` key is always `N`, and is skipped. Any other value is
+    /// mixed into the state.
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        if i != N {
+            self.hash = rapid_mix(i as u64 ^ RAPID_SECRET[0], self.hash ^ RAPID_SECRET[1]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RAPID_SEED;
+
+    #[test]
+    fn array_hasher_matches_oneshot() {
+        let uuid: [u8; 16] = core::array::from_fn(|i| i as u8);
+        let digest = [0xabu8; 32];
+        assert_eq!(RapidArrayBuildHasher::<16>::new(RAPID_SEED).hash_one(uuid), crate::rapidhash(&uuid));
+        assert_eq!(RapidArrayBuildHasher::<16>::new(7).hash_one(uuid), crate::rapidhash_seeded(&uuid, 7));
+        assert_eq!(RapidArrayBuildHasher::<32>::new(RAPID_SEED).hash_one(digest), crate::rapidhash_array(&digest));
+        assert_eq!(crate::rapidhash_array_seeded(&digest, 7), crate::rapidhash_seeded(&digest, 7));
+    }
+
+    #[test]
+    fn array_hasher_other_writes() {
+        let build = RapidArrayBuildHasher::<4>::default();
+        assert_ne!(build.hash_one([1u8, 2, 3]), build.hash_one([1u8, 2, 3, 0]));
+        assert_ne!(build.hash_one(1usize), build.hash_one(2usize));
+        assert_ne!(build.hash_one(([1u8; 4], 5usize)), build.hash_one(([1u8; 4], 6usize)));
+    }
+}
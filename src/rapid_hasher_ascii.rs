@@ -0,0 +1,220 @@
+use core::hash::{BuildHasher, Hasher};
+use crate::rapid_ascii::{fold_ascii_lowercase, ASCII_FOLD_BUFFER};
+use crate::rapid_const::RAPID_HASHER_SEED;
+use crate::rapid_global_seed::default_build_seed;
+use crate::RapidHasher;
+
+/// A [Hasher] that folds ASCII case in byte slices before hashing them with [RapidHasher], so that
+/// `"Content-Type"` and `"content-type"` hash the same.
+///
+/// Only the bytes passed to [Hasher::write], such as the contents of a `str` or `[u8]`, are folded
+/// and integer writes are hashed unchanged. The bytes are lowercased through a stack buffer rather
+/// than an allocated copy. Non-ASCII bytes are unchanged, so this doesn't fold Unicode case.
+///
+/// A map with this hasher also needs keys whose [Eq] ignores ASCII case, otherwise keys that
+/// differ only in case hash the same but are still stored separately.
+///
+/// # Example
+/// ```
+/// use std::hash::{Hash, Hasher};
+/// use rapidhash::RapidAsciiLowercaseHasher;
+///
+/// let hash = |s: &str| {
+///     let mut hasher = RapidAsciiLowercaseHasher::default();
+///     s.hash(&mut hasher);
+///     hasher.finish()
+/// };
+///
+/// assert_eq!(hash("Content-Type"), hash("content-type"));
+/// assert_ne!(hash("Content-Type"), hash("Content-Length"));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct RapidAsciiLowercaseHasher(RapidHasher);
+
+/// A [std::hash::BuildHasher] trait compatible hasher that uses the [RapidAsciiLowercaseHasher]
+/// algorithm.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use std::hash::BuildHasher;
+/// use rapidhash::RapidAsciiLowercaseBuildHasher;
+///
+/// let build = RapidAsciiLowercaseBuildHasher::default();
+/// assert_eq!(build.hash_one("Accept"), build.hash_one("ACCEPT"));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidAsciiLowercaseBuildHasher {
+    seed: u64,
+}
+
+impl RapidAsciiLowercaseHasher {
+    /// Default `RapidAsciiLowercaseHasher` seed.
+    pub const DEFAULT_SEED: u64 = RAPID_HASHER_SEED;
+
+    /// Create a new [RapidAsciiLowercaseHasher] with a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self(RapidHasher::new(seed))
+    }
+
+    /// Create a new [RapidAsciiLowercaseHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+impl Default for RapidAsciiLowercaseHasher {
+    /// Create a new [RapidAsciiLowercaseHasher] with the default seed.
+    #[inline]
+    fn default() -> Self {
+        Self::default_const()
+    }
+}
+
+impl RapidAsciiLowercaseBuildHasher {
+    /// Create a new [RapidAsciiLowercaseBuildHasher] whose hashers use a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Create a new [RapidAsciiLowercaseBuildHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(RapidAsciiLowercaseHasher::DEFAULT_SEED)
+    }
+}
+
+impl Default for RapidAsciiLowercaseBuildHasher {
+    /// Create a new [RapidAsciiLowercaseBuildHasher] with the seed set by [crate::set_global_seed],
+    /// or the default seed if none was set.
+    #[inline]
+    fn default() -> Self {
+        Self::new(default_build_seed())
+    }
+}
+
+impl BuildHasher for RapidAsciiLowercaseBuildHasher {
+    type Hasher = RapidAsciiLowercaseHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        RapidAsciiLowercaseHasher::new(self.seed)
+    }
+}
+
+/// Byte slices are lowercased before hashing, and integers are forwarded to [RapidHasher]
+/// unchanged.
+impl Hasher for RapidAsciiLowercaseHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    /// Write a byte slice to the hasher with ASCII letters lowercased. Slices longer than 192 bytes
+    /// are written in 192-byte chunks.
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut buffer = [0u8; ASCII_FOLD_BUFFER];
+        for chunk in bytes.chunks(ASCII_FOLD_BUFFER) {
+            self.0.write(fold_ascii_lowercase(chunk, &mut buffer));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.0.write_u8(i)
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.0.write_u16(i)
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.0.write_u32(i)
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.0.write_u64(i)
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.0.write_u128(i)
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.0.write_usize(i)
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.0.write_i8(i)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.0.write_i16(i)
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.0.write_i32(i)
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.0.write_i64(i)
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.0.write_i128(i)
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.0.write_isize(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::hash::Hash;
+    use std::string::String;
+    use super::*;
+
+    fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = RapidAsciiLowercaseHasher::new(7);
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn folds_ascii_case() {
+        assert_eq!(hash("X-Request-ID"), hash("x-request-id"));
+        assert_eq!(hash(&("Host", 8080u16)), hash(&("HOST", 8080u16)));
+        assert_ne!(hash("host"), hash("hosts"));
+        assert_ne!(hash(&1u32), hash(&2u32));
+
+        let long: String = "AbC".repeat(200);
+        assert_eq!(hash(long.as_str()), hash(long.to_ascii_lowercase().as_str()));
+
+        // lowercase input hashes the same as with RapidHasher
+        let mut plain = RapidHasher::new(7);
+        "x-request-id".hash(&mut plain);
+        assert_eq!(hash("X-Request-ID"), plain.finish());
+    }
+}
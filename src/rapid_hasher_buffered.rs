@@ -0,0 +1,294 @@
+use core::hash::{BuildHasher, Hasher};
+use crate::rapid_const::RAPID_HASHER_SEED;
+use crate::rapid_global_seed::default_build_seed;
+use crate::RapidInlineHasher;
+
+/// The number of bytes [RapidBufferedHasher] coalesces before running the hash core, which is the
+/// width of one folded multiply.
+const CHUNK: usize = 16;
+
+/// A [Hasher] that coalesces consecutive small writes into 16-byte chunks before hashing them, for
+/// `#[derive(Hash)]` structs with many `u8`, `u16`, and `u32` fields.
+///
+/// [crate::RapidHasher] runs a full folded multiply for every `write_*` call, so a struct with
+/// eight `u32` fields costs eight multiplies. This hasher packs the fields into a 128-bit
+/// register, and only runs the hash core once per 16 bytes, halving the cost of such structs.
+///
+/// Each chunk is accumulated in a register rather than a byte buffer. Mixing narrow stores with a
+/// wide read of the same buffer stalls the CPU's store forwarding, which made a 64-byte buffer
+/// slower than not coalescing at all.
+///
+/// The hash is that of the concatenated little-endian bytes, so a stream of writes totalling at
+/// most 16 bytes is equal to [crate::rapidhash_seeded] of those bytes. Longer streams are hashed
+/// in chunks and differ from [crate::RapidHasher]. As with other streaming hashers,
+/// distinguishing `("ab", "c")` from `("a", "bc")` is left to the `Hash` impls, which prefix
+/// variable-length types with their length.
+///
+/// Prefer [crate::RapidHasher] for single integer or string keys, where there is nothing to
+/// coalesce.
+///
+/// # Example
+/// ```
+/// use std::hash::BuildHasher;
+/// use rapidhash::RapidBufferedBuildHasher;
+///
+/// #[derive(Hash)]
+/// struct Pixel {
+///     x: u16,
+///     y: u16,
+///     rgba: [u8; 4],
+/// }
+///
+/// let pixel = Pixel { x: 1, y: 2, rgba: [255, 0, 0, 255] };
+/// let hash = RapidBufferedBuildHasher::default().hash_one(&pixel);
+/// assert_ne!(hash, RapidBufferedBuildHasher::new(7).hash_one(&pixel));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct RapidBufferedHasher {
+    inner: RapidInlineHasher,
+    /// The pending bytes of the current chunk, in little-endian order.
+    chunk: u128,
+    /// The number of pending bytes, always less than [CHUNK].
+    len: usize,
+}
+
+/// A [std::hash::BuildHasher] trait compatible hasher that uses the [RapidBufferedHasher]
+/// algorithm.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use rapidhash::RapidBufferedBuildHasher;
+///
+/// let mut map = HashMap::with_hasher(RapidBufferedBuildHasher::default());
+/// map.insert((1u8, 2u16, 3u32), "small fields");
+///
+/// static SEEDED: RapidBufferedBuildHasher = RapidBufferedBuildHasher::new(0x1234);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidBufferedBuildHasher {
+    seed: u64,
+}
+
+/// A [std::collections::HashMap] type that uses the [RapidBufferedBuildHasher] hasher.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidBufferedHashMap;
+/// let mut map = RapidBufferedHashMap::default();
+/// map.insert((1u8, 2u16, 3u32), "small fields");
+/// ```
+#[cfg(any(feature = "std", docsrs))]
+pub type RapidBufferedHashMap<K, V> = std::collections::HashMap<K, V, RapidBufferedBuildHasher>;
+
+/// A [std::collections::HashSet] type that uses the [RapidBufferedBuildHasher] hasher.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidBufferedHashSet;
+/// let mut set = RapidBufferedHashSet::default();
+/// set.insert((1u8, 2u16, 3u32));
+/// ```
+#[cfg(any(feature = "std", docsrs))]
+pub type RapidBufferedHashSet<K> = std::collections::HashSet<K, RapidBufferedBuildHasher>;
+
+impl RapidBufferedHasher {
+    /// Default `RapidBufferedHasher` seed.
+    pub const DEFAULT_SEED: u64 = RAPID_HASHER_SEED;
+
+    /// Create a new [RapidBufferedHasher] with a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            inner: RapidInlineHasher::new(seed),
+            chunk: 0,
+            len: 0,
+        }
+    }
+
+    /// Create a new [RapidBufferedHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+
+    /// Append the low `n` bytes of `value`, for `n <= 16`, hashing the chunk once it's full.
+    #[inline(always)]
+    fn push(&mut self, value: u128, n: usize) {
+        if self.len + n < CHUNK {
+            self.chunk |= value << (8 * self.len);
+            self.len += n;
+            return;
+        }
+
+        // fill the rest of the chunk, and carry the remaining bytes of the value to the next
+        let taken = CHUNK - self.len;
+        let full = self.chunk | value << (8 * self.len);
+        self.inner = self.inner.write_const(&full.to_le_bytes());
+        self.chunk = if taken < CHUNK { value >> (8 * taken) } else { 0 };
+        self.len = n - taken;
+    }
+}
+
+impl Default for RapidBufferedHasher {
+    /// Create a new [RapidBufferedHasher] with the default seed.
+    #[inline]
+    fn default() -> Self {
+        Self::default_const()
+    }
+}
+
+impl RapidBufferedBuildHasher {
+    /// Create a new [RapidBufferedBuildHasher] whose hashers use a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Create a new [RapidBufferedBuildHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(RapidBufferedHasher::DEFAULT_SEED)
+    }
+}
+
+impl Default for RapidBufferedBuildHasher {
+    /// Create a new [RapidBufferedBuildHasher] with the seed set by [crate::set_global_seed], or the default
+    /// seed if none was set.
+    #[inline]
+    fn default() -> Self {
+        Self::new(default_build_seed())
+    }
+}
+
+impl BuildHasher for RapidBufferedBuildHasher {
+    type Hasher = RapidBufferedHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        RapidBufferedHasher::new(self.seed)
+    }
+}
+
+impl Hasher for RapidBufferedHasher {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        if self.len == 0 {
+            self.inner.finish_const()
+        } else {
+            self.inner.write_const(&self.chunk.to_le_bytes()[..self.len]).finish_const()
+        }
+    }
+
+    /// Coalesce `bytes` if they're shorter than a chunk, otherwise hash the pending bytes and then
+    /// `bytes` directly.
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        if bytes.len() < CHUNK {
+            let mut value = [0u8; CHUNK];
+            value[..bytes.len()].copy_from_slice(bytes);
+            self.push(u128::from_le_bytes(value), bytes.len());
+            return;
+        }
+
+        if self.len > 0 {
+            self.inner = self.inner.write_const(&self.chunk.to_le_bytes()[..self.len]);
+            self.chunk = 0;
+            self.len = 0;
+        }
+        self.inner = self.inner.write_const(bytes);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.push(i as u128, 1);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.push(i as u128, 2);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.push(i as u128, 4);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.push(i as u128, 8);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, i: u128) {
+        self.push(i, 16);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.push(i as u128, core::mem::size_of::<usize>());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_streams_match_oneshot() {
+        let mut hasher = RapidBufferedHasher::new(7);
+        hasher.write_u8(1);
+        hasher.write_u16(2);
+        hasher.write_u32(3);
+        hasher.write(b"hello");
+        let mut expected = [0u8; 12];
+        expected[0] = 1;
+        expected[1..3].copy_from_slice(&2u16.to_le_bytes());
+        expected[3..7].copy_from_slice(&3u32.to_le_bytes());
+        expected[7..].copy_from_slice(b"hello");
+        assert_eq!(hasher.finish(), crate::rapidhash_seeded(&expected, 7));
+
+        // a value straddling two chunks is split between them
+        let mut hasher = RapidBufferedHasher::new(7);
+        hasher.write_u64(u64::MAX);
+        hasher.write_u128(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+        let mut expected = [0u8; 24];
+        expected[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        expected[8..].copy_from_slice(&0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128.to_le_bytes());
+        let mut chunked = RapidInlineHasher::new(7);
+        chunked.write(&expected[..16]);
+        chunked.write(&expected[16..]);
+        assert_eq!(hasher.finish(), chunked.finish());
+    }
+
+    #[test]
+    fn long_streams_hash_in_chunks() {
+        let data: [u8; 300] = core::array::from_fn(|i| i as u8);
+
+        // 100 writes of 3 bytes are coalesced into chunks of 16 bytes
+        let mut buffered = RapidBufferedHasher::default();
+        for chunk in data.chunks(3) {
+            buffered.write(chunk);
+        }
+        let mut expected = RapidInlineHasher::default();
+        for chunk in data.chunks(16) {
+            expected.write(chunk);
+        }
+        assert_eq!(buffered.finish(), expected.finish());
+
+        // a large write hashes the pending bytes first, and is then hashed directly
+        let mut buffered = RapidBufferedHasher::default();
+        buffered.write_u32(5);
+        buffered.write(&data);
+        buffered.write_u8(9);
+        let mut expected = RapidInlineHasher::default();
+        expected.write(&5u32.to_le_bytes());
+        expected.write(&data);
+        expected.write(&[9]);
+        assert_eq!(buffered.finish(), expected.finish());
+    }
+}
@@ -1,5 +1,6 @@
-use core::hash::Hasher;
-use crate::rapid_const::{rapidhash_core, rapidhash_finish, rapidhash_seed, RAPID_SEED};
+use core::hash::{BuildHasher, Hasher};
+use crate::rapid_const::{rapid_mix, rapidhash_core, rapidhash_finish, rapidhash_key_seed, RAPID_HASHER_SEED, RAPID_SECRET};
+use crate::rapid_global_seed::default_build_seed;
 
 /// A [Hasher] trait compatible hasher that uses the [rapidhash](https://github.com/Nicoshev/rapidhash)
 /// algorithm, and uses `#[inline(always)]` for all methods.
@@ -22,6 +23,9 @@ use crate::rapid_const::{rapidhash_core, rapidhash_finish, rapidhash_seed, RAPID
 /// let hash = hasher.finish();
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct RapidInlineHasher {
     seed: u64,
     a: u64,
@@ -31,7 +35,9 @@ pub struct RapidInlineHasher {
 
 /// A [std::hash::BuildHasher] trait compatible hasher that uses the [RapidInlineHasher] algorithm.
 ///
-/// This is an alias for [`std::hash::BuildHasherDefault<RapidHasher>`] with a static seed.
+/// Uses the default seed unless constructed with [RapidInlineBuildHasher::new]. Both constructors
+/// are `const`, so a build hasher can live in a `static` without lazy initialisation. With `std`,
+/// `default()` uses the seed from [crate::set_global_seed] instead, if one was set.
 ///
 /// Note there that [crate::RapidRandomState] with can be used instead for a
 /// [std::hash::BuildHasher] that initialises with a random seed.
@@ -39,13 +45,20 @@ pub struct RapidInlineHasher {
 /// # Example
 /// ```
 /// use std::collections::HashMap;
-/// use std::hash::Hasher;
-/// use rapidhash::RapidInlineHashBuilder;
+/// use rapidhash::RapidInlineBuildHasher;
 ///
-/// let mut map = HashMap::with_hasher(RapidInlineHashBuilder::default());
+/// let mut map = HashMap::with_hasher(RapidInlineBuildHasher::default());
 /// map.insert(42, "the answer");
+///
+/// static SEEDED: RapidInlineBuildHasher = RapidInlineBuildHasher::new(0x1234);
 /// ```
-pub type RapidInlineBuildHasher = core::hash::BuildHasherDefault<RapidInlineHasher>;
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RapidInlineBuildHasher {
+    seed: u64,
+}
 
 /// Deprecated and renamed to [RapidInlineBuildHasher].
 #[deprecated(since = "1.1.0", note = "Renamed to `RapidInlineBuildHasher`")]
@@ -83,15 +96,21 @@ pub type RapidInlineHashMap<K, V> = std::collections::HashMap<K, V, RapidInlineB
 pub type RapidInlineHashSet<K> = std::collections::HashSet<K, RapidInlineBuildHasher>;
 
 impl RapidInlineHasher {
-    /// Default `RapidHasher` seed.
-    pub const DEFAULT_SEED: u64 = RAPID_SEED;
+    /// Default `RapidInlineHasher` seed.
+    ///
+    /// This is [crate::RAPID_SEED], unless the `const-random-seed` feature is enabled, in which
+    /// case it's generated at compile time so it differs between builds.
+    pub const DEFAULT_SEED: u64 = RAPID_HASHER_SEED;
 
     /// Create a new [RapidInlineHasher] with a custom seed.
+    ///
+    /// The seed's folded multiply from [crate::raw::rapidhash_seed] is applied once here, rather
+    /// than on every write. For a constant seed it's computed at compile time.
     #[inline(always)]
     #[must_use]
     pub const fn new(seed: u64) -> Self {
         Self {
-            seed,
+            seed: seed ^ rapid_mix(seed ^ RAPID_SECRET[0], RAPID_SECRET[1]),
             a: 0,
             b: 0,
             size: 0,
@@ -105,10 +124,39 @@ impl RapidInlineHasher {
         Self::new(Self::DEFAULT_SEED)
     }
 
+    /// Create a new [RapidInlineHasher] with a seed derived from a string key, such as `"user-cache"`, to
+    /// namespace hashes without choosing ad-hoc seed constants.
+    ///
+    /// The seed is the [crate::rapidhash] of the key, and so is stable across builds and platforms.
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_key(key: &str) -> Self {
+        Self::new(rapidhash_key_seed(key))
+    }
+
+    /// Create a new [RapidInlineHasher] using the default seed, which has already absorbed a
+    /// length-prefixed `domain` tag.
+    ///
+    /// Values hashed under different domains are framed as distinct inputs, see
+    /// [crate::tagged_hash].
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_domain(domain: &[u8]) -> Self {
+        Self::default_const()
+            .write_const(&(domain.len() as u64).to_le_bytes())
+            .write_const(domain)
+    }
+
     /// Const equivalent to [Hasher::write], and marked as `#[inline(always)]`.
     ///
     /// This can deliver a large performance improvement when the `bytes` length is known at compile
     /// time.
+    ///
+    /// Each write xors the running length into the seed, which was already mixed in
+    /// [RapidInlineHasher::new], so a single write is equal to the oneshot [crate::rapidhash]. Later
+    /// writes don't re-run the seed's folded multiply, which made up a large part of the cost of
+    /// hashing a `#[derive(Hash)]` struct with many small fields. The `a` and `b` state is still
+    /// carried through a full multiply on every write.
     #[inline(always)]
     #[must_use]
     pub const fn write_const(&self, bytes: &[u8]) -> Self {
@@ -121,7 +169,7 @@ impl RapidInlineHasher {
 
         let mut this = *self;
         this.size += bytes.len() as u64;
-        this.seed = rapidhash_seed(this.seed, this.size);
+        this.seed ^= this.size;
         let (a, b, seed) = rapidhash_core(this.a, this.b, this.seed, bytes);
         this.a = a;
         this.b = b;
@@ -137,6 +185,59 @@ impl RapidInlineHasher {
     }
 }
 
+impl RapidInlineBuildHasher {
+    /// Create a new [RapidInlineBuildHasher] whose hashers use a custom seed.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Create a new [RapidInlineBuildHasher] using the default seed.
+    #[inline(always)]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(RapidInlineHasher::DEFAULT_SEED)
+    }
+
+    /// Create a new [RapidInlineBuildHasher] with a seed derived from a string key, equivalent to
+    /// [RapidInlineHasher::with_key].
+    ///
+    /// # Example
+    /// ```
+    /// use std::hash::BuildHasher;
+    /// use rapidhash::RapidInlineBuildHasher;
+    ///
+    /// static USERS: RapidInlineBuildHasher = RapidInlineBuildHasher::with_key("user-cache");
+    /// static URLS: RapidInlineBuildHasher = RapidInlineBuildHasher::with_key("url-dedup");
+    ///
+    /// assert_ne!(USERS.hash_one("hello"), URLS.hash_one("hello"));
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_key(key: &str) -> Self {
+        Self::new(rapidhash_key_seed(key))
+    }
+}
+
+impl Default for RapidInlineBuildHasher {
+    /// Create a new [RapidInlineBuildHasher] with the seed set by [crate::set_global_seed], or the default
+    /// seed if none was set.
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(default_build_seed())
+    }
+}
+
+impl BuildHasher for RapidInlineBuildHasher {
+    type Hasher = RapidInlineHasher;
+
+    #[inline(always)]
+    fn build_hasher(&self) -> Self::Hasher {
+        RapidInlineHasher::new(self.seed)
+    }
+}
+
 impl Default for RapidInlineHasher {
     /// Create a new [RapidInlineHasher] with the default seed.
     ///
@@ -144,7 +245,7 @@ impl Default for RapidInlineHasher {
     /// seed.
     #[inline(always)]
     fn default() -> Self {
-        Self::new(RAPID_SEED)
+        Self::new(Self::DEFAULT_SEED)
     }
 }
 
@@ -184,7 +285,7 @@ impl Hasher for RapidInlineHasher {
 
         // NOTE: in case of compiler regression, it should compile to:
         // self.size += size_of::<u64>() as u64;
-        // self.seed ^= rapid_mix(self.seed ^ RAPID_SECRET[0], RAPID_SECRET[1]) ^ self.size;
+        // self.seed ^= self.size;
         // self.a ^= i.rotate_right(32) ^ RAPID_SECRET[1];
         // self.b ^= i ^ self.seed;
         // rapid_mum(&mut self.a, &mut self.b);
@@ -0,0 +1,256 @@
+use core::hash::{BuildHasher, Hasher};
+use crate::rapid_const::{rapid_mix, rapidhash_inline, RAPID_HASHER_SEED, RAPID_SECRET};
+use crate::rapid_global_seed::default_build_seed;
+
+/// A minimal [Hasher] for integer keys, hashing each integer with a single [crate::raw::rapid_mix]
+/// folded multiply.
+///
+/// [crate::RapidHasher] runs the full rapidhash seeding, mixing, and finalisation for every write,
+/// which is overkill for `HashMap<u64, V>`. This hasher keeps a single `u64` of state and mixes
+/// each integer into it with one 64x64-bit multiply, competing with `fxhash` on integer workloads
+/// while still mixing the high bits into the low bits and vice versa.
+///
+/// Only use this for integer keys, or structs of integers, from a trusted source. It has none of
+/// rapidhash's quality guarantees for other inputs and no HashDoS resistance. Byte slices, such as
+/// strings, fall back to a full [crate::rapidhash] of the bytes. The output for a given key is not
+/// equal to [crate::RapidHasher]'s.
+///
+/// # Example
+/// ```
+/// use std::hash::Hasher;
+/// use rapidhash::RapidIntHasher;
+///
+/// let mut hasher = RapidIntHasher::default();
+/// hasher.write_u64(42);
+/// let hash = hasher.finish();
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidIntHasher {
+    hash: u64,
+}
+
+/// A [std::hash::BuildHasher] trait compatible hasher that uses the [RapidIntHasher] algorithm.
+///
+/// Only suitable for trusted integer keys, see [RapidIntHasher].
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use rapidhash::RapidIntBuildHasher;
+///
+/// let mut map = HashMap::with_hasher(RapidIntBuildHasher::default());
+/// map.insert(42u64, "the answer");
+///
+/// static SEEDED: RapidIntBuildHasher = RapidIntBuildHasher::new(0x1234);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidIntBuildHasher {
+    seed: u64,
+}
+
+/// A [std::collections::HashMap] type that uses the [RapidIntBuildHasher] hasher, for trusted
+/// integer keys.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidIntHashMap;
+/// let mut map = RapidIntHashMap::default();
+/// map.insert(42u64, "the answer");
+/// ```
+#[cfg(any(feature = "std", docsrs))]
+pub type RapidIntHashMap<K, V> = std::collections::HashMap<K, V, RapidIntBuildHasher>;
+
+/// A [std::collections::HashSet] type that uses the [RapidIntBuildHasher] hasher, for trusted
+/// integer keys.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidIntHashSet;
+/// let mut set = RapidIntHashSet::default();
+/// set.insert(42u32);
+/// ```
+#[cfg(any(feature = "std", docsrs))]
+pub type RapidIntHashSet<K> = std::collections::HashSet<K, RapidIntBuildHasher>;
+
+impl RapidIntHasher {
+    /// Default `RapidIntHasher` seed.
+    pub const DEFAULT_SEED: u64 = RAPID_HASHER_SEED;
+
+    /// Create a new [RapidIntHasher] with a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { hash: seed }
+    }
+
+    /// Create a new [RapidIntHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+
+    /// Const equivalent to [Hasher::write_u64].
+    #[inline(always)]
+    #[must_use]
+    pub const fn write_u64_const(&self, i: u64) -> Self {
+        Self { hash: rapid_mix(i ^ RAPID_SECRET[0], self.hash ^ RAPID_SECRET[1]) }
+    }
+
+    /// Const equivalent to [Hasher::finish].
+    #[inline(always)]
+    #[must_use]
+    pub const fn finish_const(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl Default for RapidIntHasher {
+    /// Create a new [RapidIntHasher] with the default seed.
+    #[inline]
+    fn default() -> Self {
+        Self::default_const()
+    }
+}
+
+impl RapidIntBuildHasher {
+    /// Create a new [RapidIntBuildHasher] whose hashers use a custom seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Create a new [RapidIntBuildHasher] using the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn default_const() -> Self {
+        Self::new(RapidIntHasher::DEFAULT_SEED)
+    }
+}
+
+impl Default for RapidIntBuildHasher {
+    /// Create a new [RapidIntBuildHasher] with the seed set by [crate::set_global_seed], or the default
+    /// seed if none was set.
+    #[inline]
+    fn default() -> Self {
+        Self::new(default_build_seed())
+    }
+}
+
+impl BuildHasher for RapidIntBuildHasher {
+    type Hasher = RapidIntHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        RapidIntHasher::new(self.seed)
+    }
+}
+
+/// Every integer write is widened to a `u64` and mixed in with [RapidIntHasher::write_u64_const].
+impl Hasher for RapidIntHasher {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.finish_const()
+    }
+
+    /// Byte slices aren't the intended use of this hasher, and fall back to a full rapidhash seeded
+    /// with the current state.
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash = rapidhash_inline(bytes, self.hash);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        *self = self.write_u64_const(i);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn write_i8(&mut self, i: i8) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn write_i16(&mut self, i: i16) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn write_i32(&mut self, i: i32) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    #[inline(always)]
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_hasher_spreads_sequential_keys() {
+        // a fixed seed, as the default seed differs between builds with `const-random-seed`
+        let build = RapidIntBuildHasher::new(crate::RAPID_SEED);
+        let mut low = [0u32; 16];
+        let mut high = [0u32; 16];
+        for i in 0..16_000u64 {
+            let hash = build.hash_one(i);
+            low[(hash & 15) as usize] += 1;
+            high[(hash >> 60) as usize] += 1;
+        }
+        for count in low.iter().chain(high.iter()) {
+            assert!(*count > 850 && *count < 1150, "count {count}");
+        }
+    }
+
+    #[test]
+    fn int_hasher_seeds_and_widths() {
+        assert_ne!(RapidIntBuildHasher::new(1).hash_one(7u64), RapidIntBuildHasher::new(2).hash_one(7u64));
+
+        let build = RapidIntBuildHasher::default();
+        assert_eq!(build.hash_one(7u32), build.hash_one(7u64));
+        assert_ne!(build.hash_one(7u64), build.hash_one(8u64));
+        assert_ne!(build.hash_one((1u64, 2u64)), build.hash_one((2u64, 1u64)));
+        assert_ne!(build.hash_one("a"), build.hash_one("b"));
+    }
+}
@@ -0,0 +1,270 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::vec::Vec;
+use crate::rapid_const::RAPID_SEED;
+use crate::RapidBuildHasher;
+
+/// A bounded-memory heavy hitters sketch, using the SpaceSaving algorithm keyed by rapidhash.
+///
+/// Tracks at most `capacity` keys. While there is space, every key is counted exactly. Once full,
+/// an unseen key replaces the key with the smallest count and inherits that count, which is
+/// recorded as the new key's error. Every key that occurs more than `total / capacity` times is
+/// guaranteed to be tracked, and every estimated count is an overestimate by at most its error.
+///
+/// Each insertion is a single hash map lookup plus `O(log capacity)` heap updates.
+///
+/// Requires the `std` and `hasher` features.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidHeavyHitters;
+///
+/// let mut hitters = RapidHeavyHitters::new(16);
+/// for i in 0..10_000u32 {
+///     // key 7 makes up half of the stream
+///     let key = if i % 2 == 0 { 7 } else { 100 + i };
+///     hitters.insert(key);
+/// }
+///
+/// let top = hitters.top_k(1);
+/// assert_eq!(*top[0].key, 7);
+/// assert!(top[0].count - top[0].error <= 5_000 && top[0].count >= 5_000);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RapidHeavyHitters<K> {
+    capacity: usize,
+    total: u64,
+    slots: HashMap<K, usize, RapidBuildHasher>,
+    counters: Vec<Counter<K>>,
+    /// A binary min-heap of counter indices, ordered by count.
+    heap: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct Counter<K> {
+    key: K,
+    count: u64,
+    error: u64,
+    /// The position of this counter in the heap.
+    position: usize,
+}
+
+/// A key tracked by [RapidHeavyHitters], with its estimated count.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct HeavyHitter<'a, K> {
+    /// The tracked key.
+    pub key: &'a K,
+    /// The estimated count, which is never below the true count.
+    pub count: u64,
+    /// The maximum overestimate, so the true count is at least `count - error`.
+    pub error: u64,
+}
+
+impl<K: Hash + Eq + Clone> RapidHeavyHitters<K> {
+    /// Create an empty sketch that tracks at most `capacity` keys, with the default seed.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    #[inline]
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::new_seeded(capacity, RAPID_SEED)
+    }
+
+    /// Create an empty sketch that tracks at most `capacity` keys, with a custom seed.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    #[must_use]
+    pub fn new_seeded(capacity: usize, seed: u64) -> Self {
+        assert!(capacity > 0, "RapidHeavyHitters: capacity must be non-zero");
+        Self {
+            capacity,
+            total: 0,
+            slots: HashMap::with_capacity_and_hasher(capacity, RapidBuildHasher::new(seed)),
+            counters: Vec::with_capacity(capacity),
+            heap: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// The maximum number of keys tracked.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of keys currently tracked.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.counters.len()
+    }
+
+    /// Returns true if nothing has been inserted.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty()
+    }
+
+    /// The total weight of everything inserted, including untracked keys.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Count one occurrence of `key`.
+    #[inline]
+    pub fn insert(&mut self, key: K) {
+        self.insert_weighted(key, 1);
+    }
+
+    /// Count `weight` occurrences of `key`.
+    pub fn insert_weighted(&mut self, key: K, weight: u64) {
+        self.total += weight;
+
+        if let Some(&index) = self.slots.get(&key) {
+            self.counters[index].count += weight;
+            self.sift_down(self.counters[index].position);
+            return;
+        }
+
+        if self.counters.len() < self.capacity {
+            let index = self.counters.len();
+            self.slots.insert(key.clone(), index);
+            self.counters.push(Counter { key, count: weight, error: 0, position: index });
+            self.heap.push(index);
+            self.sift_up(index);
+            return;
+        }
+
+        // replace the smallest counter, which is at the root of the heap
+        let index = self.heap[0];
+        let counter = &mut self.counters[index];
+        self.slots.remove(&counter.key);
+        self.slots.insert(key.clone(), index);
+        counter.key = key;
+        counter.error = counter.count;
+        counter.count += weight;
+        self.sift_down(0);
+    }
+
+    /// The estimated count of `key`, or `None` if it isn't tracked.
+    ///
+    /// An untracked key occurred at most as many times as the smallest tracked count.
+    pub fn count<Q>(&self, key: &Q) -> Option<u64>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.slots.get(key).map(|&index| self.counters[index].count)
+    }
+
+    /// The `k` keys with the highest estimated counts, in descending order of count.
+    pub fn top_k(&self, k: usize) -> Vec<HeavyHitter<'_, K>> {
+        let mut top: Vec<_> = self.counters.iter().map(|counter| HeavyHitter {
+            key: &counter.key,
+            count: counter.count,
+            error: counter.error,
+        }).collect();
+        top.sort_unstable_by_key(|hitter| core::cmp::Reverse(hitter.count));
+        top.truncate(k);
+        top
+    }
+
+    /// Remove all keys and reset the total.
+    pub fn clear(&mut self) {
+        self.total = 0;
+        self.slots.clear();
+        self.counters.clear();
+        self.heap.clear();
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.counters[self.heap[a]].position = a;
+        self.counters[self.heap[b]].position = b;
+    }
+
+    fn count_at(&self, position: usize) -> u64 {
+        self.counters[self.heap[position]].count
+    }
+
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if self.count_at(parent) <= self.count_at(position) {
+                break;
+            }
+            self.swap(parent, position);
+            position = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut position: usize) {
+        loop {
+            let left = 2 * position + 1;
+            let right = left + 1;
+            let mut smallest = position;
+            if left < self.heap.len() && self.count_at(left) < self.count_at(smallest) {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.count_at(right) < self.count_at(smallest) {
+                smallest = right;
+            }
+            if smallest == position {
+                break;
+            }
+            self.swap(position, smallest);
+            position = smallest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_below_capacity() {
+        let mut hitters = RapidHeavyHitters::new(10);
+        for i in 0..10u32 {
+            hitters.insert_weighted(i, i as u64 + 1);
+        }
+        hitters.insert(3);
+        assert_eq!(hitters.len(), 10);
+        assert_eq!(hitters.total(), 56);
+        assert_eq!(hitters.count(&3), Some(5));
+        assert_eq!(hitters.count(&10), None);
+
+        let top = hitters.top_k(3);
+        assert_eq!(top.iter().map(|h| *h.key).collect::<Vec<_>>(), [9, 8, 7]);
+        assert!(top.iter().all(|h| h.error == 0));
+    }
+
+    #[test]
+    fn finds_frequent_keys() {
+        let mut hitters = RapidHeavyHitters::new(20);
+        let mut exact = HashMap::new();
+        for i in 0..100_000u64 {
+            // keys 0..5 each make up 10% of the stream, the rest are unique
+            let key = if i % 2 == 0 { i % 10 / 2 } else { i + 1000 };
+            hitters.insert(key);
+            *exact.entry(key).or_insert(0u64) += 1;
+        }
+
+        let top = hitters.top_k(5);
+        let mut keys: Vec<_> = top.iter().map(|h| *h.key).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, [0, 1, 2, 3, 4]);
+
+        // every tracked count brackets the true count
+        for hitter in hitters.top_k(20) {
+            let actual = exact[hitter.key];
+            assert!(hitter.count >= actual && hitter.count - hitter.error <= actual);
+        }
+
+        hitters.clear();
+        assert!(hitters.is_empty());
+        assert_eq!(hitters.total(), 0);
+    }
+}
@@ -0,0 +1,374 @@
+use core::hash::{Hash, Hasher};
+use alloc::vec::Vec;
+use crate::rapid_const::{rapidhash_inline, RAPID_SEED};
+use crate::RapidHasher;
+
+/// The smallest supported precision, using 16 registers.
+const MIN_PRECISION: u8 = 4;
+
+/// The largest supported precision, using 256 KiB of registers.
+const MAX_PRECISION: u8 = 18;
+
+/// A HyperLogLog approximate distinct counter that uses rapidhash as its 64-bit hash function.
+///
+/// The precision `p` sets the number of registers to `2^p` bytes, with a standard error of roughly
+/// `1.04 / sqrt(2^p)`. For example, the default precision of 12 uses 4 KiB and estimates within
+/// about 1.6%.
+///
+/// Two counters with the same precision and seed can be merged to estimate the size of the union
+/// of their inputs, for example when counting in parallel or across machines. Send a counter to
+/// another machine with [RapidHyperLogLog::registers] and rebuild it with
+/// [RapidHyperLogLog::from_registers].
+///
+/// Counters that are stored or merged across machines or crate versions should be filled with
+/// [RapidHyperLogLog::insert_bytes] or [RapidHyperLogLog::insert_hash]. [RapidHyperLogLog::insert]
+/// hashes with the [RapidHasher], whose output is not stable across crate versions.
+///
+/// Requires the `alloc` and `hasher` features.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidHyperLogLog;
+///
+/// let mut hll = RapidHyperLogLog::default();
+/// for i in 0..10_000u32 {
+///     hll.insert(&(i % 1000));
+/// }
+///
+/// let estimate = hll.count();
+/// assert!(estimate > 950 && estimate < 1050);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RapidHyperLogLog {
+    precision: u8,
+    seed: u64,
+    registers: Vec<u8>,
+}
+
+impl RapidHyperLogLog {
+    /// The default precision, using 4 KiB of registers.
+    pub const DEFAULT_PRECISION: u8 = 12;
+
+    /// Create a new [RapidHyperLogLog] with the default seed and the given precision.
+    ///
+    /// # Panics
+    /// Panics if the precision is not between 4 and 18 inclusive.
+    #[inline]
+    #[must_use]
+    pub fn new(precision: u8) -> Self {
+        Self::new_seeded(precision, RAPID_SEED)
+    }
+
+    /// Create a new [RapidHyperLogLog] with a custom seed and the given precision.
+    ///
+    /// # Panics
+    /// Panics if the precision is not between 4 and 18 inclusive.
+    #[must_use]
+    pub fn new_seeded(precision: u8, seed: u64) -> Self {
+        assert!(
+            (MIN_PRECISION..=MAX_PRECISION).contains(&precision),
+            "RapidHyperLogLog: precision must be between {MIN_PRECISION} and {MAX_PRECISION}",
+        );
+        Self {
+            precision,
+            seed,
+            registers: alloc::vec![0; 1 << precision],
+        }
+    }
+
+    /// The precision of this counter.
+    #[inline]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// The seed used to hash items.
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The `2^precision` registers of this counter, each the highest rank seen for its bucket.
+    ///
+    /// Together with the precision and seed, this is the whole state of the counter, and can be
+    /// restored with [RapidHyperLogLog::from_registers].
+    #[inline]
+    pub fn registers(&self) -> &[u8] {
+        &self.registers
+    }
+
+    /// Rebuild a counter from its precision, seed, and [RapidHyperLogLog::registers].
+    ///
+    /// Returns `None` if the precision is not between 4 and 18 inclusive, if there aren't exactly
+    /// `2^precision` registers, or if a register holds a rank no hash could produce.
+    ///
+    /// # Example
+    /// ```
+    /// use rapidhash::RapidHyperLogLog;
+    ///
+    /// let mut hll = RapidHyperLogLog::new(10);
+    /// hll.insert_bytes(b"hello");
+    ///
+    /// let restored = RapidHyperLogLog::from_registers(hll.precision(), hll.seed(), hll.registers().to_vec());
+    /// assert_eq!(restored, Some(hll));
+    /// ```
+    #[must_use]
+    pub fn from_registers(precision: u8, seed: u64, registers: Vec<u8>) -> Option<Self> {
+        if !(MIN_PRECISION..=MAX_PRECISION).contains(&precision) || registers.len() != 1 << precision {
+            return None;
+        }
+        // matches the largest rank insert_hash can produce
+        let max_rank = 64 - precision + 1;
+        if registers.iter().any(|&register| register > max_rank) {
+            return None;
+        }
+        Some(Self { precision, seed, registers })
+    }
+
+    /// Hash and insert an item using the [RapidHasher].
+    ///
+    /// The [RapidHasher] output may change between crate versions, so use
+    /// [RapidHyperLogLog::insert_bytes] or [RapidHyperLogLog::insert_hash] for counters that are
+    /// stored, or merged with counters built by another build.
+    #[inline]
+    pub fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        let mut hasher = RapidHasher::new(self.seed);
+        item.hash(&mut hasher);
+        self.insert_hash(hasher.finish());
+    }
+
+    /// Hash and insert a byte slice using [crate::rapidhash_seeded].
+    #[inline]
+    pub fn insert_bytes(&mut self, bytes: &[u8]) {
+        self.insert_hash(rapidhash_inline(bytes, self.seed));
+    }
+
+    /// Insert a pre-computed 64-bit hash.
+    ///
+    /// The hash must be well distributed, and computed the same way for every item.
+    #[inline]
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+
+        // the sentinel bit caps the rank at 64 - p + 1 when the remaining bits are all zero
+        let remaining = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+
+        let register = &mut self.registers[index];
+        if rank > *register {
+            *register = rank;
+        }
+    }
+
+    /// Estimate the number of distinct items inserted.
+    #[must_use]
+    pub fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+
+        let mut sum = 0.0;
+        let mut zeros = 0;
+        for &register in &self.registers {
+            sum += 1.0 / (1u64 << register) as f64;
+            zeros += (register == 0) as usize;
+        }
+
+        let estimate = alpha * m * m / sum;
+
+        // linear counting is more accurate for small cardinalities
+        if estimate <= 2.5 * m && zeros > 0 {
+            m * ln(m / zeros as f64)
+        } else {
+            estimate
+        }
+    }
+
+    /// Estimate the number of distinct items inserted, rounded to the nearest integer.
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        // the estimate is never negative, so this rounds to the nearest integer without std
+        (self.estimate() + 0.5) as u64
+    }
+
+    /// Returns `true` if no items have been inserted.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.registers.iter().all(|&register| register == 0)
+    }
+
+    /// Merge another counter into this one, so this counter estimates the union of both inputs.
+    ///
+    /// # Panics
+    /// Panics if the counters have different precisions or seeds.
+    pub fn merge(&mut self, other: &Self) {
+        assert!(
+            self.precision == other.precision && self.seed == other.seed,
+            "RapidHyperLogLog: cannot merge counters with different precisions or seeds",
+        );
+        for (register, &theirs) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(theirs);
+        }
+    }
+
+    /// Reset the counter to empty.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.registers.fill(0);
+    }
+}
+
+impl Default for RapidHyperLogLog {
+    /// Create a new [RapidHyperLogLog] with the default seed and precision.
+    #[inline]
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PRECISION)
+    }
+}
+
+impl<T: Hash> Extend<T> for RapidHyperLogLog {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(&item);
+        }
+    }
+}
+
+/// The natural logarithm, using [f64::ln] when `std` is available.
+#[cfg(feature = "std")]
+#[inline]
+fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+/// The natural logarithm of a positive, finite `x`, for `no_std` builds where [f64::ln] is not
+/// available.
+///
+/// Splits `x` into `2^e * m` with `m` in `[1, 2)`, and computes `ln(m)` with the `2 * atanh(z)`
+/// series, which converges quickly as `z = (m - 1) / (m + 1)` is at most `1/3`.
+#[cfg(not(feature = "std"))]
+fn ln(x: f64) -> f64 {
+    debug_assert!(x > 0.0 && x.is_finite());
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = 0.0;
+    let mut k = 1.0;
+    while k < 40.0 {
+        sum += term / k;
+        term *= z2;
+        k += 2.0;
+    }
+
+    exponent as f64 * core::f64::consts::LN_2 + 2.0 * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relative_error(estimate: u64, actual: u64) -> f64 {
+        (estimate as f64 - actual as f64).abs() / actual as f64
+    }
+
+    #[test]
+    fn estimates_within_error() {
+        for actual in [10u64, 1_000, 100_000, 1_000_000] {
+            let mut hll = RapidHyperLogLog::default();
+            hll.extend(0..actual);
+
+            // allow ~4 standard errors at p = 12
+            let error = relative_error(hll.count(), actual);
+            assert!(error < 0.065, "estimate {} for {actual}, error {error}", hll.count());
+        }
+    }
+
+    #[test]
+    fn duplicates_are_ignored() {
+        let mut hll = RapidHyperLogLog::new(10);
+        for _ in 0..10 {
+            for i in 0..500u32 {
+                hll.insert_bytes(&i.to_le_bytes());
+            }
+        }
+        assert!(relative_error(hll.count(), 500) < 0.1);
+    }
+
+    #[test]
+    fn merge_estimates_union() {
+        let mut a = RapidHyperLogLog::new_seeded(14, 7);
+        let mut b = RapidHyperLogLog::new_seeded(14, 7);
+        a.extend(0..60_000u64);
+        b.extend(40_000..100_000u64);
+
+        let mut expected = RapidHyperLogLog::new_seeded(14, 7);
+        expected.extend(0..100_000u64);
+
+        a.merge(&b);
+        assert_eq!(a, expected);
+        assert!(relative_error(a.count(), 100_000) < 0.04);
+    }
+
+    #[test]
+    fn empty_and_clear() {
+        let mut hll = RapidHyperLogLog::new(4);
+        assert!(hll.is_empty());
+        assert_eq!(hll.count(), 0);
+
+        hll.insert("hello");
+        assert!(!hll.is_empty());
+
+        hll.clear();
+        assert!(hll.is_empty());
+    }
+
+    #[test]
+    fn registers_round_trip() {
+        let mut hll = RapidHyperLogLog::new_seeded(8, 7);
+        hll.extend(0..1000u32);
+        let restored = RapidHyperLogLog::from_registers(8, 7, hll.registers().to_vec()).unwrap();
+        assert_eq!(restored, hll);
+        assert_eq!(restored.count(), hll.count());
+
+        // the largest possible rank is accepted, anything above it or a wrong length isn't
+        hll.insert_hash(0);
+        assert_eq!(hll.registers()[0], 57);
+        assert!(RapidHyperLogLog::from_registers(8, 7, hll.registers().to_vec()).is_some());
+        let mut registers = hll.registers().to_vec();
+        registers[0] = 58;
+        assert_eq!(RapidHyperLogLog::from_registers(8, 7, registers), None);
+        assert_eq!(RapidHyperLogLog::from_registers(8, 7, alloc::vec![0; 255]), None);
+        assert_eq!(RapidHyperLogLog::from_registers(3, 7, alloc::vec![0; 8]), None);
+        assert_eq!(RapidHyperLogLog::from_registers(19, 7, alloc::vec![0; 1 << 19]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn merge_mismatched_precision_panics() {
+        let mut a = RapidHyperLogLog::new(10);
+        a.merge(&RapidHyperLogLog::new(11));
+    }
+
+    #[test]
+    fn ln_matches_reference() {
+        for (x, expected) in [(1.0, 0.0), (2.0, core::f64::consts::LN_2), (4096.0 / 3.0, 7.219153878051234), (0.5, -core::f64::consts::LN_2)] {
+            assert!((ln(x) - expected).abs() < 1e-12, "ln({x}) = {}, expected {expected}", ln(x));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_precision_panics() {
+        let _ = RapidHyperLogLog::new(3);
+    }
+}
@@ -0,0 +1,159 @@
+use core::convert::Infallible;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::rapid_const::rapidhash;
+
+/// A 64-bit identifier derived from a string with [crate::rapidhash], for replacing string
+/// comparisons with integer comparisons on asset paths, event names, and similar keys.
+///
+/// Build IDs at compile time with the [rapid_id!](crate::rapid_id) macro or [RapidId::new], and at
+/// runtime with [RapidId::new] or [str::parse]. The same string always produces the same ID, across
+/// platforms and versions of this crate, so IDs may be stored in asset files or sent over the
+/// network.
+///
+/// With the `std` feature and debug assertions enabled, every ID created through the macro or
+/// `parse` records its source string, and panics if two different strings hash to the same ID.
+/// [RapidId::new] skips this check so that it remains usable in `const` items.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapid_id, RapidId};
+///
+/// const HERO: RapidId = RapidId::new("textures/hero.png");
+///
+/// let id: RapidId = "textures/hero.png".parse().unwrap();
+/// assert_eq!(id, rapid_id!("textures/hero.png"));
+/// assert_eq!(id, HERO);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(transparent)]
+pub struct RapidId(pub u64);
+
+impl RapidId {
+    /// Hash a string into a `RapidId`, usable in `const` contexts.
+    #[inline]
+    #[must_use]
+    pub const fn new(name: &str) -> Self {
+        Self(rapidhash(name.as_bytes()))
+    }
+
+    /// Returns the underlying 64-bit hash.
+    #[inline]
+    #[must_use]
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Record the string an ID was created from, panicking if a different string was already
+    /// recorded for the same ID.
+    ///
+    /// Used by the [rapid_id!](crate::rapid_id) macro in debug builds. Does nothing without the
+    /// `std` feature.
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn debug_register(self, name: &str) -> Self {
+        #[cfg(feature = "std")]
+        {
+            use std::collections::BTreeMap;
+            use std::string::ToString;
+            use std::sync::Mutex;
+
+            static NAMES: Mutex<BTreeMap<u64, std::string::String>> = Mutex::new(BTreeMap::new());
+
+            let mut names = NAMES.lock().unwrap_or_else(|e| e.into_inner());
+            match names.get(&self.0) {
+                Some(existing) if existing != name => {
+                    let existing = existing.clone();
+                    drop(names);
+                    panic!("RapidId collision: {existing:?} and {name:?} both hash to {self}");
+                }
+                Some(_) => {}
+                None => {
+                    names.insert(self.0, name.to_string());
+                }
+            }
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = name;
+        self
+    }
+}
+
+impl FromStr for RapidId {
+    type Err = Infallible;
+
+    #[inline]
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let id = Self::new(name);
+        #[cfg(debug_assertions)]
+        id.debug_register(name);
+        Ok(id)
+    }
+}
+
+impl From<RapidId> for u64 {
+    #[inline]
+    fn from(id: RapidId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for RapidId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// Hash a string literal into a [RapidId] at compile time.
+///
+/// In debug builds of the calling crate, and with the `std` feature, the source string is also
+/// recorded so that colliding IDs panic. See [RapidId] for details.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapid_id, RapidId};
+///
+/// fn load(id: RapidId) -> bool {
+///     id == rapid_id!("textures/hero.png")
+/// }
+///
+/// assert!(load(rapid_id!("textures/hero.png")));
+/// assert!(!load(rapid_id!("textures/villain.png")));
+/// ```
+#[macro_export]
+macro_rules! rapid_id {
+    ($name:expr) => {{
+        const ID: $crate::RapidId = $crate::RapidId::new($name);
+        #[cfg(debug_assertions)]
+        ID.debug_register($name);
+        ID
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_matches_rapidhash() {
+        const ID: RapidId = RapidId::new("textures/hero.png");
+        assert_eq!(ID.as_u64(), crate::rapidhash(b"textures/hero.png"));
+        assert_eq!(rapid_id!("textures/hero.png"), ID);
+        assert_eq!("textures/hero.png".parse::<RapidId>(), Ok(ID));
+        assert_ne!(rapid_id!("textures/villain.png"), ID);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "RapidId collision")]
+    fn debug_register_detects_collisions() {
+        let id = RapidId::new("a");
+        id.debug_register("a");
+        id.debug_register("a");
+        id.debug_register("b");
+    }
+}
@@ -0,0 +1,503 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use crate::rapid_const::RAPID_SEED;
+use crate::RapidStreamHasher;
+
+/// The size of the fixed read buffer used when streaming files.
+const READ_BUFFER: usize = 64 * 1024;
+
+/// With the `mmap` feature, files at least this large are memory-mapped instead of streamed.
+#[cfg(feature = "mmap")]
+const MMAP_THRESHOLD: u64 = 1024 * 1024;
+
+/// Hash the contents of a file with the default seed, in constant memory.
+///
+/// The result is equal to [crate::rapidhash] of the full file contents, and to hashing the file with
+/// the [RapidStreamHasher].
+///
+/// With the `mmap` feature enabled, large files are memory-mapped and hashed in one pass. Note that
+/// memory-mapping is only sound if the file is not modified while it is being hashed.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+/// ```no_run
+/// use rapidhash::rapidhash_file;
+///
+/// let hash = rapidhash_file("example.txt").unwrap();
+/// println!("{hash}");
+/// ```
+#[inline]
+pub fn rapidhash_file(path: impl AsRef<Path>) -> std::io::Result<u64> {
+    rapidhash_file_seeded(path, RAPID_SEED)
+}
+
+/// Hash the contents of a file with a custom seed, in constant memory.
+///
+/// The result is equal to [crate::rapidhash_seeded] of the full file contents. See
+/// [rapidhash_file] for more details.
+pub fn rapidhash_file_seeded(path: impl AsRef<Path>, seed: u64) -> std::io::Result<u64> {
+    let file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+
+    #[cfg(feature = "mmap")]
+    if len >= MMAP_THRESHOLD {
+        // SAFETY: the mapping is read-only, and the caller is warned in the docs that the file must
+        // not be modified while hashing.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        if map.len() as u64 == len {
+            return Ok(crate::rapidhash_seeded(&map, seed));
+        }
+    }
+
+    rapidhash_stream_seeded(file, len, seed)
+}
+
+/// Hash `len` bytes of a file starting at byte `offset` with the default seed, in constant memory.
+///
+/// The result is equal to [crate::rapidhash] of `contents[offset..offset + len]`, so storage tools
+/// can verify a single extent of a very large file, or resume verification part way through,
+/// without reading the rest of the file. The file is seeked to `offset` and the range is streamed.
+///
+/// Returns an [std::io::ErrorKind::UnexpectedEof] error if the range extends past the end of the
+/// file.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+/// ```no_run
+/// use rapidhash::rapidhash_file_range;
+///
+/// // the second 4 MiB extent
+/// let extent = rapidhash_file_range("disk.img", 4 << 20, 4 << 20).unwrap();
+/// ```
+#[inline]
+pub fn rapidhash_file_range(path: impl AsRef<Path>, offset: u64, len: u64) -> std::io::Result<u64> {
+    rapidhash_file_range_seeded(path, offset, len, RAPID_SEED)
+}
+
+/// Hash `len` bytes of a file starting at byte `offset` with a custom seed, in constant memory.
+///
+/// The result is equal to [crate::rapidhash_seeded] of `contents[offset..offset + len]`. See
+/// [rapidhash_file_range] for more details.
+pub fn rapidhash_file_range_seeded(path: impl AsRef<Path>, offset: u64, len: u64, seed: u64) -> std::io::Result<u64> {
+    let mut file = std::fs::File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    rapidhash_stream_seeded(file, len, seed)
+}
+
+/// Hash exactly `len` bytes from a reader with the default seed, using a fixed-size buffer and the
+/// [RapidStreamHasher].
+///
+/// The result is equal to [crate::rapidhash] of the `len` bytes read, regardless of how the reader
+/// splits its output. As rapidhash mixes the total input length into the seed before reading any
+/// data, the length must be known up front. Exactly `len` bytes are consumed from the reader, and an
+/// [std::io::ErrorKind::UnexpectedEof] error is returned if it ends early.
+///
+/// There is deliberately no variant without `len`: learning the length of an arbitrary reader means
+/// reading all of it first, so it couldn't stream in constant memory. To hash a reader of unknown
+/// length, such as a pipe, [Read::read_to_end] it and use [crate::rapidhash], or spool it to a file
+/// and use [crate::rapidhash_file].
+///
+/// Requires the `std` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash, rapidhash_stream};
+///
+/// let data = b"hello world";
+/// let hash = rapidhash_stream(&data[..], data.len() as u64).unwrap();
+/// assert_eq!(hash, rapidhash(data));
+/// ```
+#[inline]
+pub fn rapidhash_stream(reader: impl Read, len: u64) -> std::io::Result<u64> {
+    rapidhash_stream_seeded(reader, len, RAPID_SEED)
+}
+
+/// Hash exactly `len` bytes from a reader with a custom seed, using a fixed-size buffer and the
+/// [RapidStreamHasher].
+///
+/// The result is equal to [crate::rapidhash_seeded] of the `len` bytes read. See
+/// [rapidhash_stream] for more details.
+pub fn rapidhash_stream_seeded(mut reader: impl Read, len: u64, seed: u64) -> std::io::Result<u64> {
+    let mut hasher = RapidStreamHasher::new_seeded(len, seed);
+    let mut buffer = vec![0u8; usize::try_from(len).map_or(READ_BUFFER, |len| len.min(READ_BUFFER))];
+
+    while !hasher.is_complete() {
+        let max = (len - hasher.written()).min(buffer.len() as u64) as usize;
+        match reader.read(&mut buffer[..max]) {
+            Ok(0) => return Err(std::io::ErrorKind::UnexpectedEof.into()),
+            Ok(read) => RapidStreamHasher::write(&mut hasher, &buffer[..read]),
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(hasher.finish())
+}
+
+/// A [Read] adapter that hashes all bytes read through it using the [RapidStreamHasher].
+///
+/// The resulting hash is equal to [crate::rapidhash_seeded] of the full input, regardless of how the
+/// reads are sized, so code that already streams a file through a parser gets the checksum without
+/// a second pass.
+///
+/// As rapidhash mixes the total input length into the seed, the reader must be constructed with the
+/// exact length of the input. The adapter behaves like [Read::take], and will report end-of-file
+/// after `len` bytes. [RapidHashingReader::hash] returns `None` until all `len` bytes have been read.
+///
+/// # Example
+/// ```
+/// use std::io::Read;
+/// use rapidhash::{rapidhash, RapidHashingReader};
+///
+/// let data = b"hello world";
+/// let mut reader = RapidHashingReader::new(&data[..], data.len() as u64);
+///
+/// let mut contents = String::new();
+/// reader.read_to_string(&mut contents).unwrap();
+///
+/// assert_eq!(reader.hash(), Some(rapidhash(data)));
+/// ```
+pub struct RapidHashingReader<R> {
+    inner: R,
+    hasher: RapidStreamHasher,
+}
+
+impl<R: Read> RapidHashingReader<R> {
+    /// Wrap a reader of exactly `len` bytes, hashing with the default seed.
+    #[inline]
+    pub fn new(inner: R, len: u64) -> Self {
+        Self::new_seeded(inner, len, RAPID_SEED)
+    }
+
+    /// Wrap a reader of exactly `len` bytes, hashing with a custom seed.
+    #[inline]
+    pub fn new_seeded(inner: R, len: u64, seed: u64) -> Self {
+        Self {
+            inner,
+            hasher: RapidStreamHasher::new_seeded(len, seed),
+        }
+    }
+
+    /// The hash of the input, or `None` if fewer than `len` bytes have been read so far.
+    #[inline]
+    pub fn hash(&self) -> Option<u64> {
+        match self.hasher.is_complete() {
+            true => Some(self.hasher.finish()),
+            false => None,
+        }
+    }
+
+    /// The number of bytes read and hashed so far.
+    #[inline]
+    pub fn bytes_read(&self) -> u64 {
+        self.hasher.written()
+    }
+
+    /// Get a reference to the underlying reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the underlying reader.
+    ///
+    /// Reading directly from the underlying reader will skip those bytes from the hash.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwrap this adapter, returning the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for RapidHashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let remaining = self.hasher.len() - self.hasher.written();
+        let max = (buf.len() as u64).min(remaining) as usize;
+        let read = self.inner.read(&mut buf[..max])?;
+        self.hasher.write(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// A [Write] adapter that hashes all bytes written through it using the [RapidStreamHasher].
+///
+/// The resulting hash is equal to [crate::rapidhash_seeded] of the full output, regardless of how
+/// the writes are sized, for producing a checksum of serialized output without buffering the whole
+/// payload.
+///
+/// As rapidhash mixes the total input length into the seed, the writer must be constructed with the
+/// exact length of the output. Writing more than `len` bytes returns an
+/// [std::io::ErrorKind::InvalidInput] error. [RapidHashingWriter::hash] returns `None` until all
+/// `len` bytes have been written.
+///
+/// # Example
+/// ```
+/// use std::io::Write;
+/// use rapidhash::{rapidhash, RapidHashingWriter};
+///
+/// let payload = b"hello world";
+/// let mut writer = RapidHashingWriter::new(Vec::new(), payload.len() as u64);
+/// writer.write_all(payload).unwrap();
+///
+/// assert_eq!(writer.hash(), Some(rapidhash(payload)));
+/// assert_eq!(writer.into_inner(), payload);
+/// ```
+pub struct RapidHashingWriter<W> {
+    inner: W,
+    hasher: RapidStreamHasher,
+}
+
+impl<W: Write> RapidHashingWriter<W> {
+    /// Wrap a writer that will receive exactly `len` bytes, hashing with the default seed.
+    #[inline]
+    pub fn new(inner: W, len: u64) -> Self {
+        Self::new_seeded(inner, len, RAPID_SEED)
+    }
+
+    /// Wrap a writer that will receive exactly `len` bytes, hashing with a custom seed.
+    #[inline]
+    pub fn new_seeded(inner: W, len: u64, seed: u64) -> Self {
+        Self {
+            inner,
+            hasher: RapidStreamHasher::new_seeded(len, seed),
+        }
+    }
+
+    /// The hash of the output, or `None` if fewer than `len` bytes have been written so far.
+    #[inline]
+    pub fn hash(&self) -> Option<u64> {
+        match self.hasher.is_complete() {
+            true => Some(self.hasher.finish()),
+            false => None,
+        }
+    }
+
+    /// The number of bytes written and hashed so far.
+    #[inline]
+    pub fn bytes_written(&self) -> u64 {
+        self.hasher.written()
+    }
+
+    /// Get a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the underlying writer.
+    ///
+    /// Writing directly to the underlying writer will skip those bytes from the hash.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap this adapter, returning the underlying writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for RapidHashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let remaining = self.hasher.len() - self.hasher.written();
+        if buf.len() as u64 > remaining {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "RapidHashingWriter: wrote more bytes than the declared length",
+            ));
+        }
+
+        let written = self.inner.write(buf)?;
+        self.hasher.write(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Write bytes into the [RapidStreamHasher], so it can be the target of [std::io::copy],
+/// `serde_json::to_writer`, and similar APIs.
+///
+/// Writing more than the declared length returns an [std::io::ErrorKind::InvalidInput] error
+/// instead of panicking.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash, RapidStreamHasher};
+///
+/// let data = b"hello world";
+/// let mut hasher = RapidStreamHasher::new(data.len() as u64);
+/// std::io::copy(&mut &data[..], &mut hasher).unwrap();
+///
+/// assert_eq!(hasher.finish(), rapidhash(data));
+/// ```
+impl Write for RapidStreamHasher {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.len() as u64 > self.len() - self.written() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "RapidStreamHasher: wrote more bytes than the declared length",
+            ));
+        }
+
+        RapidStreamHasher::write(self, buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reader that returns at most `chunk` bytes per call, to exercise odd read sizes.
+    struct ChunkedReader<'a> {
+        data: &'a [u8],
+        chunk: usize,
+    }
+
+    impl Read for ChunkedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let len = self.chunk.min(buf.len()).min(self.data.len());
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn reader_matches_oneshot() {
+        let data: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+
+        for chunk in [1, 7, 96, 1000] {
+            let inner = ChunkedReader { data: &data, chunk };
+            let mut reader = RapidHashingReader::new_seeded(inner, data.len() as u64, 7);
+
+            assert_eq!(reader.hash(), None);
+            let mut out = Vec::new();
+            reader.read_to_end(&mut out).unwrap();
+
+            assert_eq!(out, data);
+            assert_eq!(reader.bytes_read(), 1000);
+            assert_eq!(reader.hash(), Some(crate::rapidhash_seeded(&data, 7)));
+        }
+    }
+
+    #[test]
+    fn reader_stops_at_declared_length() {
+        let mut reader = RapidHashingReader::new(&b"hello world"[..], 5);
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, b"hello");
+        assert_eq!(reader.hash(), Some(crate::rapidhash(b"hello")));
+    }
+
+    #[test]
+    fn writer_matches_oneshot() {
+        let data: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+
+        for chunk in [1, 7, 96, 1000] {
+            let mut writer = RapidHashingWriter::new_seeded(Vec::new(), data.len() as u64, 7);
+            for part in data.chunks(chunk) {
+                assert_eq!(writer.hash(), None);
+                writer.write_all(part).unwrap();
+            }
+
+            assert_eq!(writer.bytes_written(), 1000);
+            assert_eq!(writer.hash(), Some(crate::rapidhash_seeded(&data, 7)));
+            assert_eq!(writer.into_inner(), data);
+        }
+    }
+
+    #[test]
+    fn writer_rejects_excess_bytes() {
+        let mut writer = RapidHashingWriter::new(Vec::new(), 5);
+        let err = writer.write_all(b"hello world").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(writer.bytes_written(), 0);
+    }
+
+    #[test]
+    fn stream_hasher_io_write() {
+        let data: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+
+        let mut hasher = RapidStreamHasher::new(data.len() as u64);
+        let copied = std::io::copy(&mut ChunkedReader { data: &data, chunk: 33 }, &mut hasher).unwrap();
+        assert_eq!(copied, 1000);
+        assert_eq!(hasher.finish(), crate::rapidhash(&data));
+
+        let mut hasher = RapidStreamHasher::new(4);
+        let err = Write::write_all(&mut hasher, b"hello").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn file_matches_oneshot() {
+        let data: Vec<u8> = (0..1_500_000u32).map(|i| (i.wrapping_mul(2654435761) >> 7) as u8).collect();
+        let path = std::env::temp_dir().join(format!("rapidhash-test-file-{}", std::process::id()));
+
+        for len in [0, 5, 100, 65_536, 200_000, 1_500_000] {
+            std::fs::write(&path, &data[..len]).unwrap();
+            assert_eq!(rapidhash_file(&path).unwrap(), crate::rapidhash(&data[..len]), "Mismatch for len {len}");
+            assert_eq!(rapidhash_file_seeded(&path, 3).unwrap(), crate::rapidhash_seeded(&data[..len], 3));
+        }
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(rapidhash_file(&path).is_err());
+    }
+
+    #[test]
+    fn file_range_matches_oneshot() {
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i.wrapping_mul(2654435761) >> 7) as u8).collect();
+        let path = std::env::temp_dir().join(format!("rapidhash-test-range-{}", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+
+        for (offset, len) in [(0, 0), (0, 200_000), (1, 5), (100, 96), (70_000, 130_000), (200_000, 0)] {
+            let range = &data[offset..offset + len];
+            let (offset, len) = (offset as u64, len as u64);
+            assert_eq!(rapidhash_file_range(&path, offset, len).unwrap(), crate::rapidhash(range), "range {offset}+{len}");
+            assert_eq!(rapidhash_file_range_seeded(&path, offset, len, 3).unwrap(), crate::rapidhash_seeded(range, 3));
+        }
+
+        let err = rapidhash_file_range(&path, 199_999, 2).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn stream_matches_oneshot() {
+        let data: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+
+        for chunk in [1, 7, 96, 1000] {
+            let reader = ChunkedReader { data: &data, chunk };
+            assert_eq!(rapidhash_stream_seeded(reader, 1000, 9).unwrap(), crate::rapidhash_seeded(&data, 9));
+        }
+
+        // only the declared length is consumed
+        let mut reader = &data[..];
+        assert_eq!(rapidhash_stream(&mut reader, 10).unwrap(), crate::rapidhash(&data[..10]));
+        assert_eq!(reader.len(), 990);
+    }
+
+    #[test]
+    fn stream_detects_short_reads() {
+        let err = rapidhash_stream(&b"hello"[..], 10).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
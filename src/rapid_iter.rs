@@ -0,0 +1,212 @@
+use core::iter::FusedIterator;
+use crate::rapid_const::rapidhash_inline;
+use crate::partition_for;
+
+/// Extends every iterator of byte-like items with [RapidHashIter::rapidhashes], which pairs each
+/// item with its rapidhash.
+///
+/// The hashes are computed lazily, as the adapter is iterated, so a bulk-hashing pipeline for
+/// deduplication, partitioning, or indexing is a single chain of iterator adapters.
+///
+/// # Example
+/// ```
+/// use std::collections::HashSet;
+/// use rapidhash::{RapidHashIter, RAPID_SEED};
+///
+/// let lines = ["apple", "banana", "apple", "cherry"];
+///
+/// let mut seen = HashSet::new();
+/// let unique: Vec<&str> = lines.iter()
+///     .rapidhashes(RAPID_SEED)
+///     .filter(|&(_, hash)| seen.insert(hash))
+///     .map(|(line, _)| *line)
+///     .collect();
+///
+/// assert_eq!(unique, ["apple", "banana", "cherry"]);
+/// ```
+pub trait RapidHashIter: Iterator + Sized
+where
+    Self::Item: AsRef<[u8]>,
+{
+    /// Pair each item with its [crate::rapidhash_seeded], yielding `(item, hash)`.
+    #[inline]
+    fn rapidhashes(self, seed: u64) -> RapidHashes<Self> {
+        RapidHashes { iter: self, seed }
+    }
+
+    /// Route each item to one of `n` partitions with [crate::partition_for], yielding
+    /// `(partition, item)`.
+    ///
+    /// This is the streaming form of [partition_by_hash], for stages that send each item on to a
+    /// worker as it arrives rather than collecting every partition first.
+    ///
+    /// # Panics
+    /// Panics if `n` is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use rapidhash::{partition_for, RapidHashIter};
+    ///
+    /// for (partition, key) in ["a", "b", "c"].iter().partitioned(4, 0) {
+    ///     assert_eq!(partition, partition_for(key.as_bytes(), 4, 0));
+    /// }
+    /// ```
+    #[inline]
+    fn partitioned(self, n: u32, seed: u64) -> RapidPartitioned<Self> {
+        assert!(n != 0, "RapidHashIter::partitioned: n must be non-zero");
+        RapidPartitioned { iter: self, n, seed }
+    }
+}
+
+impl<I: Iterator> RapidHashIter for I where I::Item: AsRef<[u8]> {}
+
+/// An iterator adapter yielding each item with its rapidhash, created by
+/// [RapidHashIter::rapidhashes].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RapidHashes<I> {
+    iter: I,
+    seed: u64,
+}
+
+impl<I: Iterator> Iterator for RapidHashes<I>
+where
+    I::Item: AsRef<[u8]>,
+{
+    type Item = (I::Item, u64);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let hash = rapidhash_inline(item.as_ref(), self.seed);
+        Some((item, hash))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for RapidHashes<I>
+where
+    I::Item: AsRef<[u8]>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        let hash = rapidhash_inline(item.as_ref(), self.seed);
+        Some((item, hash))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for RapidHashes<I> where I::Item: AsRef<[u8]> {}
+
+impl<I: FusedIterator> FusedIterator for RapidHashes<I> where I::Item: AsRef<[u8]> {}
+
+/// An iterator adapter yielding each item with its partition, created by
+/// [RapidHashIter::partitioned].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RapidPartitioned<I> {
+    iter: I,
+    n: u32,
+    seed: u64,
+}
+
+impl<I: Iterator> Iterator for RapidPartitioned<I>
+where
+    I::Item: AsRef<[u8]>,
+{
+    type Item = (u32, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        Some((partition_for(item.as_ref(), self.n, self.seed), item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for RapidPartitioned<I> where I::Item: AsRef<[u8]> {}
+
+impl<I: FusedIterator> FusedIterator for RapidPartitioned<I> where I::Item: AsRef<[u8]> {}
+
+/// Route items into `n` buckets by their [crate::partition_for], keeping the order of the items
+/// within each bucket.
+///
+/// Every item with the same bytes and seed lands in the same bucket, in every process and every
+/// version of this crate, which gives parallel processing stages stable key affinity: bucket `i`
+/// can always be sent to worker `i`. The buckets are exactly uniform over `0..n`.
+///
+/// See [RapidHashIter::partitioned] to route items one at a time without collecting them.
+///
+/// Requires the `alloc` feature.
+///
+/// # Panics
+/// Panics if `n` is `0`.
+///
+/// # Example
+/// ```
+/// use rapidhash::partition_by_hash;
+///
+/// let events = ["user-1", "user-2", "user-1", "user-3"];
+/// let buckets = partition_by_hash(events, 2, 0);
+///
+/// assert_eq!(buckets.len(), 2);
+/// // both events for user-1 go to the same worker
+/// assert!(buckets.iter().any(|bucket| bucket.iter().filter(|e| **e == "user-1").count() == 2));
+/// ```
+#[cfg(any(feature = "alloc", docsrs))]
+pub fn partition_by_hash<T: AsRef<[u8]>>(items: impl IntoIterator<Item = T>, n: u32, seed: u64) -> alloc::vec::Vec<alloc::vec::Vec<T>> {
+    let mut buckets: alloc::vec::Vec<alloc::vec::Vec<T>> = (0..n).map(|_| alloc::vec::Vec::new()).collect();
+    for (partition, item) in items.into_iter().partitioned(n, seed) {
+        buckets[partition as usize].push(item);
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rapidhash_seeded;
+
+    #[test]
+    fn pairs_items_with_hashes() {
+        let keys: [&[u8]; 3] = [b"", b"a", b"hello world"];
+        let mut hashes = keys.iter().rapidhashes(7);
+        assert_eq!(hashes.len(), 3);
+        assert_eq!(hashes.next(), Some((&keys[0], rapidhash_seeded(b"", 7))));
+        assert_eq!(hashes.next_back(), Some((&keys[2], rapidhash_seeded(b"hello world", 7))));
+        assert_eq!(hashes.next(), Some((&keys[1], rapidhash_seeded(b"a", 7))));
+        assert_eq!(hashes.next(), None);
+
+        // owned items are yielded back unchanged
+        let mut owned = [[1u8; 4], [2u8; 4]].into_iter().rapidhashes(0);
+        assert_eq!(owned.next(), Some(([1u8; 4], rapidhash_seeded(&[1u8; 4], 0))));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn partitions_are_stable() {
+        let keys: alloc::vec::Vec<[u8; 8]> = (0..1000u64).map(u64::to_be_bytes).collect();
+        let buckets = partition_by_hash(keys.iter(), 7, 3);
+        assert_eq!(buckets.len(), 7);
+        assert_eq!(buckets.iter().map(|bucket| bucket.len()).sum::<usize>(), 1000);
+
+        for (partition, bucket) in buckets.iter().enumerate() {
+            assert!(bucket.len() > 100, "bucket {partition} has {} keys", bucket.len());
+            assert!(bucket.windows(2).all(|pair| pair[0] < pair[1]), "order is kept");
+            for key in bucket {
+                assert_eq!(partition_for(*key, 7, 3), partition as u32);
+            }
+        }
+
+        let streamed: alloc::vec::Vec<u32> = keys.iter().partitioned(7, 3).map(|(partition, _)| partition).collect();
+        assert_eq!(streamed[..3], [0, 1, 2].map(|i| partition_for(&keys[i], 7, 3)));
+    }
+}
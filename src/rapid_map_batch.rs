@@ -0,0 +1,190 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::vec::Vec;
+
+/// Look up many keys in a [HashMap] or [hashbrown::HashMap] at once, such as a
+/// [crate::RapidHashMap] or [crate::RapidHashbrownMap].
+///
+/// For a [hashbrown::HashMap], the keys are resolved in windows of [BATCH_WINDOW]: every key in
+/// the window is hashed first, and then each is found by its precomputed hash with hashbrown's raw
+/// entry API. No prefetch instructions are issued, as hashbrown doesn't expose where a hash's
+/// buckets are. Whether this beats calling `get` for each key depends on the map size and the CPU,
+/// so measure it with the `batch` benchmark:
+/// ```shell
+/// cargo bench --bench bench --features hashbrown -- batch/map_get
+/// ```
+///
+/// std's [HashMap] has no way to look a key up by a precomputed hash, so its implementation is
+/// only a convenience that calls [HashMap::get] for each key in turn.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::{RapidBatchGet, RapidHashMap};
+///
+/// let mut map = RapidHashMap::default();
+/// map.insert("a", 1);
+/// map.insert("b", 2);
+///
+/// assert_eq!(map.get_batch(["a", "c", "b"]), [Some(&1), None, Some(&2)]);
+///
+/// let mut out = [None; 2];
+/// map.get_batch_into(["b", "a"], &mut out);
+/// assert_eq!(out, [Some(&2), Some(&1)]);
+/// ```
+pub trait RapidBatchGet<K, V> {
+    /// Look up each key, returning the values in the same order as the keys.
+    fn get_batch<'a, 'q, Q, I>(&'a self, keys: I) -> Vec<Option<&'a V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>;
+
+    /// Look up each key, writing the values to the matching position in `out`, without
+    /// allocating.
+    ///
+    /// # Panics
+    /// Panics if `keys` doesn't yield exactly `out.len()` keys.
+    fn get_batch_into<'a, 'q, Q, I>(&'a self, keys: I, out: &mut [Option<&'a V>])
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>;
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> RapidBatchGet<K, V> for HashMap<K, V, S> {
+    fn get_batch<'a, 'q, Q, I>(&'a self, keys: I) -> Vec<Option<&'a V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        keys.into_iter().map(|key| self.get(key)).collect()
+    }
+
+    fn get_batch_into<'a, 'q, Q, I>(&'a self, keys: I, out: &mut [Option<&'a V>])
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        let mut slots = out.iter_mut();
+        for key in keys {
+            let slot = slots.next().expect("get_batch_into: more keys than outputs");
+            *slot = self.get(key);
+        }
+        assert!(slots.next().is_none(), "get_batch_into: fewer keys than outputs");
+    }
+}
+
+/// The number of keys [RapidBatchGet] hashes up front before resolving them in a
+/// [hashbrown::HashMap].
+#[cfg(any(feature = "hashbrown", docsrs))]
+pub const BATCH_WINDOW: usize = 16;
+
+#[cfg(any(feature = "hashbrown", docsrs))]
+impl<K: Hash + Eq, V, S: BuildHasher> RapidBatchGet<K, V> for hashbrown::HashMap<K, V, S> {
+    fn get_batch<'a, 'q, Q, I>(&'a self, keys: I) -> Vec<Option<&'a V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        let keys: Vec<&Q> = keys.into_iter().collect();
+        let mut out = std::vec![None; keys.len()];
+        self.get_batch_into(keys, &mut out);
+        out
+    }
+
+    fn get_batch_into<'a, 'q, Q, I>(&'a self, keys: I, out: &mut [Option<&'a V>])
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        let mut keys = keys.into_iter();
+        for out in out.chunks_mut(BATCH_WINDOW) {
+            // the unused tail of the last window repeats the first key, and is never looked up
+            let first = keys.next().expect("get_batch_into: fewer keys than outputs");
+            let mut window = [first; BATCH_WINDOW];
+            for key in &mut window[1..out.len()] {
+                *key = keys.next().expect("get_batch_into: fewer keys than outputs");
+            }
+            let window = &window[..out.len()];
+
+            let mut hashes = [0u64; BATCH_WINDOW];
+            for (hash, key) in hashes.iter_mut().zip(window) {
+                *hash = self.hasher().hash_one(key);
+            }
+
+            for ((key, hash), slot) in window.iter().zip(hashes).zip(out) {
+                *slot = self.raw_entry().from_hash(hash, |k| k.borrow() == *key).map(|(_, v)| v);
+            }
+        }
+        assert!(keys.next().is_none(), "get_batch_into: more keys than outputs");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RapidHashMap;
+
+    #[test]
+    fn batch_matches_get() {
+        let map: RapidHashMap<u64, u64> = (0..1000).map(|i| (i, i * 2)).collect();
+        let keys: Vec<u64> = (500..1500).collect();
+        let values = map.get_batch(&keys);
+        for (key, value) in keys.iter().zip(&values) {
+            assert_eq!(*value, map.get(key));
+        }
+
+        let mut out = std::vec![None; keys.len()];
+        map.get_batch_into(keys.iter(), &mut out);
+        assert_eq!(out, values);
+
+        let strings: RapidHashMap<std::string::String, u32> = [("a".into(), 1)].into_iter().collect();
+        assert_eq!(strings.get_batch(["a", "b"]), [Some(&1), None]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_lengths_panic() {
+        let map: RapidHashMap<u64, u64> = RapidHashMap::default();
+        let mut out = [None; 3];
+        map.get_batch_into(&[1u64, 2], &mut out);
+    }
+
+    #[cfg(feature = "hashbrown")]
+    #[test]
+    fn hashbrown_batch_matches_get() {
+        let map: crate::RapidHashbrownMap<u64, u64> = (0..1000).map(|i| (i, i * 2)).collect();
+        // lengths around the window size leave partial windows
+        for len in [0, 1, BATCH_WINDOW - 1, BATCH_WINDOW, BATCH_WINDOW + 1, 1000] {
+            let keys: Vec<u64> = (500..500 + len as u64).collect();
+            let values = map.get_batch(&keys);
+            assert_eq!(values.len(), len);
+            for (key, value) in keys.iter().zip(&values) {
+                assert_eq!(*value, map.get(key));
+            }
+
+            let mut out = std::vec![None; len];
+            map.get_batch_into(keys.iter(), &mut out);
+            assert_eq!(out, values);
+        }
+
+        let strings: crate::RapidHashbrownMap<std::string::String, u32> = [("a".into(), 1)].into_iter().collect();
+        assert_eq!(strings.get_batch(["a", "b"]), [Some(&1), None]);
+    }
+
+    #[cfg(feature = "hashbrown")]
+    #[test]
+    #[should_panic]
+    fn hashbrown_mismatched_lengths_panic() {
+        let map: crate::RapidHashbrownMap<u64, u64> = crate::RapidHashbrownMap::default();
+        let mut out = [None; 1];
+        map.get_batch_into(&[1u64, 2], &mut out);
+    }
+}
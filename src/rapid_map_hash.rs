@@ -0,0 +1,186 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash, Hasher};
+use crate::rapid_const::RAPID_SEED;
+use crate::RapidInlineHasher;
+
+/// Compute the hash a map or set gives a key, such as a [crate::RapidHashMap] or
+/// [crate::RapidHashSet].
+///
+/// The hash depends on the map's [BuildHasher] and so its seed, which may be random or set by
+/// [crate::set_global_seed]. This returns the exact hash the map uses, to reuse for routing a key
+/// to a shard, logging, or a secondary index without keeping a copy of the build hasher. The key
+/// doesn't need to be in the map.
+///
+/// Requires the `std` and `hasher` features.
+///
+/// # Example
+/// ```
+/// use rapidhash::{RapidHashKey, RapidHashMap};
+///
+/// let mut map = RapidHashMap::default();
+/// map.insert("user:42".to_string(), 7);
+///
+/// let hash = map.hash_key("user:42");
+/// let shard = hash % 16;
+/// ```
+pub trait RapidHashKey<K> {
+    /// The hash of `key` under this collection's [BuildHasher].
+    fn hash_key<Q: Hash + ?Sized>(&self, key: &Q) -> u64
+    where
+        K: Borrow<Q>;
+}
+
+impl<K, V, S: BuildHasher> RapidHashKey<K> for HashMap<K, V, S> {
+    #[inline]
+    fn hash_key<Q: Hash + ?Sized>(&self, key: &Q) -> u64
+    where
+        K: Borrow<Q>,
+    {
+        self.hasher().hash_one(key)
+    }
+}
+
+impl<K, S: BuildHasher> RapidHashKey<K> for HashSet<K, S> {
+    #[inline]
+    fn hash_key<Q: Hash + ?Sized>(&self, key: &Q) -> u64
+    where
+        K: Borrow<Q>,
+    {
+        self.hasher().hash_one(key)
+    }
+}
+
+#[cfg(any(feature = "hashbrown", docsrs))]
+impl<K, V, S: BuildHasher> RapidHashKey<K> for hashbrown::HashMap<K, V, S> {
+    #[inline]
+    fn hash_key<Q: Hash + ?Sized>(&self, key: &Q) -> u64
+    where
+        K: Borrow<Q>,
+    {
+        self.hasher().hash_one(key)
+    }
+}
+
+#[cfg(any(feature = "hashbrown", docsrs))]
+impl<K, S: BuildHasher> RapidHashKey<K> for hashbrown::HashSet<K, S> {
+    #[inline]
+    fn hash_key<Q: Hash + ?Sized>(&self, key: &Q) -> u64
+    where
+        K: Borrow<Q>,
+    {
+        self.hasher().hash_one(key)
+    }
+}
+
+/// An order-independent digest of every entry in a map, such as a [crate::RapidHashMap], with the
+/// default seed.
+///
+/// Two maps with the same entries have the same digest, whatever their insertion order, capacity,
+/// or [BuildHasher] seed, so two processes can check whether their maps have converged by
+/// exchanging a single `u64` instead of serializing and sorting the maps. Each entry is hashed
+/// with a fixed-seed [RapidInlineHasher], and the entry hashes are summed and hashed with the
+/// number of entries.
+///
+/// The keys and values must hash the same in both processes, which holds for integers, strings,
+/// and derived [Hash] impls of those, but not for pointers. This detects maps that have diverged,
+/// but the digest is not a cryptographic commitment, and entries could be crafted to collide.
+///
+/// Requires the `std` and `hasher` features.
+///
+/// # Example
+/// ```
+/// use rapidhash::{map_digest, RapidHashMap};
+///
+/// let mut a = RapidHashMap::default();
+/// a.insert("x", 1);
+/// a.insert("y", 2);
+///
+/// let mut b = RapidHashMap::default();
+/// b.insert("y", 2);
+/// b.insert("x", 1);
+/// assert_eq!(map_digest(&a), map_digest(&b));
+///
+/// b.insert("x", 3);
+/// assert_ne!(map_digest(&a), map_digest(&b));
+/// ```
+#[inline]
+pub fn map_digest<K: Hash, V: Hash, S>(map: &HashMap<K, V, S>) -> u64 {
+    map_digest_seeded(map, RAPID_SEED)
+}
+
+/// An order-independent digest of every entry in a map with a custom seed. See [map_digest].
+pub fn map_digest_seeded<K: Hash, V: Hash, S>(map: &HashMap<K, V, S>, seed: u64) -> u64 {
+    unordered_digest(map.iter(), map.len(), seed)
+}
+
+/// An order-independent digest of every key in a set, such as a [crate::RapidHashSet], with the
+/// default seed. See [map_digest].
+///
+/// Requires the `std` and `hasher` features.
+#[inline]
+pub fn set_digest<K: Hash, S>(set: &HashSet<K, S>) -> u64 {
+    set_digest_seeded(set, RAPID_SEED)
+}
+
+/// An order-independent digest of every key in a set with a custom seed. See [map_digest].
+pub fn set_digest_seeded<K: Hash, S>(set: &HashSet<K, S>, seed: u64) -> u64 {
+    unordered_digest(set.iter(), set.len(), seed)
+}
+
+/// Sum the hash of each item, then hash the sum with the number of items.
+#[inline]
+fn unordered_digest<T: Hash>(items: impl Iterator<Item = T>, len: usize, seed: u64) -> u64 {
+    let sum = items.fold(0u64, |sum, item| {
+        let mut hasher = RapidInlineHasher::new(seed);
+        item.hash(&mut hasher);
+        sum.wrapping_add(hasher.finish())
+    });
+
+    let mut hasher = RapidInlineHasher::new(seed);
+    hasher.write_u64(len as u64);
+    hasher.write_u64(sum);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RapidBuildHasher, RapidHashMap, RapidHashSet};
+
+    #[test]
+    fn matches_build_hasher() {
+        let map: RapidHashMap<std::string::String, u32> = HashMap::with_hasher(RapidBuildHasher::new(7));
+        let set: RapidHashSet<u64> = HashSet::with_hasher(RapidBuildHasher::new(7));
+        assert_eq!(map.hash_key("key"), RapidBuildHasher::new(7).hash_one("key"));
+        assert_eq!(set.hash_key(&42u64), RapidBuildHasher::new(7).hash_one(42u64));
+        assert_ne!(set.hash_key(&42u64), RapidHashSet::<u64>::with_hasher(RapidBuildHasher::new(8)).hash_key(&42u64));
+    }
+
+    #[test]
+    fn digest_ignores_order_and_seed() {
+        let a: RapidHashMap<u32, u32> = (0..100).map(|i| (i, i * 3)).collect();
+        let mut b: RapidHashMap<u32, u32> = HashMap::with_hasher(RapidBuildHasher::new(1));
+        for i in (0..100).rev() {
+            b.insert(i, i * 3);
+        }
+        assert_eq!(map_digest(&a), map_digest(&b));
+        assert_ne!(map_digest(&a), map_digest_seeded(&a, 7));
+
+        b.insert(5, 0);
+        assert_ne!(map_digest(&a), map_digest(&b));
+        b.insert(5, 15);
+        b.insert(100, 300);
+        assert_ne!(map_digest(&a), map_digest(&b));
+
+        // the same keys and values, paired differently
+        let swapped: RapidHashMap<u32, u32> = [(1, 2), (2, 1)].into_iter().collect();
+        let same: RapidHashMap<u32, u32> = [(1, 1), (2, 2)].into_iter().collect();
+        assert_ne!(map_digest(&swapped), map_digest(&same));
+
+        let x: RapidHashSet<&str> = ["a", "b", "c"].into_iter().collect();
+        let y: RapidHashSet<&str> = ["c", "a", "b"].into_iter().collect();
+        assert_eq!(set_digest(&x), set_digest(&y));
+        assert_ne!(set_digest(&x), set_digest(&RapidHashSet::<&str>::default()));
+    }
+}
@@ -0,0 +1,51 @@
+use crate::rapid_const::rapidhash_inline;
+
+/// Hash a slice of plain-old-data values with a custom seed, by reinterpreting the slice as bytes
+/// and hashing it in one pass.
+///
+/// This is equal to [crate::rapidhash_seeded] of the slice's raw bytes, and is much faster than
+/// hashing numeric buffers element-by-element through the [core::hash::Hasher] trait. Use
+/// [crate::RAPID_SEED] for the default seed.
+///
+/// The bytes are in native endianness, so the hash of a `&[u64]` or `&[f32]` will differ between
+/// little-endian and big-endian platforms. Floats are hashed by their bit pattern, so `0.0` and
+/// `-0.0` hash differently, and NaN payloads are preserved.
+///
+/// Requires the `bytemuck` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash_pod, rapidhash_seeded, RAPID_SEED};
+///
+/// let values: [u32; 3] = [1, 2, 3];
+/// let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+///
+/// assert_eq!(rapidhash_pod(&values, RAPID_SEED), rapidhash_seeded(&bytes, RAPID_SEED));
+/// ```
+#[inline]
+#[must_use]
+pub fn rapidhash_pod<T: bytemuck::Pod>(items: &[T], seed: u64) -> u64 {
+    rapidhash_inline(bytemuck::cast_slice(items), seed)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+    use super::*;
+    use crate::{rapidhash, RAPID_SEED};
+
+    #[test]
+    fn pod_matches_bytes() {
+        let floats: Vec<f32> = (0..100).map(|i| i as f32 * 0.5).collect();
+        let bytes: Vec<u8> = floats.iter().flat_map(|f| f.to_ne_bytes()).collect();
+        assert_eq!(rapidhash_pod(&floats, RAPID_SEED), rapidhash(&bytes));
+
+        let ints: Vec<u64> = (0..100).collect();
+        let bytes: Vec<u8> = ints.iter().flat_map(|i| i.to_ne_bytes()).collect();
+        assert_eq!(rapidhash_pod(&ints, 42), crate::rapidhash_seeded(&bytes, 42));
+
+        assert_eq!(rapidhash_pod::<u16>(&[], RAPID_SEED), rapidhash(&[]));
+    }
+}
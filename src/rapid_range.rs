@@ -0,0 +1,293 @@
+use crate::rapid_const::{rapid_mum, rapidhash_seeded};
+
+/// Map a 64-bit hash onto the range `0..n` using a multiply-shift reduction.
+///
+/// This computes `(hash * n) >> 64` with a 128-bit product, which is considerably faster than
+/// `hash % n` as it avoids a division, and depends on all bits of the hash rather than only the
+/// low bits. As with `hash % n`, each output is hit by either `floor(2^64 / n)` or `ceil(2^64 / n)`
+/// hashes, which is a negligible bias for any `n` much smaller than `2^64`. Use [crate::RapidRng]
+/// methods when exactly uniform random sampling is required.
+///
+/// Returns `0` when `n` is `0`.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash, rapid_range};
+///
+/// let bucket = rapid_range(rapidhash(b"hello world"), 10);
+/// assert!(bucket < 10);
+/// ```
+#[inline(always)]
+#[must_use]
+pub const fn rapid_range(hash: u64, n: u64) -> u64 {
+    rapid_mum(hash, n).1
+}
+
+/// Map a 64-bit hash onto the range `0..n` for a power-of-two `n`, by masking the low bits.
+///
+/// This is exactly uniform and slightly faster than [rapid_range], and is suitable for hash tables
+/// or shards with a power-of-two size.
+///
+/// # Panics
+/// Panics in debug builds if `n` is not a power of two.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash, rapid_range_pow2};
+///
+/// let shard = rapid_range_pow2(rapidhash(b"hello world"), 16);
+/// assert!(shard < 16);
+/// ```
+#[inline(always)]
+#[must_use]
+pub const fn rapid_range_pow2(hash: u64, n: u64) -> u64 {
+    debug_assert!(n.is_power_of_two(), "rapid_range_pow2: n must be a power of two");
+    hash & n.wrapping_sub(1)
+}
+
+/// Route a key to one of `num_partitions` partitions, with a stable definition that will never
+/// change.
+///
+/// Producers and consumers written in different languages, or built against different versions of
+/// this crate, can rely on every key being routed to the same partition forever. The definition is:
+///
+/// 1. `h = rapidhash_seeded(key, seed)`.
+/// 2. `m = h * num_partitions` as a 128-bit product, and `lo` its low 64 bits.
+/// 3. If `lo < (2^64 - num_partitions) % num_partitions`, set `h = rapidhash_seeded(h as
+///    little-endian bytes, seed)` and go back to step 2.
+/// 4. Return the high 64 bits of `m`.
+///
+/// This is Lemire's multiply-shift reduction with rejection, and so is exactly uniform over
+/// `0..num_partitions`, unlike [rapid_range] or `hash % n`. The rejection in step 3 happens with
+/// probability below `num_partitions / 2^64`, so in practice the key is hashed once.
+///
+/// # Panics
+/// Panics if `num_partitions` is `0`.
+///
+/// # Example
+/// ```
+/// use rapidhash::partition_for;
+///
+/// let partition = partition_for(b"user-1234", 12, 0);
+/// assert!(partition < 12);
+/// assert_eq!(partition, partition_for(b"user-1234", 12, 0));
+/// ```
+#[inline]
+#[must_use]
+pub const fn partition_for(key: &[u8], num_partitions: u32, seed: u64) -> u32 {
+    assert!(num_partitions != 0, "partition_for: num_partitions must be non-zero");
+
+    let n = num_partitions as u64;
+    let threshold = n.wrapping_neg() % n;
+    let mut hash = rapidhash_seeded(key, seed);
+    loop {
+        let (lo, hi) = rapid_mum(hash, n);
+        if lo >= threshold {
+            return hi as u32;
+        }
+        hash = rapidhash_seeded(&hash.to_le_bytes(), seed);
+    }
+}
+
+/// Assign a key to one of `n` experiment buckets, with a stable definition that will never change.
+///
+/// Suitable for A/B test assignment, where every service sharing the seed must put a user in the
+/// same bucket, now and after upgrading this crate. The definition is exactly that of
+/// [partition_for], so the assignment is uniform over `0..n`.
+///
+/// Use a different seed for each experiment, for example from [crate::SeedTree::child_label], so
+/// that the buckets of two experiments are independent.
+///
+/// # Panics
+/// Panics if `n` is `0`.
+///
+/// # Example
+/// ```
+/// use rapidhash::bucket;
+///
+/// let variant = bucket(b"user-1234", 3, 0xe1);
+/// assert!(variant < 3);
+/// ```
+#[inline]
+#[must_use]
+pub const fn bucket(key: &[u8], n: u32, seed: u64) -> u32 {
+    partition_for(key, n, seed)
+}
+
+/// Decide whether to sample a key at `rate`, with a stable definition that will never change.
+///
+/// Suitable for trace and log sampling, where every service sharing the seed must make the same
+/// decision for a trace ID, now and after upgrading this crate. The definition is:
+///
+/// - If `rate >= 1.0`, return `true`.
+/// - Otherwise, return `rapidhash_seeded(key, seed) < t`, where `t` is `rate * 2^64` rounded
+///   towards zero. `t` is `0` for a negative or NaN `rate`, which is never sampled.
+///
+/// Decisions are consistent across rates: a key sampled at some rate is also sampled at every
+/// higher rate. Keys are sampled with a probability of `rate`, to within `2^-64`.
+///
+/// # Example
+/// ```
+/// use rapidhash::sample;
+///
+/// let keep = sample(b"trace-8e1f", 0.01, 0);
+/// assert_eq!(keep, sample(b"trace-8e1f", 0.01, 0));
+/// assert!(sample(b"trace-8e1f", 1.0, 0));
+/// assert!(!sample(b"trace-8e1f", 0.0, 0));
+/// ```
+#[inline]
+#[must_use]
+pub fn sample(key: &[u8], rate: f64, seed: u64) -> bool {
+    if rate >= 1.0 {
+        return true;
+    }
+    // 2^64 as a float, exactly; the cast saturates negative and NaN values to 0
+    let threshold = (rate * 18_446_744_073_709_551_616.0) as u64;
+    rapidhash_seeded(key, seed) < threshold
+}
+
+/// Derive an 8-bit fingerprint from a 64-bit hash, for cuckoo filter tags and swiss-table-style
+/// metadata bytes.
+///
+/// This takes the top 8 bits of the hash. Tables usually pick the slot from the low bits, for
+/// example with [rapid_range_pow2], and so taking the fingerprint from the opposite end keeps the
+/// two independent. Naively truncating with `hash as u8` would reuse the same bits as the slot
+/// index, and every key in a slot would share a fingerprint.
+///
+/// When the slot is chosen with [rapid_range], which depends mostly on the top bits, use the low
+/// bits as the fingerprint instead.
+///
+/// Tables that reserve `0` for empty slots should remap a zero fingerprint, e.g. with
+/// `fingerprint.max(1)`.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash, rapid_fingerprint8, rapid_range_pow2};
+///
+/// let hash = rapidhash(b"hello world");
+/// let slot = rapid_range_pow2(hash, 1024);
+/// let tag = rapid_fingerprint8(hash);
+/// ```
+#[inline(always)]
+#[must_use]
+pub const fn rapid_fingerprint8(hash: u64) -> u8 {
+    (hash >> 56) as u8
+}
+
+/// Derive a 16-bit fingerprint from a 64-bit hash, by taking the top 16 bits.
+///
+/// See [rapid_fingerprint8] for why the top bits are used.
+#[inline(always)]
+#[must_use]
+pub const fn rapid_fingerprint16(hash: u64) -> u16 {
+    (hash >> 48) as u16
+}
+
+/// Derive a 32-bit fingerprint or compact index from a 64-bit hash, by taking the top 32 bits.
+///
+/// See [rapid_fingerprint8] for why the top bits are used.
+#[inline(always)]
+#[must_use]
+pub const fn rapid_fingerprint32(hash: u64) -> u32 {
+    (hash >> 32) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rapidhash;
+
+    #[test]
+    fn range_bounds() {
+        assert_eq!(rapid_range(u64::MAX, 10), 9);
+        assert_eq!(rapid_range(0, 10), 0);
+        assert_eq!(rapid_range(u64::MAX, 0), 0);
+        assert_eq!(rapid_range(1 << 63, 10), 5);
+        assert_eq!(rapid_range_pow2(u64::MAX, 1), 0);
+        assert_eq!(rapid_range_pow2(0xff, 16), 0xf);
+    }
+
+    #[test]
+    fn range_is_roughly_uniform() {
+        let mut counts = [0u32; 10];
+        for i in 0..100_000u64 {
+            let hash = rapidhash(&i.to_le_bytes());
+            counts[rapid_range(hash, 10) as usize] += 1;
+        }
+        for count in counts {
+            assert!(count > 9_500 && count < 10_500, "count {count}");
+        }
+    }
+
+    #[test]
+    fn fingerprints_use_top_bits() {
+        let hash = 0x0123_4567_89ab_cdef;
+        assert_eq!(rapid_fingerprint8(hash), 0x01);
+        assert_eq!(rapid_fingerprint16(hash), 0x0123);
+        assert_eq!(rapid_fingerprint32(hash), 0x0123_4567);
+
+        // keys sharing a slot should still spread across all fingerprints
+        let mut seen = [false; 256];
+        for i in 0..100_000u64 {
+            let hash = rapidhash(&i.to_le_bytes());
+            if rapid_range_pow2(hash, 16) == 0 {
+                seen[rapid_fingerprint8(hash) as usize] = true;
+            }
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn partition_for_is_frozen() {
+        // these values must never change, as they are relied on across services and languages
+        assert_eq!(partition_for(b"", 1, 0), 0);
+        assert_eq!(partition_for(b"hello world", 12, 0), 4);
+        assert_eq!(partition_for(b"hello world", 12, 1), 5);
+        assert_eq!(partition_for(b"user-1234", 1000, 0), 817);
+        assert_eq!(partition_for(b"user-1234", u32::MAX, 0), 3_513_039_226);
+
+        for i in 0..1000u64 {
+            let key = i.to_le_bytes();
+            let hash = rapidhash_seeded(&key, 0);
+            // rejection is vanishingly rare, so this matches the plain multiply-shift
+            assert_eq!(partition_for(&key, 7, 0) as u64, rapid_range(hash, 7));
+        }
+    }
+
+    #[test]
+    fn bucket_and_sample_are_frozen() {
+        // these values must never change, as they are relied on across services and releases
+        assert_eq!(bucket(b"user-1234", 1000, 0), 817);
+        assert_eq!(bucket(b"hello world", 12, 1), 5);
+        assert!(!sample(b"hello world", 0.34, 0));
+        assert!(sample(b"hello world", 0.35, 0));
+        assert!(sample(b"", 1.0, 0));
+        assert!(sample(b"", f64::INFINITY, 0));
+        assert!(!sample(b"", 0.0, 0));
+        assert!(!sample(b"", -1.0, 0));
+        assert!(!sample(b"", f64::NAN, 0));
+
+        let mut sampled = 0;
+        for i in 0..100_000u64 {
+            let key = i.to_le_bytes();
+            let low = sample(&key, 0.1, 7);
+            sampled += low as u32;
+            // sampled at a lower rate implies sampled at a higher rate
+            assert!(!low || sample(&key, 0.2, 7));
+        }
+        assert!(sampled > 9_500 && sampled < 10_500, "sampled {sampled}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn partition_for_rejects_zero() {
+        let _ = partition_for(b"key", 0, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn pow2_rejects_other_sizes() {
+        let _ = rapid_range_pow2(0, 10);
+    }
+}
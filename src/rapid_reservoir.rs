@@ -0,0 +1,183 @@
+use std::vec::Vec;
+use crate::RapidRng;
+
+/// A fixed-size uniform random sample of a stream of unknown length, using [RapidRng] for its
+/// random decisions.
+///
+/// After `n` items have been pushed, each of them is in the sample with equal probability
+/// `capacity / n`. This uses Li's Algorithm L, which computes how many items to skip before the
+/// next replacement instead of drawing a random number for every item. Once the reservoir is full,
+/// pushing an item that is skipped is just a counter comparison, so sampling a long stream costs
+/// `O(capacity * (1 + ln(n / capacity)))` random numbers in total.
+///
+/// The order of the sample is unspecified. Requires the `std` and `rng` features.
+///
+/// # Example
+/// ```
+/// use rapidhash::{RapidRng, Reservoir};
+///
+/// let mut reservoir = Reservoir::with_rng(10, RapidRng::new(42));
+/// reservoir.extend(0..1_000_000u32);
+///
+/// assert_eq!(reservoir.len(), 10);
+/// assert_eq!(reservoir.seen(), 1_000_000);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Reservoir<T> {
+    capacity: usize,
+    seen: u64,
+    items: Vec<T>,
+    rng: RapidRng,
+    /// The running maximum of the `capacity` smallest random keys, from Algorithm L.
+    w: f64,
+    /// The index of the next item to be sampled once the reservoir is full.
+    next: u64,
+}
+
+impl<T> Reservoir<T> {
+    /// Create an empty reservoir holding up to `capacity` items, seeded with [RapidRng::default].
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    #[inline]
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::with_rng(capacity, RapidRng::default())
+    }
+
+    /// Create an empty reservoir holding up to `capacity` items, using `rng` for its random
+    /// decisions so the sample is reproducible.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    #[must_use]
+    pub fn with_rng(capacity: usize, rng: RapidRng) -> Self {
+        assert!(capacity > 0, "Reservoir: capacity must be non-zero");
+        Self {
+            capacity,
+            seen: 0,
+            items: Vec::with_capacity(capacity),
+            rng,
+            w: 1.0,
+            next: 0,
+        }
+    }
+
+    /// Offer an item from the stream to the sample.
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        let index = self.seen;
+        self.seen += 1;
+
+        if self.items.len() < self.capacity {
+            self.items.push(item);
+            if self.items.len() == self.capacity {
+                self.advance();
+                self.next = index;
+                self.skip();
+            }
+        } else if index == self.next {
+            let slot = self.rng.next_below(self.capacity as u64) as usize;
+            self.items[slot] = item;
+            self.advance();
+            self.skip();
+        }
+    }
+
+    /// The maximum number of items in the sample.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of items in the sample, which is less than the capacity until the reservoir has
+    /// been filled.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if no items have been pushed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The number of items pushed so far.
+    #[inline]
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// The current sample.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Consume the reservoir and return the sample.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Multiply `w` by `u^(1/capacity)`, the largest of `capacity` uniform random numbers.
+    fn advance(&mut self) {
+        self.w *= (self.uniform().ln() / self.capacity as f64).exp();
+    }
+
+    /// Move `next` past a geometrically distributed number of skipped items.
+    fn skip(&mut self) {
+        let skip = (self.uniform().ln() / (-self.w).ln_1p()).floor();
+        // a huge or infinite skip saturates, and the rest of the stream is skipped
+        self.next = self.next.saturating_add(skip as u64).saturating_add(1);
+    }
+
+    /// A uniform random float in `(0, 1]`, which is never zero so its logarithm is finite.
+    fn uniform(&mut self) -> f64 {
+        ((self.rng.next() >> 11) + 1) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+impl<T> Extend<T> for Reservoir<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_before_sampling() {
+        let mut reservoir = Reservoir::with_rng(5, RapidRng::new(1));
+        reservoir.extend(0..3u32);
+        assert_eq!(reservoir.as_slice(), [0, 1, 2]);
+        reservoir.extend(3..5);
+        assert_eq!(reservoir.as_slice(), [0, 1, 2, 3, 4]);
+        reservoir.extend(5..1000);
+        assert_eq!(reservoir.len(), 5);
+        assert_eq!(reservoir.seen(), 1000);
+    }
+
+    #[test]
+    fn sample_is_uniform() {
+        // each of 100 items should be sampled 10% of the time into a reservoir of 10
+        let mut counts = [0u32; 100];
+        let mut rng = RapidRng::new(7);
+        for _ in 0..20_000 {
+            let mut reservoir = Reservoir::with_rng(10, RapidRng::new(rng.next()));
+            reservoir.extend(0..100usize);
+            for &item in reservoir.as_slice() {
+                counts[item] += 1;
+            }
+        }
+        for count in counts {
+            assert!(count > 1_800 && count < 2_200, "count {count}");
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use alloc::vec::Vec;
+use crate::rapid_const::{rapidhash_seeded, RAPID_SEED};
+
+/// A consistent hash ring, which routes keys to weighted nodes so that adding or removing a node
+/// only moves the keys on that node.
+///
+/// Each node is placed on the ring at `replicas * weight` points, and a key is routed to the node
+/// owning the first point at or after the key's hash. A node's share of the keys is therefore
+/// proportional to its weight, so a fleet of backends with different capacities can be balanced by
+/// giving each a weight proportional to its capacity. More replicas per unit of weight give a more
+/// even split, at the cost of memory and lookup time: a node's share deviates from its target by
+/// around `1 / sqrt(replicas * weight)`, so around 10% with 100 replicas and a weight of 1.
+///
+/// Nodes are identified by their bytes, such as a host name. The points for a node depend only on
+/// its bytes, weight, and the seed, so every process with the same nodes, weights, and seed routes
+/// every key the same way, whatever order the nodes were added in.
+///
+/// Requires the `alloc` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::RapidHashRing;
+///
+/// let mut ring = RapidHashRing::new(100);
+/// ring.add("cache-a", 1);
+/// ring.add("cache-b", 1);
+/// // twice the memory, twice the keys
+/// ring.add("cache-c", 2);
+///
+/// let node = ring.get(b"user-1234").unwrap();
+/// assert!(["cache-a", "cache-b", "cache-c"].contains(node));
+///
+/// // removing a node only moves the keys it owned
+/// let before = *ring.get(b"user-1234").unwrap();
+/// ring.remove(b"cache-a");
+/// if before != "cache-a" {
+///     assert_eq!(*ring.get(b"user-1234").unwrap(), before);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RapidHashRing<N> {
+    seed: u64,
+    replicas: u32,
+    nodes: Vec<RingNode<N>>,
+    /// The points on the ring, sorted by hash, with the index of the node owning each.
+    points: Vec<(u64, usize)>,
+}
+
+#[derive(Clone, Debug)]
+struct RingNode<N> {
+    node: N,
+    weight: u32,
+}
+
+impl<N: AsRef<[u8]>> RapidHashRing<N> {
+    /// Create an empty ring with `replicas` points per unit of weight, and the default seed.
+    #[inline]
+    #[must_use]
+    pub fn new(replicas: u32) -> Self {
+        Self::new_seeded(replicas, RAPID_SEED)
+    }
+
+    /// Create an empty ring with `replicas` points per unit of weight, and a custom seed.
+    #[must_use]
+    pub fn new_seeded(replicas: u32, seed: u64) -> Self {
+        Self {
+            seed,
+            replicas,
+            nodes: Vec::new(),
+            points: Vec::new(),
+        }
+    }
+
+    /// Add a node with a weight, or change the weight of a node with the same bytes.
+    ///
+    /// A node with a weight of `0` stays in the ring but receives no keys, for example to drain it.
+    pub fn add(&mut self, node: N, weight: u32) {
+        match self.position(node.as_ref()) {
+            Some(index) => self.nodes[index] = RingNode { node, weight },
+            None => self.nodes.push(RingNode { node, weight }),
+        }
+        self.rebuild();
+    }
+
+    /// Remove the node with these bytes, returning it if it was in the ring.
+    pub fn remove(&mut self, node: &[u8]) -> Option<N> {
+        let index = self.position(node)?;
+        let removed = self.nodes.remove(index);
+        self.rebuild();
+        Some(removed.node)
+    }
+
+    /// The node that `key` is routed to, or `None` if no node has a non-zero weight.
+    #[inline]
+    pub fn get(&self, key: &[u8]) -> Option<&N> {
+        self.get_hash(rapidhash_seeded(key, self.seed))
+    }
+
+    /// The node that a key with a precomputed `hash` is routed to. The hash must be the
+    /// [crate::rapidhash_seeded] of the key with this ring's seed.
+    pub fn get_hash(&self, hash: u64) -> Option<&N> {
+        let point = self.point_after(hash)?;
+        Some(&self.nodes[self.points[point].1].node)
+    }
+
+    /// The weight of the node with these bytes, if it's in the ring.
+    pub fn weight(&self, node: &[u8]) -> Option<u32> {
+        self.position(node).map(|index| self.nodes[index].weight)
+    }
+
+    /// The nodes in the ring and their weights, in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = (&N, u32)> {
+        self.nodes.iter().map(|node| (&node.node, node.weight))
+    }
+
+    /// The number of nodes in the ring, including nodes with a weight of `0`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the ring has no nodes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The seed the ring hashes keys with.
+    #[inline]
+    pub(crate) fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The sum of the weights of every node.
+    pub(crate) fn total_weight(&self) -> u64 {
+        self.nodes.iter().map(|node| node.weight as u64).sum()
+    }
+
+    /// The node with this index, and its weight.
+    #[inline]
+    pub(crate) fn node_at(&self, index: usize) -> (&N, u32) {
+        let node = &self.nodes[index];
+        (&node.node, node.weight)
+    }
+
+    /// The index of the node with these bytes.
+    #[inline]
+    pub(crate) fn index_of(&self, node: &[u8]) -> Option<usize> {
+        self.position(node)
+    }
+
+    /// The indices of the nodes owning each point, walking clockwise from `hash` for one lap of the
+    /// ring.
+    pub(crate) fn walk(&self, hash: u64) -> impl Iterator<Item = usize> + '_ {
+        let start = self.point_after(hash).unwrap_or(0);
+        let (before, after) = self.points.split_at(start);
+        after.iter().chain(before).map(|&(_, index)| index)
+    }
+
+    /// The index into `points` of the first point at or after `hash`, wrapping around the ring.
+    #[inline]
+    fn point_after(&self, hash: u64) -> Option<usize> {
+        if self.points.is_empty() {
+            return None;
+        }
+        let point = self.points.partition_point(|&(point, _)| point < hash);
+        Some(if point == self.points.len() { 0 } else { point })
+    }
+
+    fn position(&self, node: &[u8]) -> Option<usize> {
+        self.nodes.iter().position(|n| n.node.as_ref() == node)
+    }
+
+    /// Recompute every point, after the nodes or their weights have changed.
+    fn rebuild(&mut self) {
+        self.points.clear();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let node_hash = rapidhash_seeded(node.node.as_ref(), self.seed);
+            let count = self.replicas as u64 * node.weight as u64;
+            self.points.extend((0..count).map(|replica| {
+                (rapidhash_seeded(&replica.to_le_bytes(), node_hash), index)
+            }));
+        }
+
+        // break the rare tie between two nodes' points by their bytes, not the order they were added
+        let nodes = &self.nodes;
+        self.points.sort_unstable_by(|a, b| {
+            a.0.cmp(&b.0).then_with(|| nodes[a.1].node.as_ref().cmp(nodes[b.1].node.as_ref()))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn shares_follow_weights() {
+        let mut ring = RapidHashRing::new(100);
+        ring.add("a", 1);
+        ring.add("b", 3);
+        ring.add("c", 0);
+
+        let mut counts = [0u32; 3];
+        for i in 0..100_000u64 {
+            let node = ring.get(&i.to_le_bytes()).unwrap();
+            counts[(node.as_bytes()[0] - b'a') as usize] += 1;
+        }
+        assert!(counts[0] > 20_000 && counts[0] < 30_000, "counts {counts:?}");
+        assert_eq!(counts[2], 0);
+        assert_eq!(ring.weight(b"b"), Some(3));
+    }
+
+    #[test]
+    fn only_moved_keys_change() {
+        let mut ring = RapidHashRing::new_seeded(50, 7);
+        for node in ["a", "b", "c"] {
+            ring.add(node, 1);
+        }
+        let before: Vec<&str> = (0..10_000u64).map(|i| *ring.get(&i.to_le_bytes()).unwrap()).collect();
+
+        // order of insertion doesn't matter
+        let mut reversed = RapidHashRing::new_seeded(50, 7);
+        for node in ["c", "b", "a"] {
+            reversed.add(node, 1);
+        }
+
+        ring.add("d", 2);
+        for (i, before) in before.iter().enumerate() {
+            let key = (i as u64).to_le_bytes();
+            assert_eq!(reversed.get(&key), Some(before));
+            let after = *ring.get(&key).unwrap();
+            assert!(after == *before || after == "d");
+        }
+
+        assert_eq!(ring.remove(b"d"), Some("d"));
+        assert_eq!(ring.remove(b"d"), None);
+        for (i, before) in before.iter().enumerate() {
+            assert_eq!(ring.get(&(i as u64).to_le_bytes()), Some(before));
+        }
+    }
+
+    #[test]
+    fn empty_ring() {
+        let mut ring = RapidHashRing::<&str>::new(10);
+        assert!(ring.get(b"key").is_none());
+        ring.add("a", 0);
+        assert!(ring.get(b"key").is_none());
+        assert_eq!(ring.len(), 1);
+    }
+}
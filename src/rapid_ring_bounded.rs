@@ -0,0 +1,202 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::rapid_const::rapidhash_seeded;
+use crate::RapidHashRing;
+
+/// Consistent hashing with bounded loads, which caps every node of a [RapidHashRing] at
+/// `(1 + epsilon)` times its fair share of the keys, to avoid hot spots in cache and gateway
+/// sharding.
+///
+/// Each assigned key is routed to the node that the ring would route it to, unless that node is
+/// already full, in which case it spills over to the next node clockwise around the ring that has
+/// capacity. A node's capacity is `ceil((1 + epsilon) * (assigned + 1) * weight / total_weight)`,
+/// so no node ever holds more than `1 + epsilon` times its weighted average load. A smaller
+/// `epsilon` evens out the load, but moves more keys away from their preferred node.
+///
+/// This is the algorithm of Mirrokni, Thorup, and Zadimoghaddam, "Consistent Hashing with Bounded
+/// Loads". Unlike a plain ring, the node a key is assigned to depends on the current loads, so keys
+/// must be released with [RapidBoundedRing::release] when they leave their node, and every process
+/// sharing assignments must see the same sequence of assignments and releases.
+///
+/// The ring's nodes are fixed once it's wrapped. To change the nodes, take the ring back with
+/// [RapidBoundedRing::into_ring] and wrap it again, which starts every load at zero.
+///
+/// Requires the `alloc` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::{RapidBoundedRing, RapidHashRing};
+///
+/// let mut ring = RapidHashRing::new(100);
+/// ring.add("gateway-a", 1);
+/// ring.add("gateway-b", 1);
+///
+/// let mut bounded = RapidBoundedRing::new(ring, 0.25);
+/// for i in 0..1000u32 {
+///     bounded.assign(&i.to_le_bytes());
+/// }
+///
+/// // neither gateway holds more than 1.25x its fair share of 500
+/// assert!(bounded.load(b"gateway-a").unwrap() <= 625);
+/// assert!(bounded.load(b"gateway-b").unwrap() <= 625);
+///
+/// let node = *bounded.assign(b"session-42").unwrap();
+/// bounded.release(node.as_bytes());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RapidBoundedRing<N> {
+    ring: RapidHashRing<N>,
+    epsilon: f64,
+    total_weight: u64,
+    /// The number of keys assigned to each node, by the node's index in the ring.
+    loads: Vec<u64>,
+    assigned: u64,
+}
+
+impl<N: AsRef<[u8]>> RapidBoundedRing<N> {
+    /// Wrap a ring to bound every node's load at `(1 + epsilon)` times its fair share.
+    ///
+    /// # Panics
+    /// Panics if `epsilon` is negative or NaN.
+    #[must_use]
+    pub fn new(ring: RapidHashRing<N>, epsilon: f64) -> Self {
+        assert!(epsilon >= 0.0, "RapidBoundedRing: epsilon must be non-negative");
+        Self {
+            total_weight: ring.total_weight(),
+            loads: vec![0; ring.len()],
+            ring,
+            epsilon,
+            assigned: 0,
+        }
+    }
+
+    /// Assign `key` to the first node clockwise from its hash that has capacity, and count it
+    /// towards that node's load. Returns `None` if no node has a non-zero weight.
+    #[inline]
+    pub fn assign(&mut self, key: &[u8]) -> Option<&N> {
+        self.assign_hash(rapidhash_seeded(key, self.ring.seed()))
+    }
+
+    /// Assign a key with a precomputed `hash`, which must be the [crate::rapidhash_seeded] of the
+    /// key with the ring's seed. See [RapidBoundedRing::assign].
+    pub fn assign_hash(&mut self, hash: u64) -> Option<&N> {
+        let index = self.find(hash)?;
+        self.loads[index] += 1;
+        self.assigned += 1;
+        Some(self.ring.node_at(index).0)
+    }
+
+    /// The node that `key` would be assigned to at the current loads, without assigning it.
+    #[inline]
+    pub fn peek(&self, key: &[u8]) -> Option<&N> {
+        let index = self.find(rapidhash_seeded(key, self.ring.seed()))?;
+        Some(self.ring.node_at(index).0)
+    }
+
+    /// Release a key that was assigned to the node with these bytes, for example when a session
+    /// ends or a cache entry is evicted. Returns `false` if the node isn't in the ring or holds no
+    /// keys.
+    pub fn release(&mut self, node: &[u8]) -> bool {
+        match self.ring.index_of(node) {
+            Some(index) if self.loads[index] > 0 => {
+                self.loads[index] -= 1;
+                self.assigned -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The number of keys currently assigned to the node with these bytes.
+    pub fn load(&self, node: &[u8]) -> Option<u64> {
+        self.ring.index_of(node).map(|index| self.loads[index])
+    }
+
+    /// The number of keys currently assigned to every node.
+    #[inline]
+    pub fn assigned(&self) -> u64 {
+        self.assigned
+    }
+
+    /// The wrapped ring.
+    #[inline]
+    pub fn ring(&self) -> &RapidHashRing<N> {
+        &self.ring
+    }
+
+    /// Unwrap the ring, discarding the loads.
+    #[inline]
+    pub fn into_ring(self) -> RapidHashRing<N> {
+        self.ring
+    }
+
+    /// The index of the first node clockwise from `hash` with room for one more key.
+    fn find(&self, hash: u64) -> Option<usize> {
+        if self.total_weight == 0 {
+            return None;
+        }
+
+        let target = (1.0 + self.epsilon) * (self.assigned + 1) as f64 / self.total_weight as f64;
+        self.ring.walk(hash).find(|&index| {
+            // loads are integers, so comparing to the unrounded capacity is the same as its ceiling
+            (self.loads[index] as f64) < target * self.ring.node_at(index).1 as f64
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use super::*;
+
+    fn ring(weights: &[u32]) -> RapidHashRing<String> {
+        let mut ring = RapidHashRing::new(100);
+        for (i, &weight) in weights.iter().enumerate() {
+            ring.add(i.to_string(), weight);
+        }
+        ring
+    }
+
+    #[test]
+    fn loads_are_bounded() {
+        let mut bounded = RapidBoundedRing::new(ring(&[1, 1, 1, 1, 2, 0]), 0.1);
+        for i in 0..60_000u64 {
+            bounded.assign(&i.to_le_bytes()).unwrap();
+        }
+        assert_eq!(bounded.assigned(), 60_000);
+        for (node, weight) in bounded.ring().nodes() {
+            // 1.1 times the fair share of 10,000 keys per unit of weight
+            let cap = 11_000 * weight as u64 + 1;
+            assert!(bounded.load(node.as_bytes()).unwrap() <= cap, "node {node}");
+        }
+        assert_eq!(bounded.load(b"5"), Some(0));
+    }
+
+    #[test]
+    fn prefers_the_ring_node() {
+        // with a generous bound, keys go where the plain ring puts them
+        let mut bounded = RapidBoundedRing::new(ring(&[1, 1, 1]), 10.0);
+        for i in 0..100u64 {
+            let key = i.to_le_bytes();
+            let expected = bounded.ring().get(&key).cloned();
+            assert_eq!(bounded.peek(&key).cloned(), expected);
+            assert_eq!(bounded.assign(&key).cloned(), expected);
+        }
+    }
+
+    #[test]
+    fn release_frees_capacity() {
+        let mut bounded = RapidBoundedRing::new(ring(&[1, 1]), 0.0);
+        let first = bounded.assign(b"a").unwrap().clone();
+        // each node may hold at most one of the first two keys
+        let second = bounded.assign(b"a").unwrap().clone();
+        assert_ne!(first, second);
+
+        assert!(bounded.release(first.as_bytes()));
+        assert!(!bounded.release(first.as_bytes()));
+        assert!(!bounded.release(b"missing"));
+        assert_eq!(bounded.assigned(), 1);
+
+        assert!(RapidBoundedRing::new(ring(&[0]), 0.5).assign(b"a").is_none());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::rapid_const::{rapidhash_inline, RAPID_SECRET};
+
+/// A node in a tree of deterministic seeds, for giving every task of a parallel run its own
+/// independent seed derived from one master seed.
+///
+/// Each child is derived from its parent's seed and an index or a label with a full rapidhash, so
+/// sibling seeds are unrelated to each other and to their parent. The seed of a task depends only
+/// on the master seed and the task's path, not on the order or the thread the tasks run on, so a
+/// whole run can be reproduced from the master seed alone.
+///
+/// Index and label children are derived differently, so `child(1)` and a label with the same
+/// bytes never share a seed. All methods are `const`.
+///
+/// # Example
+/// ```rust
+/// use rapidhash::SeedTree;
+///
+/// let master = SeedTree::new(42);
+///
+/// // each worker and each of its tasks get their own seed
+/// let worker = master.child_label("worker").child(3);
+/// let task = worker.child(17);
+///
+/// // the same path always gives the same seed
+/// assert_eq!(task, master.child_label("worker").path(&[3, 17]));
+/// assert_ne!(task.seed(), worker.child(18).seed());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub struct SeedTree {
+    seed: u64,
+}
+
+impl SeedTree {
+    /// Create the root of a seed tree from a master seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// The seed at this node of the tree.
+    #[inline]
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Derive the child at `index`, such as a task or shard number.
+    #[inline]
+    #[must_use]
+    pub const fn child(&self, index: u64) -> Self {
+        Self::new(rapidhash_inline(&index.to_le_bytes(), self.seed))
+    }
+
+    /// Derive the child named `label`, such as a subsystem or stage of a run.
+    #[inline]
+    #[must_use]
+    pub const fn child_label(&self, label: &str) -> Self {
+        Self::new(rapidhash_inline(label.as_bytes(), self.seed ^ RAPID_SECRET[2]))
+    }
+
+    /// Derive a descendant by following a path of indices, equal to calling [SeedTree::child] for
+    /// each index in turn.
+    #[inline]
+    #[must_use]
+    pub const fn path(&self, path: &[u64]) -> Self {
+        let mut node = *self;
+        let mut i = 0;
+        while i < path.len() {
+            node = node.child(path[i]);
+            i += 1;
+        }
+        node
+    }
+
+    /// Create a [crate::RapidRng] seeded from this node.
+    ///
+    /// Requires the `rng` feature.
+    #[cfg(any(feature = "rng", docsrs))]
+    #[inline]
+    pub fn rng(&self) -> crate::RapidRng {
+        crate::RapidRng::new(self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_tree_paths() {
+        let root = SeedTree::new(7);
+        assert_eq!(root.path(&[]), root);
+        assert_eq!(root.path(&[1, 2]), root.child(1).child(2));
+        assert_ne!(root.path(&[1, 2]), root.path(&[2, 1]));
+        assert_ne!(root.child(0), root);
+        assert_ne!(root.child(1), SeedTree::new(8).child(1));
+
+        // index and label children are separate
+        let label = core::str::from_utf8(&[1, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        assert_ne!(root.child(1), root.child_label(label));
+        assert_ne!(root.child_label("a"), root.child_label("b"));
+    }
+
+    #[test]
+    fn seed_tree_siblings_are_distinct() {
+        let root = SeedTree::new(0);
+        let mut seeds = [0u64; 1000];
+        for (i, seed) in seeds.iter_mut().enumerate() {
+            *seed = root.child(i as u64).seed();
+        }
+        seeds.sort_unstable();
+        assert!(seeds.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+}
@@ -0,0 +1,223 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::vec::Vec;
+use crate::{rapid_range, RapidBuildHasher, RapidHashMap};
+
+/// A concurrent hash map split into `N` [RapidHashMap] shards, each behind its own [RwLock].
+///
+/// Each key is hashed with rapidhash to pick its shard, and again inside the shard's map. Threads
+/// working on keys in different shards never contend, which makes this a lightweight alternative
+/// to `dashmap` for workloads that only need coarse sharding.
+///
+/// The shard is chosen from the bits just below the top 7 bits of the hash, avoiding both the low
+/// bits that the shard's table uses to pick a bucket and the top 7 bits it uses as a tag. Keys in
+/// the same shard are therefore still spread evenly across that shard's table.
+///
+/// Values are only reachable through the shard's lock, so lookups either clone the value with
+/// [RapidShardedMap::get] or run a closure on it with [RapidShardedMap::with]. Don't call back into
+/// the map from inside these closures, as a write to the same shard would deadlock.
+///
+/// Requires the `std` and `hasher` features.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use rapidhash::RapidShardedMap;
+///
+/// let map = Arc::new(RapidShardedMap::new());
+///
+/// let handles: Vec<_> = (0..4u32).map(|t| {
+///     let map = map.clone();
+///     std::thread::spawn(move || {
+///         for i in 0..100 {
+///             map.insert(t * 100 + i, i);
+///         }
+///     })
+/// }).collect();
+/// handles.into_iter().for_each(|h| h.join().unwrap());
+///
+/// assert_eq!(map.len(), 400);
+/// assert_eq!(map.get(&205), Some(5));
+/// ```
+pub struct RapidShardedMap<K, V> {
+    shards: Vec<RwLock<RapidHashMap<K, V>>>,
+    hasher: RapidBuildHasher,
+}
+
+impl<K: Hash + Eq, V> RapidShardedMap<K, V> {
+    /// Create an empty map with four shards per available CPU.
+    pub fn new() -> Self {
+        let cpus = std::thread::available_parallelism().map_or(4, |n| n.get());
+        Self::with_shards(cpus * 4)
+    }
+
+    /// Create an empty map with `shards` shards.
+    ///
+    /// # Panics
+    /// Panics if `shards` is `0`.
+    pub fn with_shards(shards: usize) -> Self {
+        assert!(shards > 0, "RapidShardedMap: shards must be non-zero");
+        Self {
+            shards: (0..shards).map(|_| RwLock::new(RapidHashMap::default())).collect(),
+            hasher: RapidBuildHasher::default(),
+        }
+    }
+
+    /// The number of shards.
+    #[inline]
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// The index of the shard that holds `key`.
+    #[inline]
+    pub fn shard_index<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        let hash = self.hasher.hash_one(key);
+        rapid_range(hash << 7, self.shards.len() as u64) as usize
+    }
+
+    /// Lock the shard at `index` for reading, for iteration or batches of lookups.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn read_shard(&self, index: usize) -> RwLockReadGuard<'_, RapidHashMap<K, V>> {
+        self.shards[index].read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Lock the shard at `index` for writing, for batches of updates.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn write_shard(&self, index: usize) -> RwLockWriteGuard<'_, RapidHashMap<K, V>> {
+        self.shards[index].write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The total number of entries, summed over all shards.
+    ///
+    /// Shards are locked one at a time, so concurrent writes may or may not be counted.
+    pub fn len(&self) -> usize {
+        (0..self.shards.len()).map(|i| self.read_shard(i).len()).sum()
+    }
+
+    /// Returns `true` if every shard is empty.
+    pub fn is_empty(&self) -> bool {
+        (0..self.shards.len()).all(|i| self.read_shard(i).is_empty())
+    }
+
+    /// Insert a key-value pair, returning the previous value for the key.
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        self.write_shard(self.shard_index(&key)).insert(key, value)
+    }
+
+    /// Remove a key, returning its value.
+    pub fn remove<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.write_shard(self.shard_index(key)).remove(key)
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.read_shard(self.shard_index(key)).contains_key(key)
+    }
+
+    /// Returns a clone of the value for `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+        V: Clone,
+    {
+        self.read_shard(self.shard_index(key)).get(key).cloned()
+    }
+
+    /// Run `f` on the value for `key` while holding the shard's read lock.
+    pub fn with<Q, R>(&self, key: &Q, f: impl FnOnce(Option<&V>) -> R) -> R
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        f(self.read_shard(self.shard_index(key)).get(key))
+    }
+
+    /// Run `f` on the value for `key`, inserting `default()` first if it is missing, while holding
+    /// the shard's write lock.
+    pub fn update<R>(&self, key: K, default: impl FnOnce() -> V, f: impl FnOnce(&mut V) -> R) -> R {
+        let mut shard = self.write_shard(self.shard_index(&key));
+        f(shard.entry(key).or_insert_with(default))
+    }
+
+    /// Remove all entries.
+    pub fn clear(&self) {
+        for i in 0..self.shards.len() {
+            self.write_shard(i).clear();
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for RapidShardedMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use super::*;
+
+    #[test]
+    fn concurrent_updates() {
+        let map = Arc::new(RapidShardedMap::with_shards(8));
+        let handles: Vec<_> = (0..8).map(|_| {
+            let map = map.clone();
+            std::thread::spawn(move || {
+                for i in 0..1000u32 {
+                    map.update(i % 100, || 0u32, |v| *v += 1);
+                }
+            })
+        }).collect();
+        handles.into_iter().for_each(|h| h.join().unwrap());
+
+        assert_eq!(map.len(), 100);
+        for i in 0..100 {
+            assert_eq!(map.get(&i), Some(80));
+        }
+    }
+
+    #[test]
+    fn basic_operations() {
+        let map = RapidShardedMap::with_shards(3);
+        assert!(map.is_empty());
+        assert_eq!(map.insert("a".to_string(), 1), None);
+        assert_eq!(map.insert("a".to_string(), 2), Some(1));
+        assert!(map.contains_key("a"));
+        assert_eq!(map.with("a", |v| v.copied()), Some(2));
+        assert_eq!(map.remove("a"), Some(2));
+        assert!(!map.contains_key("a"));
+
+        map.insert("b".to_string(), 3);
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn keys_spread_across_shards() {
+        let map = RapidShardedMap::with_shards(16);
+        for i in 0..16_000u32 {
+            map.insert(i, ());
+        }
+        for i in 0..map.shard_count() {
+            let len = map.read_shard(i).len();
+            assert!(len > 800 && len < 1200, "shard {i} has {len} entries");
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::rapid_const::{rapidhash_inline, RAPID_SEED};
+
+/// A SimHash builder that accumulates weighted token hashes into a 64-bit fingerprint, for
+/// near-duplicate detection of text and other token streams.
+///
+/// Unlike a regular hash, similar inputs produce fingerprints that differ in only a few bits, so the
+/// [hamming_distance] between two fingerprints estimates how different the inputs are. Inputs
+/// sharing most of their tokens typically have a distance of a few bits, while unrelated inputs
+/// average 32.
+///
+/// Each token is hashed with [crate::rapidhash_seeded], and each bit of the fingerprint is set if
+/// the weighted vote of the tokens with that bit set outweighs those without it.
+///
+/// # Example
+/// ```
+/// use rapidhash::{hamming_distance, RapidSimHash};
+///
+/// fn simhash(text: &str) -> u64 {
+///     let mut simhash = RapidSimHash::new();
+///     for word in text.split_whitespace() {
+///         simhash.add_token(word);
+///     }
+///     simhash.fingerprint()
+/// }
+///
+/// let a = simhash("the quick brown fox jumps over the lazy dog near the river bank today");
+/// let b = simhash("the quick brown fox jumps over the lazy cat near the river bank today");
+/// let c = simhash("lorem ipsum dolor sit amet consectetur adipiscing elit sed do eiusmod");
+///
+/// assert!(hamming_distance(a, b) < hamming_distance(a, c));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RapidSimHash {
+    seed: u64,
+    /// The per-bit vote totals, wide enough that no sequence of fewer than 2^63 `i64` weights can
+    /// overflow them.
+    weights: [i128; 64],
+}
+
+impl RapidSimHash {
+    /// Create a new [RapidSimHash] with the default seed.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::new_seeded(RAPID_SEED)
+    }
+
+    /// Create a new [RapidSimHash] with a custom seed. Fingerprints are only comparable when built
+    /// with the same seed.
+    #[inline]
+    #[must_use]
+    pub const fn new_seeded(seed: u64) -> Self {
+        Self {
+            seed,
+            weights: [0; 64],
+        }
+    }
+
+    /// Add a token with a weight of 1.
+    #[inline]
+    pub fn add_token(&mut self, token: impl AsRef<[u8]>) {
+        self.add_weighted(token, 1);
+    }
+
+    /// Add a token with a custom weight, such as its frequency or TF-IDF score. A negative weight
+    /// removes a previously added token.
+    ///
+    /// The weights are summed in `i128`, so any `i64` weights can be added without overflowing, up
+    /// to 2^63 tokens.
+    #[inline]
+    pub fn add_weighted(&mut self, token: impl AsRef<[u8]>, weight: i64) {
+        self.add_hash(rapidhash_inline(token.as_ref(), self.seed), weight);
+    }
+
+    /// Add a pre-computed 64-bit token hash with a custom weight. See [RapidSimHash::add_weighted].
+    #[inline]
+    pub fn add_hash(&mut self, hash: u64, weight: i64) {
+        let weight = weight as i128;
+        for (bit, total) in self.weights.iter_mut().enumerate() {
+            if (hash >> bit) & 1 == 1 {
+                *total += weight;
+            } else {
+                *total -= weight;
+            }
+        }
+    }
+
+    /// Compute the 64-bit SimHash fingerprint of the tokens added so far.
+    #[inline]
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        let mut fingerprint = 0;
+        for (bit, &total) in self.weights.iter().enumerate() {
+            if total > 0 {
+                fingerprint |= 1 << bit;
+            }
+        }
+        fingerprint
+    }
+}
+
+impl Default for RapidSimHash {
+    /// Create a new [RapidSimHash] with the default seed.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: AsRef<[u8]>> Extend<T> for RapidSimHash {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for token in iter {
+            self.add_token(token);
+        }
+    }
+}
+
+/// The number of differing bits between two fingerprints, such as those produced by
+/// [RapidSimHash].
+///
+/// # Example
+/// ```
+/// use rapidhash::hamming_distance;
+///
+/// assert_eq!(hamming_distance(0b1011, 0b0010), 2);
+/// ```
+#[inline(always)]
+#[must_use]
+pub const fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simhash(text: &str) -> u64 {
+        let mut simhash = RapidSimHash::new();
+        simhash.extend(text.split_whitespace());
+        simhash.fingerprint()
+    }
+
+    #[test]
+    fn similar_inputs_are_close() {
+        let base = "a b c d e f g h i j k l m n o p q r s t u v w x y z";
+        let edited = "a b c d e f g h i j k l m n o p q r s t u v w x y zz";
+        let other = "one two three four five six seven eight nine ten eleven twelve";
+
+        assert_eq!(simhash(base), simhash(base));
+        assert!(hamming_distance(simhash(base), simhash(edited)) < 12);
+        assert!(hamming_distance(simhash(base), simhash(other)) > hamming_distance(simhash(base), simhash(edited)));
+    }
+
+    #[test]
+    fn order_independent() {
+        assert_eq!(simhash("hello big world"), simhash("world hello big"));
+    }
+
+    #[test]
+    fn weights() {
+        let mut simhash = RapidSimHash::new();
+        simhash.add_weighted("hello", 3);
+        simhash.add_token("world");
+        simhash.add_weighted("world", -1);
+        assert_eq!(simhash.fingerprint(), crate::rapidhash(b"hello"));
+
+        assert_eq!(RapidSimHash::new().fingerprint(), 0);
+    }
+
+    #[test]
+    fn extreme_weights_dont_overflow() {
+        let mut simhash = RapidSimHash::new();
+        simhash.add_weighted("hello", i64::MAX);
+        simhash.add_weighted("hello", i64::MAX);
+        simhash.add_weighted("world", i64::MIN);
+        simhash.add_weighted("world", i64::MIN);
+        // the two i64::MIN votes for "world" outweigh the i64::MAX votes for "hello" by 2 per bit
+        assert_eq!(simhash.fingerprint(), !crate::rapidhash(b"world"));
+
+        // removing them again is exact
+        simhash.add_weighted("hello", -i64::MAX);
+        simhash.add_weighted("hello", -i64::MAX);
+        simhash.add_weighted("world", i64::MAX);
+        simhash.add_weighted("world", i64::MAX);
+        simhash.add_weighted("world", 2);
+        assert_eq!(simhash, RapidSimHash::new());
+    }
+}
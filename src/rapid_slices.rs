@@ -0,0 +1,92 @@
+use crate::rapid_const::RAPID_SEED;
+#[cfg(all(target_endian = "little", any(all(feature = "unsafe", not(miri)), feature = "bytemuck")))]
+use crate::rapid_const::rapidhash_inline;
+#[cfg(any(test, not(all(target_endian = "little", any(all(feature = "unsafe", not(miri)), feature = "bytemuck")))))]
+use crate::RapidStreamHasher;
+
+macro_rules! impl_int_slices {
+    ($ty:ty, $hash:ident, $hash_seeded:ident, $fallback:ident) => {
+        #[doc = concat!("Hash a slice of `", stringify!($ty), "` with the default seed, in one pass over its bytes.")]
+        ///
+        #[doc = concat!("Equal to [crate::rapidhash] of the little-endian bytes of each element. See [", stringify!($hash_seeded), "].")]
+        #[inline]
+        #[must_use]
+        pub fn $hash(data: &[$ty]) -> u64 {
+            $hash_seeded(data, RAPID_SEED)
+        }
+
+        #[doc = concat!("Hash a slice of `", stringify!($ty), "` with a custom seed, in one pass over its bytes.")]
+        ///
+        #[doc = concat!("Equal to [crate::rapidhash_seeded] of the little-endian bytes of each element, so the result is the same on every platform. The `Hash` impl for `[", stringify!($ty), "]` instead writes each element to the hasher separately, which is several times slower.")]
+        ///
+        /// On little-endian targets with the `unsafe` or `bytemuck` feature the slice's memory is
+        /// hashed directly. Otherwise the elements are converted to little-endian bytes in a small
+        /// stack buffer and streamed, without allocating.
+        #[inline]
+        #[must_use]
+        pub fn $hash_seeded(data: &[$ty], seed: u64) -> u64 {
+            #[cfg(all(target_endian = "little", feature = "unsafe", not(miri)))]
+            {
+                // SAFETY: integers have no padding or invalid bit patterns, `u8` has an alignment
+                // of 1, and the byte slice covers exactly the memory of `data` for its lifetime.
+                let bytes = unsafe {
+                    core::slice::from_raw_parts(data.as_ptr().cast::<u8>(), core::mem::size_of_val(data))
+                };
+                rapidhash_inline(bytes, seed)
+            }
+
+            #[cfg(all(target_endian = "little", feature = "bytemuck", not(all(feature = "unsafe", not(miri)))))]
+            {
+                rapidhash_inline(bytemuck::cast_slice(data), seed)
+            }
+
+            #[cfg(not(all(target_endian = "little", any(all(feature = "unsafe", not(miri)), feature = "bytemuck"))))]
+            {
+                $fallback(data, seed)
+            }
+        }
+
+        /// Portable fallback that converts each element to little-endian bytes.
+        #[cfg(any(test, not(all(target_endian = "little", any(all(feature = "unsafe", not(miri)), feature = "bytemuck")))))]
+        fn $fallback(data: &[$ty], seed: u64) -> u64 {
+            const WIDTH: usize = core::mem::size_of::<$ty>();
+            let mut hasher = RapidStreamHasher::new_seeded(core::mem::size_of_val(data) as u64, seed);
+            let mut buf = [0u8; 256];
+            for chunk in data.chunks(256 / WIDTH) {
+                for (out, value) in buf.chunks_exact_mut(WIDTH).zip(chunk) {
+                    out.copy_from_slice(&value.to_le_bytes());
+                }
+                hasher.write(&buf[..chunk.len() * WIDTH]);
+            }
+            hasher.finish()
+        }
+    };
+}
+
+impl_int_slices!(u32, rapidhash_u32s, rapidhash_u32s_seeded, rapidhash_u32s_portable);
+impl_int_slices!(u64, rapidhash_u64s, rapidhash_u64s_seeded, rapidhash_u64s_portable);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_slices_match_le_bytes() {
+        let values: [u32; 100] = core::array::from_fn(|i| (i as u32).wrapping_mul(0x9e37_79b9));
+        let mut bytes = [0u8; 400];
+        for (out, value) in bytes.chunks_exact_mut(4).zip(values) {
+            out.copy_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(rapidhash_u32s(&values), crate::rapidhash(&bytes));
+        assert_eq!(rapidhash_u32s_portable(&values, 7), crate::rapidhash_seeded(&bytes, 7));
+        assert_eq!(rapidhash_u32s(&[]), crate::rapidhash(&[]));
+
+        let values: [u64; 100] = core::array::from_fn(|i| (i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let mut bytes = [0u8; 800];
+        for (out, value) in bytes.chunks_exact_mut(8).zip(values) {
+            out.copy_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(rapidhash_u64s_seeded(&values, 7), crate::rapidhash_seeded(&bytes, 7));
+        assert_eq!(rapidhash_u64s_portable(&values, 7), crate::rapidhash_seeded(&bytes, 7));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::rapid_const::RAPID_SEED;
+use crate::rapid_core::{RapidCore, RAPID_BLOCK};
+
+/// A streaming rapidhash hasher that produces the same output as [crate::rapidhash_seeded],
+/// regardless of how the input is split across calls to [RapidStreamHasher::write].
+///
+/// [crate::RapidHasher] hashes each write separately, so its result depends on how the input is
+/// split. This hasher instead buffers input into 96-byte blocks and runs the exact same loop as the
+/// oneshot [crate::rapidhash] function, by driving a [crate::raw::RapidCore], so it gives the
+/// oneshot result over the concatenated bytes. This makes it suitable for checksumming files and
+/// streams in constant memory.
+///
+/// The rapidhash algorithm mixes the total input length into the seed before reading any data, and
+/// so the total length must be known up front when constructing the hasher.
+///
+/// # Example
+/// ```
+/// use rapidhash::{rapidhash, RapidStreamHasher};
+///
+/// let data = b"hello world, streamed in multiple chunks";
+///
+/// let mut hasher = RapidStreamHasher::new(data.len() as u64);
+/// for chunk in data.chunks(7) {
+///     hasher.write(chunk);
+/// }
+///
+/// assert_eq!(hasher.finish(), rapidhash(data));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct RapidStreamHasher {
+    core: RapidCore,
+    /// The number of bytes written so far.
+    written: u64,
+    /// Bytes that have not yet been absorbed as a full block.
+    buffer: [u8; RAPID_BLOCK],
+    buffered: usize,
+}
+
+impl RapidStreamHasher {
+    /// Create a new [RapidStreamHasher] using the default seed, for an input of exactly `len` bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new(len: u64) -> Self {
+        Self::new_seeded(len, RAPID_SEED)
+    }
+
+    /// Create a new [RapidStreamHasher] with a custom seed, for an input of exactly `len` bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new_seeded(len: u64, seed: u64) -> Self {
+        Self {
+            core: RapidCore::new(len, seed),
+            written: 0,
+            buffer: [0; RAPID_BLOCK],
+            buffered: 0,
+        }
+    }
+
+    /// The declared total length of the input.
+    #[inline]
+    pub const fn len(&self) -> u64 {
+        self.core.len()
+    }
+
+    /// Returns `true` if the declared total length of the input is zero.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.core.is_empty()
+    }
+
+    /// The number of bytes written to the hasher so far.
+    #[inline]
+    pub const fn written(&self) -> u64 {
+        self.written
+    }
+
+    /// Returns `true` once exactly the declared number of bytes have been written.
+    #[inline]
+    pub const fn is_complete(&self) -> bool {
+        self.written == self.core.len()
+    }
+
+    /// Write the next chunk of the input to the hasher.
+    ///
+    /// # Panics
+    /// Panics if more bytes are written than the length declared when constructing the hasher.
+    #[inline]
+    pub fn write(&mut self, mut bytes: &[u8]) {
+        assert!(
+            bytes.len() as u64 <= self.core.len() - self.written,
+            "RapidStreamHasher: wrote more bytes than the declared length",
+        );
+        self.written += bytes.len() as u64;
+
+        // top up a partially filled buffer first
+        if self.buffered > 0 {
+            let take = (RAPID_BLOCK - self.buffered).min(bytes.len());
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&bytes[..take]);
+            self.buffered += take;
+            bytes = &bytes[take..];
+
+            if self.buffered < RAPID_BLOCK {
+                return;
+            }
+
+            self.core.absorb_block(&self.buffer);
+            self.buffered = 0;
+        }
+
+        // process whole blocks directly from the input without copying
+        let mut blocks = bytes.chunks_exact(RAPID_BLOCK);
+        for block in &mut blocks {
+            self.core.absorb_block(block.try_into().unwrap());
+        }
+
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+    }
+
+    /// Compute the final hash, which is equal to [crate::rapidhash_seeded] of the full input.
+    ///
+    /// # Panics
+    /// Panics if fewer bytes have been written than the length declared when constructing the
+    /// hasher.
+    #[inline]
+    #[must_use]
+    pub fn finish(&self) -> u64 {
+        assert!(
+            self.is_complete(),
+            "RapidStreamHasher: finished before writing the declared length",
+        );
+
+        let mut core = self.core;
+        core.absorb_tail(&self.buffer[..self.buffered]);
+        core.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::rapidhash_seeded;
+
+    /// Check the streaming hasher matches the oneshot for every length and a variety of chunkings.
+    #[test]
+    fn streaming_equivalent_to_oneshot() {
+        let data: std::vec::Vec<u8> = (0..1024u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+
+        for len in 0..=data.len() {
+            let data = &data[..len];
+            let expected = rapidhash_seeded(data, 42);
+
+            for chunk_size in [1, 3, 16, 17, 48, 95, 96, 97, 200, 1024] {
+                let mut hasher = RapidStreamHasher::new_seeded(len as u64, 42);
+                for chunk in data.chunks(chunk_size) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(hasher.finish(), expected, "Mismatch for len {len}, chunk size {chunk_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn default_seed() {
+        let mut hasher = RapidStreamHasher::new(11);
+        hasher.write(b"hello world");
+        assert_eq!(hasher.finish(), crate::rapidhash(b"hello world"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_past_declared_length_panics() {
+        let mut hasher = RapidStreamHasher::new(4);
+        hasher.write(b"hello");
+    }
+
+    #[test]
+    #[should_panic]
+    fn finish_before_declared_length_panics() {
+        let mut hasher = RapidStreamHasher::new(8);
+        hasher.write(b"hello");
+        let _ = hasher.finish();
+    }
+}
@@ -0,0 +1,115 @@
+use core::hint::black_box;
+use crate::rapid_const::{RAPID_SECRET, RAPID_SEED};
+use crate::RapidStreamHasher;
+
+/// Hash `data` under a `domain` tag with the default seed, so that one hash space can back several
+/// logical tables without equal payloads colliding across them.
+///
+/// The domain is framed by its length before the data, so every `(domain, data)` pair is hashed as
+/// a distinct byte string: `("ab", "c")` and `("a", "bc")` never produce the same input. Two tagged
+/// hashes can therefore only collide with the usual probability of any two distinct inputs, and
+/// never because the same payload was hashed under different domains.
+///
+/// The result is equal to [crate::rapidhash] of the domain length as a little-endian `u64`, followed
+/// by the domain and the data, without allocating a combined buffer.
+///
+/// # Example
+/// ```
+/// use rapidhash::tagged_hash;
+///
+/// let user = tagged_hash(b"user", b"42");
+/// let order = tagged_hash(b"order", b"42");
+/// assert_ne!(user, order);
+/// ```
+#[inline]
+#[must_use]
+pub fn tagged_hash(domain: &[u8], data: &[u8]) -> u64 {
+    tagged_hash_seeded(domain, data, RAPID_SEED)
+}
+
+/// Hash `data` under a `domain` tag with a custom seed. See [tagged_hash] for more details.
+#[must_use]
+pub fn tagged_hash_seeded(domain: &[u8], data: &[u8], seed: u64) -> u64 {
+    let len = 8 + domain.len() as u64 + data.len() as u64;
+    let mut hasher = RapidStreamHasher::new_seeded(len, seed);
+    hasher.write(&(domain.len() as u64).to_le_bytes());
+    hasher.write(domain);
+    hasher.write(data);
+    hasher.finish()
+}
+
+/// Compute a 64-bit integrity tag of `data` under a secret `key`, to check later with
+/// [verify_keyed_tag].
+///
+/// This is an anti-accident check, not an anti-attacker one: it catches data that was corrupted,
+/// truncated, or paired with the wrong key, but rapidhash is not a MAC, and an attacker who can
+/// observe tags may be able to forge them. Use HMAC or another cryptographic MAC when the data
+/// comes from an untrusted source.
+///
+/// The key is framed before the data as in [tagged_hash], but under a separate seed, so a keyed
+/// tag never equals the tagged hash of the same key and data.
+///
+/// # Example
+/// ```
+/// use rapidhash::{keyed_tag, verify_keyed_tag};
+///
+/// let key = b"per-deployment secret";
+/// let tag = keyed_tag(key, b"payload");
+///
+/// assert!(verify_keyed_tag(key, b"payload", tag));
+/// assert!(!verify_keyed_tag(key, b"payloaf", tag));
+/// assert!(!verify_keyed_tag(b"another secret", b"payload", tag));
+/// ```
+#[inline]
+#[must_use]
+pub fn keyed_tag(key: &[u8], data: &[u8]) -> u64 {
+    tagged_hash_seeded(key, data, RAPID_SECRET[2])
+}
+
+/// Check a tag from [keyed_tag], comparing the tags in constant time.
+///
+/// Comparing tags derived from a secret with `==` may return early on the first differing byte,
+/// so the time taken could leak how much of a guessed tag was correct. This comparison takes the
+/// same time whether the tags match or not. See [keyed_tag] for what this does and doesn't
+/// protect against.
+#[must_use]
+pub fn verify_keyed_tag(key: &[u8], data: &[u8], tag: u64) -> bool {
+    let diff = black_box(keyed_tag(key, data) ^ tag);
+    (diff | diff.wrapping_neg()) >> 63 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+    use super::*;
+
+    #[test]
+    fn tagged_matches_framed_oneshot() {
+        let mut framed = Vec::new();
+        framed.extend_from_slice(&4u64.to_le_bytes());
+        framed.extend_from_slice(b"user");
+        framed.extend_from_slice(b"hello world");
+        assert_eq!(tagged_hash(b"user", b"hello world"), crate::rapidhash(&framed));
+        assert_eq!(tagged_hash_seeded(b"user", b"hello world", 7), crate::rapidhash_seeded(&framed, 7));
+    }
+
+    #[test]
+    fn domains_are_separated() {
+        assert_ne!(tagged_hash(b"ab", b"c"), tagged_hash(b"a", b"bc"));
+        assert_ne!(tagged_hash(b"", b"data"), tagged_hash(b"data", b""));
+        assert_ne!(tagged_hash(b"a", b""), crate::rapidhash(b""));
+    }
+
+    #[test]
+    fn keyed_tags_verify() {
+        let tag = keyed_tag(b"secret", b"data");
+        assert_ne!(tag, tagged_hash(b"secret", b"data"));
+        assert!(verify_keyed_tag(b"secret", b"data", tag));
+        assert!(!verify_keyed_tag(b"secret", b"data", tag ^ 1));
+        assert!(!verify_keyed_tag(b"secret", b"data", tag ^ (1 << 63)));
+        assert!(!verify_keyed_tag(b"secreT", b"data", tag));
+        assert!(!verify_keyed_tag(b"secret", b"", tag));
+    }
+}
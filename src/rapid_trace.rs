@@ -0,0 +1,205 @@
+use core::hash::{Hash, Hasher};
+use alloc::vec::Vec;
+use crate::rapid_const::rapidhash;
+use crate::RapidHasher;
+
+/// A single labelled checksum recorded by a [ChecksumTrace].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TraceEntry {
+    /// The frame the checksum was recorded in.
+    pub frame: u64,
+    /// The label passed to [ChecksumTrace::record].
+    pub label: &'static str,
+    /// The rapidhash of the recorded state.
+    pub hash: u64,
+}
+
+/// The first point at which two [ChecksumTrace]s differ, returned by
+/// [ChecksumTrace::first_divergence].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TraceDivergence {
+    /// The position of the first differing entry in both traces.
+    pub index: usize,
+    /// The entry in the first trace, or `None` if it ended early.
+    pub left: Option<TraceEntry>,
+    /// The entry in the second trace, or `None` if it ended early.
+    pub right: Option<TraceEntry>,
+}
+
+/// Records labelled checksums of simulation state, frame by frame, to find where two runs of a
+/// deterministic simulation diverged.
+///
+/// Lockstep networked games and deterministic replays run the same simulation on several machines,
+/// and a desync is typically only noticed frames after its cause. Recording a checksum of each
+/// subsystem's state every frame, then comparing the traces from two peers with
+/// [ChecksumTrace::first_divergence], points straight at the first frame and subsystem that
+/// differed.
+///
+/// State is hashed with its [Hash] implementation and [RapidHasher] using the default seed. For
+/// traces to be comparable between machines, the hashed types must hash identically on each, so
+/// avoid hashing `usize`, `isize`, or pointers when the peers' platforms differ.
+///
+/// Requires the `alloc` and `hasher` features.
+///
+/// # Example
+/// ```
+/// use rapidhash::ChecksumTrace;
+///
+/// let mut ours = ChecksumTrace::new();
+/// let mut theirs = ChecksumTrace::new();
+///
+/// for frame in 0..10u32 {
+///     ours.record("physics", &frame);
+///     ours.record("ai", &(frame * 2));
+///     ours.next_frame();
+///
+///     theirs.record("physics", &frame);
+///     theirs.record("ai", &(frame * 2 + (frame == 7) as u32));
+///     theirs.next_frame();
+/// }
+///
+/// let divergence = ours.first_divergence(&theirs).unwrap();
+/// let entry = divergence.left.unwrap();
+/// assert_eq!((entry.frame, entry.label), (7, "ai"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChecksumTrace {
+    frame: u64,
+    entries: Vec<TraceEntry>,
+}
+
+impl ChecksumTrace {
+    /// Create an empty trace starting at frame 0.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash `state` and record it under `label` in the current frame, returning the hash.
+    pub fn record<T: Hash + ?Sized>(&mut self, label: &'static str, state: &T) -> u64 {
+        let mut hasher = RapidHasher::default();
+        state.hash(&mut hasher);
+        self.push(label, hasher.finish())
+    }
+
+    /// Hash a byte slice with [crate::rapidhash] and record it under `label` in the current frame,
+    /// returning the hash.
+    pub fn record_bytes(&mut self, label: &'static str, bytes: &[u8]) -> u64 {
+        self.push(label, rapidhash(bytes))
+    }
+
+    #[inline]
+    fn push(&mut self, label: &'static str, hash: u64) -> u64 {
+        self.entries.push(TraceEntry { frame: self.frame, label, hash });
+        hash
+    }
+
+    /// Advance to the next frame.
+    #[inline]
+    pub fn next_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    /// The current frame number.
+    #[inline]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// All recorded entries, in the order they were recorded.
+    #[inline]
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    /// A single checksum of every entry recorded in `frame`, or `None` if the frame has no
+    /// entries.
+    ///
+    /// Peers can exchange these cheaply every frame to detect a desync, and only compare full
+    /// traces once one is found.
+    pub fn frame_checksum(&self, frame: u64) -> Option<u64> {
+        let start = self.entries.partition_point(|e| e.frame < frame);
+        let end = self.entries.partition_point(|e| e.frame <= frame);
+        if start == end {
+            return None;
+        }
+
+        let mut hasher = RapidHasher::default();
+        for entry in &self.entries[start..end] {
+            hasher.write(entry.label.as_bytes());
+            hasher.write_u64(entry.hash);
+        }
+        Some(hasher.finish())
+    }
+
+    /// Find the first entry at which this trace and `other` differ in frame, label, or hash.
+    ///
+    /// If one trace is a prefix of the other, the divergence is at the end of the shorter trace.
+    /// Returns `None` if both traces are identical.
+    pub fn first_divergence(&self, other: &Self) -> Option<TraceDivergence> {
+        let index = self.entries
+            .iter()
+            .zip(&other.entries)
+            .position(|(a, b)| a != b)
+            .unwrap_or(self.entries.len().min(other.entries.len()));
+
+        let left = self.entries.get(index).copied();
+        let right = other.entries.get(index).copied();
+        if left.is_none() && right.is_none() {
+            return None;
+        }
+        Some(TraceDivergence { index, left, right })
+    }
+
+    /// Remove all entries and reset to frame 0.
+    pub fn clear(&mut self) {
+        self.frame = 0;
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(frames: u64, bad_frame: u64) -> ChecksumTrace {
+        let mut trace = ChecksumTrace::new();
+        for frame in 0..frames {
+            trace.record("physics", &frame);
+            trace.record_bytes("net", &[frame as u8, (frame == bad_frame) as u8]);
+            trace.next_frame();
+        }
+        trace
+    }
+
+    #[test]
+    fn identical_traces() {
+        assert_eq!(trace(5, u64::MAX).first_divergence(&trace(5, u64::MAX)), None);
+        assert_eq!(trace(0, 0).first_divergence(&ChecksumTrace::new()), None);
+    }
+
+    #[test]
+    fn finds_first_divergence() {
+        let a = trace(10, u64::MAX);
+        let b = trace(10, 4);
+        let d = a.first_divergence(&b).unwrap();
+        assert_eq!(d.index, 9);
+        assert_eq!(d.left.unwrap().frame, 4);
+        assert_eq!(d.left.unwrap().label, "net");
+        assert_eq!(d.right.unwrap().label, "net");
+        assert_ne!(d.left.unwrap().hash, d.right.unwrap().hash);
+
+        assert_eq!(a.frame_checksum(3), b.frame_checksum(3));
+        assert_ne!(a.frame_checksum(4), b.frame_checksum(4));
+        assert_eq!(a.frame_checksum(10), None);
+    }
+
+    #[test]
+    fn prefix_divergence() {
+        let d = trace(3, u64::MAX).first_divergence(&trace(4, u64::MAX)).unwrap();
+        assert_eq!(d.index, 6);
+        assert_eq!(d.left, None);
+        assert_eq!(d.right.unwrap().frame, 3);
+    }
+}
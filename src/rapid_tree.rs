@@ -0,0 +1,174 @@
+use std::io;
+use std::path::Path;
+use std::vec::Vec;
+use crate::rapid_const::RAPID_SEED;
+use crate::{rapidhash_file_seeded, rapidhash_seeded};
+
+/// Tag byte for a file entry in a directory node.
+const TAG_FILE: u8 = b'f';
+
+/// Tag byte for a subdirectory entry in a directory node.
+const TAG_DIR: u8 = b'd';
+
+/// Tag byte for a symbolic link entry in a directory node.
+const TAG_LINK: u8 = b'l';
+
+/// Hash a whole directory tree into a single value with the default seed, so two machines can
+/// compare artifact trees by exchanging one `u64`.
+///
+/// See [rapidhash_dir_seeded] for the tree construction. Requires the `std` feature.
+///
+/// # Example
+/// ```no_run
+/// use rapidhash::rapidhash_dir;
+///
+/// let root = rapidhash_dir("target/release").unwrap();
+/// println!("{root:016x}");
+/// ```
+#[inline]
+pub fn rapidhash_dir(path: impl AsRef<Path>) -> io::Result<u64> {
+    rapidhash_dir_seeded(path, RAPID_SEED)
+}
+
+/// Hash a whole directory tree into a single value with a custom seed.
+///
+/// The tree is hashed bottom-up, Merkle style:
+///
+/// - A file's hash is [crate::rapidhash_file_seeded] of its contents.
+/// - A symbolic link's hash is [crate::rapidhash_seeded] of its UTF-8 target path, as returned by
+///   [std::fs::read_link]. Links are never followed, so a link to a directory isn't descended into
+///   and a link to an ancestor can't loop.
+/// - A directory's hash is [crate::rapidhash_seeded] of the concatenation of one record per entry,
+///   in ascending byte order of the entry names. Each record is a tag byte of `b'f'` for a file,
+///   `b'l'` for a symbolic link, or `b'd'` for a directory, the name's length as a little-endian
+///   `u64`, the UTF-8 name, and the entry's hash as a little-endian `u64`.
+/// - The result is the hash of the `path` directory itself.
+///
+/// The same seed is used at every level. Names are framed by their length, so no two distinct trees
+/// produce the same directory records. Empty directories are included, and file metadata such as
+/// permissions and timestamps is ignored. Renaming or moving a file changes the root, as does
+/// changing its contents or retargeting a link. `path` itself may be a link to a directory.
+///
+/// Returns an error if any entry can't be read or has a non-UTF-8 name or link target.
+pub fn rapidhash_dir_seeded(path: impl AsRef<Path>, seed: u64) -> io::Result<u64> {
+    hash_dir(path.as_ref(), seed)
+}
+
+fn hash_dir(dir: &Path, seed: u64) -> io::Result<u64> {
+    let mut children = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().into_string().map_err(|name| {
+            io::Error::new(io::ErrorKind::InvalidData, std::format!("non-UTF-8 file name {name:?}"))
+        })?;
+        children.push((name, entry.path(), entry.file_type()?));
+    }
+    children.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let mut records = Vec::new();
+    for (name, path, file_type) in children {
+        let (tag, hash) = if file_type.is_symlink() {
+            (TAG_LINK, hash_symlink(&path, seed)?)
+        } else if file_type.is_dir() {
+            (TAG_DIR, hash_dir(&path, seed)?)
+        } else {
+            (TAG_FILE, rapidhash_file_seeded(&path, seed)?)
+        };
+        records.push(tag);
+        records.extend_from_slice(&(name.len() as u64).to_le_bytes());
+        records.extend_from_slice(name.as_bytes());
+        records.extend_from_slice(&hash.to_le_bytes());
+    }
+    Ok(rapidhash_seeded(&records, seed))
+}
+
+/// Hash the target path of the symbolic link at `path`, without following it.
+pub(crate) fn hash_symlink(path: &Path, seed: u64) -> io::Result<u64> {
+    let target = std::fs::read_link(path)?.into_os_string().into_string().map_err(|target| {
+        io::Error::new(io::ErrorKind::InvalidData, std::format!("non-UTF-8 link target {target:?}"))
+    })?;
+    Ok(rapidhash_seeded(target.as_bytes(), seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(std::format!("rapidhash-tree-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn documented_construction() {
+        let root = temp_dir("construction");
+        std::fs::create_dir(root.join("sub")).unwrap();
+        std::fs::write(root.join("b.txt"), b"hello").unwrap();
+
+        let mut sub = Vec::new();
+        let empty = crate::rapidhash(&sub);
+
+        sub.push(b'f');
+        sub.extend_from_slice(&5u64.to_le_bytes());
+        sub.extend_from_slice(b"b.txt");
+        sub.extend_from_slice(&crate::rapidhash(b"hello").to_le_bytes());
+        sub.push(b'd');
+        sub.extend_from_slice(&3u64.to_le_bytes());
+        sub.extend_from_slice(b"sub");
+        sub.extend_from_slice(&empty.to_le_bytes());
+
+        assert_eq!(rapidhash_dir(&root).unwrap(), crate::rapidhash(&sub));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn detects_changes() {
+        let root = temp_dir("changes");
+        std::fs::create_dir(root.join("a")).unwrap();
+        std::fs::write(root.join("a/x"), b"1").unwrap();
+        let original = rapidhash_dir(&root).unwrap();
+        assert_eq!(rapidhash_dir(&root).unwrap(), original);
+        assert_ne!(rapidhash_dir_seeded(&root, 1).unwrap(), original);
+
+        std::fs::write(root.join("a/x"), b"2").unwrap();
+        assert_ne!(rapidhash_dir(&root).unwrap(), original);
+
+        // moving a file into a differently named directory changes the root
+        std::fs::write(root.join("a/x"), b"1").unwrap();
+        std::fs::rename(root.join("a"), root.join("b")).unwrap();
+        assert_ne!(rapidhash_dir(&root).unwrap(), original);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn hashes_symlinks_without_following() {
+        let root = temp_dir("symlinks");
+        std::fs::create_dir(root.join("sub")).unwrap();
+        std::os::unix::fs::symlink("..", root.join("sub/up")).unwrap();
+
+        // a link to an ancestor is a single record of its target, rather than a loop
+        let mut sub = Vec::new();
+        sub.push(b'l');
+        sub.extend_from_slice(&2u64.to_le_bytes());
+        sub.extend_from_slice(b"up");
+        sub.extend_from_slice(&crate::rapidhash(b"..").to_le_bytes());
+        let mut records = Vec::new();
+        records.push(b'd');
+        records.extend_from_slice(&3u64.to_le_bytes());
+        records.extend_from_slice(b"sub");
+        records.extend_from_slice(&crate::rapidhash(&sub).to_le_bytes());
+        let original = rapidhash_dir(&root).unwrap();
+        assert_eq!(original, crate::rapidhash(&records));
+
+        // retargeting the link changes the root
+        std::fs::remove_file(root.join("sub/up")).unwrap();
+        std::os::unix::fs::symlink(".", root.join("sub/up")).unwrap();
+        assert_ne!(rapidhash_dir(&root).unwrap(), original);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+use crate::rapid_const::{RAPID_SECRET, RAPID_SEED};
+use crate::RapidStreamHasher;
+
+/// Derive a deterministic, name-based UUID version 8 from a 16-byte namespace and a name.
+///
+/// This is a faster alternative to UUIDv5, which hashes the same inputs with SHA-1. The same
+/// namespace and name always produce the same UUID, across platforms and versions of this crate.
+///
+/// The 128 bits are two [crate::rapidhash_seeded] hashes of the namespace followed by the name,
+/// with the default seed and with `RAPID_SECRET[2]`, stored big-endian. The version and variant
+/// bits are then overwritten as required by RFC 9562, leaving 122 hash bits. As rapidhash is not a
+/// cryptographic hash, these UUIDs must not be relied on to hide the name or resist deliberate
+/// collisions.
+///
+/// Use [rapidhash_uuid] with the `uuid` feature to get a `uuid::Uuid` directly.
+///
+/// # Example
+/// ```
+/// use rapidhash::rapidhash_uuid_v8;
+///
+/// // the RFC 9562 DNS namespace
+/// let dns = 0x6ba7b810_9dad_11d1_80b4_00c04fd430c8u128.to_be_bytes();
+///
+/// let uuid = rapidhash_uuid_v8(dns, b"example.com");
+/// assert_eq!(uuid, rapidhash_uuid_v8(dns, b"example.com"));
+/// assert_eq!(uuid[6] >> 4, 8); // version 8
+/// assert_eq!(uuid[8] >> 6, 0b10); // RFC 9562 variant
+/// ```
+#[must_use]
+pub fn rapidhash_uuid_v8(namespace: [u8; 16], name: &[u8]) -> [u8; 16] {
+    let hash = |seed| {
+        let mut hasher = RapidStreamHasher::new_seeded(16 + name.len() as u64, seed);
+        hasher.write(&namespace);
+        hasher.write(name);
+        hasher.finish()
+    };
+
+    let mut bytes = [0u8; 16];
+    bytes[..8].copy_from_slice(&hash(RAPID_SEED).to_be_bytes());
+    bytes[8..].copy_from_slice(&hash(RAPID_SECRET[2]).to_be_bytes());
+
+    bytes[6] = (bytes[6] & 0x0f) | 0x80;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    bytes
+}
+
+/// Derive a deterministic, name-based UUID version 8 from a namespace UUID and a name.
+///
+/// Equivalent to [rapidhash_uuid_v8], for use with the [uuid] crate.
+///
+/// Requires the `uuid` feature.
+///
+/// # Example
+/// ```
+/// use rapidhash::rapidhash_uuid;
+/// use uuid::Uuid;
+///
+/// let id = rapidhash_uuid(&Uuid::NAMESPACE_URL, b"https://example.com");
+/// assert_eq!(id.get_version_num(), 8);
+/// ```
+#[cfg(any(feature = "uuid", docsrs))]
+#[inline]
+#[must_use]
+pub fn rapidhash_uuid(namespace: &uuid::Uuid, name: &[u8]) -> uuid::Uuid {
+    uuid::Uuid::from_bytes(rapidhash_uuid_v8(*namespace.as_bytes(), name))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+    use super::*;
+
+    #[test]
+    fn uuid_v8_layout() {
+        let namespace = [7u8; 16];
+        let uuid = rapidhash_uuid_v8(namespace, b"hello world");
+
+        let mut input = Vec::from(namespace);
+        input.extend_from_slice(b"hello world");
+        let high = crate::rapidhash(&input).to_be_bytes();
+        let low = crate::rapidhash_seeded(&input, RAPID_SECRET[2]).to_be_bytes();
+
+        assert_eq!(uuid[..6], high[..6]);
+        assert_eq!(uuid[6], (high[6] & 0x0f) | 0x80);
+        assert_eq!(uuid[7], high[7]);
+        assert_eq!(uuid[8], (low[0] & 0x3f) | 0x80);
+        assert_eq!(uuid[9..], low[1..]);
+
+        assert_ne!(uuid, rapidhash_uuid_v8(namespace, b"hello world!"));
+        assert_ne!(uuid, rapidhash_uuid_v8([8u8; 16], b"hello world"));
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_crate() {
+        let id = rapidhash_uuid(&uuid::Uuid::NAMESPACE_DNS, b"example.com");
+        assert_eq!(id.get_version(), Some(uuid::Version::Custom));
+        assert_eq!(id.get_variant(), uuid::Variant::RFC4122);
+        assert_eq!(*id.as_bytes(), rapidhash_uuid_v8(*uuid::Uuid::NAMESPACE_DNS.as_bytes(), b"example.com"));
+    }
+}
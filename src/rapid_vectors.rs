@@ -0,0 +1,529 @@
+use crate::rapid_const::rapidhash_inline;
+
+/// The longest input covered by [REFERENCE_VECTORS].
+const MAX_LEN: usize = 1024;
+
+/// A reference test vector: the expected hash of the first `len` bytes of the
+/// [test_vector_input] pattern with the given seed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TestVector {
+    /// The input length in bytes.
+    pub len: usize,
+    /// The seed passed to [crate::rapidhash_seeded].
+    pub seed: u64,
+    /// The expected hash.
+    pub hash: u64,
+}
+
+/// A test vector that produced a different hash on this target.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct VectorMismatch {
+    /// The test vector that failed.
+    pub vector: TestVector,
+    /// The hash computed on this target.
+    pub actual: u64,
+}
+
+impl core::fmt::Display for VectorMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "rapidhash test vector mismatch for len {} and seed {:#018x}: expected {:#018x}, got {:#018x}",
+            self.vector.len, self.vector.seed, self.vector.hash, self.actual,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VectorMismatch {}
+
+/// The input byte at index `i` for the [REFERENCE_VECTORS], `(i * 31 + 7) % 251`.
+///
+/// The pattern is simple to reproduce in other languages, and does not repeat on any power-of-two
+/// boundary, so every read in the algorithm sees distinct data.
+#[inline]
+#[must_use]
+pub const fn test_vector_input(i: usize) -> u8 {
+    ((i * 31 + 7) % 251) as u8
+}
+
+/// Verify this build of rapidhash produces the expected output for every reference test vector,
+/// returning the first mismatch found.
+///
+/// This is intended for ports, cross-compiled targets such as big-endian platforms or wasm, and
+/// alternative feature sets such as `unsafe`, to prove byte-for-byte compatibility with the C
+/// reference at runtime. See [REFERENCE_VECTORS] for what the vectors cover.
+///
+/// Requires the `test-vectors` feature.
+///
+/// # Example
+/// ```
+/// rapidhash::verify_reference_vectors().expect("rapidhash is not compatible on this target");
+/// ```
+pub fn verify_reference_vectors() -> Result<(), VectorMismatch> {
+    let mut input = [0u8; MAX_LEN];
+    for (i, byte) in input.iter_mut().enumerate() {
+        *byte = test_vector_input(i);
+    }
+
+    for &vector in REFERENCE_VECTORS {
+        let actual = rapidhash_inline(&input[..vector.len], vector.seed);
+        if actual != vector.hash {
+            return Err(VectorMismatch { vector, actual });
+        }
+    }
+
+    Ok(())
+}
+
+/// The reference test vectors, covering every input length from 0 to 128 bytes except 48, and the
+/// boundaries of the 96-byte loop up to 1024 bytes, for the seeds `0`, [crate::RAPID_SEED], and
+/// `0x0123456789abcdef`. This crate only hashes with the default [crate::RAPID_SECRET], so every
+/// vector uses those secrets.
+///
+/// The inputs are the first `len` bytes of the [test_vector_input] pattern. The hashes are the
+/// rapidhash V1 output of upstream `rapidhash.h`, generated with the C-compatible V1 implementation
+/// of the rapidhash crate at version 4.5.1 (git `7eb34028b77ea945c005c5e089068e31de14939b`), which
+/// is tested against `rapidhash.h` in that crate. This crate matches every vector.
+///
+/// Inputs of exactly 48 bytes are left out, as this crate's hash of them differs from the C
+/// reference: it runs them through the 48-byte block of the bulk loop, where `rapidhash.h` only
+/// enters the loop for inputs longer than 48 bytes.
+pub const REFERENCE_VECTORS: &[TestVector] = &[
+    TestVector { len: 0, seed: 0x0000000000000000, hash: 0x93228a4de0eec5a2 },
+    TestVector { len: 1, seed: 0x0000000000000000, hash: 0x106146ca51157985 },
+    TestVector { len: 2, seed: 0x0000000000000000, hash: 0x1bd74640a2c26e95 },
+    TestVector { len: 3, seed: 0x0000000000000000, hash: 0xe5f638172e38cc56 },
+    TestVector { len: 4, seed: 0x0000000000000000, hash: 0xc85f6bd0231b3846 },
+    TestVector { len: 5, seed: 0x0000000000000000, hash: 0xd68a6b63ae4ae97c },
+    TestVector { len: 6, seed: 0x0000000000000000, hash: 0x1e6c5008ce280606 },
+    TestVector { len: 7, seed: 0x0000000000000000, hash: 0x2e27f022573e697c },
+    TestVector { len: 8, seed: 0x0000000000000000, hash: 0xcef73da0e41a738c },
+    TestVector { len: 9, seed: 0x0000000000000000, hash: 0x7d694c6307297fad },
+    TestVector { len: 10, seed: 0x0000000000000000, hash: 0x09073e7b7c5940aa },
+    TestVector { len: 11, seed: 0x0000000000000000, hash: 0xff860abffa26fc39 },
+    TestVector { len: 12, seed: 0x0000000000000000, hash: 0x92faa113184f9d59 },
+    TestVector { len: 13, seed: 0x0000000000000000, hash: 0x6bd33571cc064df1 },
+    TestVector { len: 14, seed: 0x0000000000000000, hash: 0x0eb9f551272931e3 },
+    TestVector { len: 15, seed: 0x0000000000000000, hash: 0x3ee5a2bb2a2d17b6 },
+    TestVector { len: 16, seed: 0x0000000000000000, hash: 0xb4a41d4c268e345a },
+    TestVector { len: 17, seed: 0x0000000000000000, hash: 0xe636815621f4abd1 },
+    TestVector { len: 18, seed: 0x0000000000000000, hash: 0x137493704e7a27b0 },
+    TestVector { len: 19, seed: 0x0000000000000000, hash: 0x1fba39992c1c27a5 },
+    TestVector { len: 20, seed: 0x0000000000000000, hash: 0xf4c3562c0fe243c7 },
+    TestVector { len: 21, seed: 0x0000000000000000, hash: 0x447be2b702a97de5 },
+    TestVector { len: 22, seed: 0x0000000000000000, hash: 0xe7c0a8f318d5fbd8 },
+    TestVector { len: 23, seed: 0x0000000000000000, hash: 0x7d051cd9e8394157 },
+    TestVector { len: 24, seed: 0x0000000000000000, hash: 0x9b04e59e436b36d2 },
+    TestVector { len: 25, seed: 0x0000000000000000, hash: 0x45c0628ceb2c59ae },
+    TestVector { len: 26, seed: 0x0000000000000000, hash: 0x53479360bea4a100 },
+    TestVector { len: 27, seed: 0x0000000000000000, hash: 0x8bc697db1c9ba237 },
+    TestVector { len: 28, seed: 0x0000000000000000, hash: 0xf4f525b532a3cee5 },
+    TestVector { len: 29, seed: 0x0000000000000000, hash: 0x5e1d8d693d3efc26 },
+    TestVector { len: 30, seed: 0x0000000000000000, hash: 0x1e3cc87e8f7dc260 },
+    TestVector { len: 31, seed: 0x0000000000000000, hash: 0x8d1f4caa541997af },
+    TestVector { len: 32, seed: 0x0000000000000000, hash: 0xfd3371568d710fb8 },
+    TestVector { len: 33, seed: 0x0000000000000000, hash: 0x5a69caa1b2ab3832 },
+    TestVector { len: 34, seed: 0x0000000000000000, hash: 0xbaadf81cee2f0e50 },
+    TestVector { len: 35, seed: 0x0000000000000000, hash: 0x71053c5691899762 },
+    TestVector { len: 36, seed: 0x0000000000000000, hash: 0xbc0e8f11d1d15995 },
+    TestVector { len: 37, seed: 0x0000000000000000, hash: 0x1cf6c54567397f02 },
+    TestVector { len: 38, seed: 0x0000000000000000, hash: 0x5f1f31f773ecbc85 },
+    TestVector { len: 39, seed: 0x0000000000000000, hash: 0xe2f9be1f24d3c8d9 },
+    TestVector { len: 40, seed: 0x0000000000000000, hash: 0x9eb7f28b46696438 },
+    TestVector { len: 41, seed: 0x0000000000000000, hash: 0xa831a9b962e98af8 },
+    TestVector { len: 42, seed: 0x0000000000000000, hash: 0xdae595eed42ab168 },
+    TestVector { len: 43, seed: 0x0000000000000000, hash: 0x148a9faad4957bc5 },
+    TestVector { len: 44, seed: 0x0000000000000000, hash: 0x587e63889315107d },
+    TestVector { len: 45, seed: 0x0000000000000000, hash: 0xd22cc649ad88cc4e },
+    TestVector { len: 46, seed: 0x0000000000000000, hash: 0x89f84f2d7d2628db },
+    TestVector { len: 47, seed: 0x0000000000000000, hash: 0xa4410bd52731372d },
+    TestVector { len: 49, seed: 0x0000000000000000, hash: 0x0c6548e314b1efb7 },
+    TestVector { len: 50, seed: 0x0000000000000000, hash: 0xdeddbc80900431f5 },
+    TestVector { len: 51, seed: 0x0000000000000000, hash: 0x499cd76e7b0a0208 },
+    TestVector { len: 52, seed: 0x0000000000000000, hash: 0xaea309a21647b1e4 },
+    TestVector { len: 53, seed: 0x0000000000000000, hash: 0xe6f4d0c4970d0c7a },
+    TestVector { len: 54, seed: 0x0000000000000000, hash: 0xac65f942946d7d2c },
+    TestVector { len: 55, seed: 0x0000000000000000, hash: 0x403dfdfe9d1a9fd2 },
+    TestVector { len: 56, seed: 0x0000000000000000, hash: 0x53daeff612a4f47e },
+    TestVector { len: 57, seed: 0x0000000000000000, hash: 0x8657ef2b894026ef },
+    TestVector { len: 58, seed: 0x0000000000000000, hash: 0x8d56b4f1d40fddcb },
+    TestVector { len: 59, seed: 0x0000000000000000, hash: 0xe5ee8c9c95c0c4d1 },
+    TestVector { len: 60, seed: 0x0000000000000000, hash: 0xa676d565b0a47161 },
+    TestVector { len: 61, seed: 0x0000000000000000, hash: 0x6ed18ba0f8b6aeee },
+    TestVector { len: 62, seed: 0x0000000000000000, hash: 0x2ee5493a3d828eed },
+    TestVector { len: 63, seed: 0x0000000000000000, hash: 0xc2d4c9822c088255 },
+    TestVector { len: 64, seed: 0x0000000000000000, hash: 0x7a22f4c043aa4799 },
+    TestVector { len: 65, seed: 0x0000000000000000, hash: 0x4c8e7eb4c4781071 },
+    TestVector { len: 66, seed: 0x0000000000000000, hash: 0x714f6290fabb1940 },
+    TestVector { len: 67, seed: 0x0000000000000000, hash: 0xec8ed6d1a1404027 },
+    TestVector { len: 68, seed: 0x0000000000000000, hash: 0xe19de4b2b5ea75fe },
+    TestVector { len: 69, seed: 0x0000000000000000, hash: 0xbd2dade1f51a5ca2 },
+    TestVector { len: 70, seed: 0x0000000000000000, hash: 0xd7c9f94e81dc07b4 },
+    TestVector { len: 71, seed: 0x0000000000000000, hash: 0x4ca6b41d83eb25a1 },
+    TestVector { len: 72, seed: 0x0000000000000000, hash: 0xb4279a61a220fb26 },
+    TestVector { len: 73, seed: 0x0000000000000000, hash: 0x4b50e5bf6fb24614 },
+    TestVector { len: 74, seed: 0x0000000000000000, hash: 0xbdde656de4c91616 },
+    TestVector { len: 75, seed: 0x0000000000000000, hash: 0x1d2ed667f06bbadf },
+    TestVector { len: 76, seed: 0x0000000000000000, hash: 0x93663c8d4e90eb70 },
+    TestVector { len: 77, seed: 0x0000000000000000, hash: 0xe3f6cb86f6d8fa5c },
+    TestVector { len: 78, seed: 0x0000000000000000, hash: 0x5368bdd4cad62a4f },
+    TestVector { len: 79, seed: 0x0000000000000000, hash: 0x05ba5e96723065e1 },
+    TestVector { len: 80, seed: 0x0000000000000000, hash: 0x84bb3aa179f95b0b },
+    TestVector { len: 81, seed: 0x0000000000000000, hash: 0xd257f6f2cac9f4e1 },
+    TestVector { len: 82, seed: 0x0000000000000000, hash: 0x5e0267b95416d69b },
+    TestVector { len: 83, seed: 0x0000000000000000, hash: 0xcbea9858f2b94707 },
+    TestVector { len: 84, seed: 0x0000000000000000, hash: 0x0499f01f196d8062 },
+    TestVector { len: 85, seed: 0x0000000000000000, hash: 0x45c8dc024f1fadaf },
+    TestVector { len: 86, seed: 0x0000000000000000, hash: 0x56fc2713baa0d96e },
+    TestVector { len: 87, seed: 0x0000000000000000, hash: 0x42865e06f62c18ac },
+    TestVector { len: 88, seed: 0x0000000000000000, hash: 0x5d2c5b9d392b7d58 },
+    TestVector { len: 89, seed: 0x0000000000000000, hash: 0x48a1ca256d01d733 },
+    TestVector { len: 90, seed: 0x0000000000000000, hash: 0x8188ac23c6183d53 },
+    TestVector { len: 91, seed: 0x0000000000000000, hash: 0x6915de9a66113dca },
+    TestVector { len: 92, seed: 0x0000000000000000, hash: 0x500827c03a07d798 },
+    TestVector { len: 93, seed: 0x0000000000000000, hash: 0x90a8e34eabf9efe8 },
+    TestVector { len: 94, seed: 0x0000000000000000, hash: 0x273a0a4f202a51e5 },
+    TestVector { len: 95, seed: 0x0000000000000000, hash: 0x24fcbb9c0bed267a },
+    TestVector { len: 96, seed: 0x0000000000000000, hash: 0xcc0560477870a0dd },
+    TestVector { len: 97, seed: 0x0000000000000000, hash: 0x84210f4e6bbbd3a6 },
+    TestVector { len: 98, seed: 0x0000000000000000, hash: 0x8da06bbf769c2e31 },
+    TestVector { len: 99, seed: 0x0000000000000000, hash: 0x7ab710647de711f1 },
+    TestVector { len: 100, seed: 0x0000000000000000, hash: 0x05af55871dc554eb },
+    TestVector { len: 101, seed: 0x0000000000000000, hash: 0x90fd9e5a414521ff },
+    TestVector { len: 102, seed: 0x0000000000000000, hash: 0x6988d32561c1d75d },
+    TestVector { len: 103, seed: 0x0000000000000000, hash: 0x71c04511518e4b3a },
+    TestVector { len: 104, seed: 0x0000000000000000, hash: 0xfdbd8d2bc503ef20 },
+    TestVector { len: 105, seed: 0x0000000000000000, hash: 0xf18b476c81a7645f },
+    TestVector { len: 106, seed: 0x0000000000000000, hash: 0x9a9d4eb865972d3a },
+    TestVector { len: 107, seed: 0x0000000000000000, hash: 0x6459d21b08f44848 },
+    TestVector { len: 108, seed: 0x0000000000000000, hash: 0x14b2663273dfaa4e },
+    TestVector { len: 109, seed: 0x0000000000000000, hash: 0x944fd572c2f85577 },
+    TestVector { len: 110, seed: 0x0000000000000000, hash: 0x5c95fce23d5b27b5 },
+    TestVector { len: 111, seed: 0x0000000000000000, hash: 0xc9a2486e2ed0a94b },
+    TestVector { len: 112, seed: 0x0000000000000000, hash: 0xcb7a00ca86657cdd },
+    TestVector { len: 113, seed: 0x0000000000000000, hash: 0x9ecb1539d813c04f },
+    TestVector { len: 114, seed: 0x0000000000000000, hash: 0x49e1c02c59e0bfa1 },
+    TestVector { len: 115, seed: 0x0000000000000000, hash: 0x1460387f38d95f1b },
+    TestVector { len: 116, seed: 0x0000000000000000, hash: 0xf75089db095158c1 },
+    TestVector { len: 117, seed: 0x0000000000000000, hash: 0x290166a4ac089b7c },
+    TestVector { len: 118, seed: 0x0000000000000000, hash: 0x79b11023d785c132 },
+    TestVector { len: 119, seed: 0x0000000000000000, hash: 0xd8d7c54bbb24ce8d },
+    TestVector { len: 120, seed: 0x0000000000000000, hash: 0x78789f34eb4ad6b5 },
+    TestVector { len: 121, seed: 0x0000000000000000, hash: 0x1bcdff7d7a958013 },
+    TestVector { len: 122, seed: 0x0000000000000000, hash: 0x2fe2268a354474cf },
+    TestVector { len: 123, seed: 0x0000000000000000, hash: 0xe3b560c5d35176d2 },
+    TestVector { len: 124, seed: 0x0000000000000000, hash: 0xb5269d7c3487ec88 },
+    TestVector { len: 125, seed: 0x0000000000000000, hash: 0xc1f3063a6930e9eb },
+    TestVector { len: 126, seed: 0x0000000000000000, hash: 0x2559003a51084288 },
+    TestVector { len: 127, seed: 0x0000000000000000, hash: 0x8a036bfaf9c530cc },
+    TestVector { len: 128, seed: 0x0000000000000000, hash: 0x09792bc7c450bcab },
+    TestVector { len: 191, seed: 0x0000000000000000, hash: 0x9986ac74eef08c8b },
+    TestVector { len: 192, seed: 0x0000000000000000, hash: 0xe3797c808ba93b02 },
+    TestVector { len: 193, seed: 0x0000000000000000, hash: 0x34d0aa380bb4aede },
+    TestVector { len: 255, seed: 0x0000000000000000, hash: 0x6dd7502bacb84ee9 },
+    TestVector { len: 256, seed: 0x0000000000000000, hash: 0x04f49a296088a302 },
+    TestVector { len: 257, seed: 0x0000000000000000, hash: 0x4529c91bb3d238f1 },
+    TestVector { len: 511, seed: 0x0000000000000000, hash: 0x8fe901356f3c8aaf },
+    TestVector { len: 512, seed: 0x0000000000000000, hash: 0x406b3bc6ff3e8d14 },
+    TestVector { len: 513, seed: 0x0000000000000000, hash: 0x184e1eeb1f291b46 },
+    TestVector { len: 1000, seed: 0x0000000000000000, hash: 0xb121513c9d07e99c },
+    TestVector { len: 1024, seed: 0x0000000000000000, hash: 0x17078638a5756c5b },
+    TestVector { len: 0, seed: 0xbdd89aa982704029, hash: 0x5a6ef77074ebc84b },
+    TestVector { len: 1, seed: 0xbdd89aa982704029, hash: 0x36a38166cb003f1a },
+    TestVector { len: 2, seed: 0xbdd89aa982704029, hash: 0xcb8139d7506b20ea },
+    TestVector { len: 3, seed: 0xbdd89aa982704029, hash: 0xf93522f653a26e6d },
+    TestVector { len: 4, seed: 0xbdd89aa982704029, hash: 0x136502758e16367a },
+    TestVector { len: 5, seed: 0xbdd89aa982704029, hash: 0xab6a01aa257ec82f },
+    TestVector { len: 6, seed: 0xbdd89aa982704029, hash: 0xd9fc6a8e8cac2e48 },
+    TestVector { len: 7, seed: 0xbdd89aa982704029, hash: 0xcf5b2e1d28035085 },
+    TestVector { len: 8, seed: 0xbdd89aa982704029, hash: 0xd228db580539de36 },
+    TestVector { len: 9, seed: 0xbdd89aa982704029, hash: 0x2b24e31d1bc8f8e9 },
+    TestVector { len: 10, seed: 0xbdd89aa982704029, hash: 0xd642af9142d08770 },
+    TestVector { len: 11, seed: 0xbdd89aa982704029, hash: 0xbe4040b5e1d2eb2a },
+    TestVector { len: 12, seed: 0xbdd89aa982704029, hash: 0xc465490e4c95dda0 },
+    TestVector { len: 13, seed: 0xbdd89aa982704029, hash: 0xcb70dff18f6ea05b },
+    TestVector { len: 14, seed: 0xbdd89aa982704029, hash: 0x96d5476ef8ddb33c },
+    TestVector { len: 15, seed: 0xbdd89aa982704029, hash: 0x17abf45f2b07a2a3 },
+    TestVector { len: 16, seed: 0xbdd89aa982704029, hash: 0xf5cd9f00f08901e3 },
+    TestVector { len: 17, seed: 0xbdd89aa982704029, hash: 0x2f57d21c0ffabe62 },
+    TestVector { len: 18, seed: 0xbdd89aa982704029, hash: 0x4e0f1d653c11f9fd },
+    TestVector { len: 19, seed: 0xbdd89aa982704029, hash: 0x92c9d7edb8c50e7e },
+    TestVector { len: 20, seed: 0xbdd89aa982704029, hash: 0x8a3403a37e10bb8c },
+    TestVector { len: 21, seed: 0xbdd89aa982704029, hash: 0x1a8a38283cd1ecc4 },
+    TestVector { len: 22, seed: 0xbdd89aa982704029, hash: 0xa97b0df9c46a7813 },
+    TestVector { len: 23, seed: 0xbdd89aa982704029, hash: 0x8ce34bdf056baf9f },
+    TestVector { len: 24, seed: 0xbdd89aa982704029, hash: 0x0ce2c4d3d7fea1ea },
+    TestVector { len: 25, seed: 0xbdd89aa982704029, hash: 0x335407944365d7db },
+    TestVector { len: 26, seed: 0xbdd89aa982704029, hash: 0x047fcc4134c8d36f },
+    TestVector { len: 27, seed: 0xbdd89aa982704029, hash: 0xda4af7d297b3883b },
+    TestVector { len: 28, seed: 0xbdd89aa982704029, hash: 0x60c82f563194f220 },
+    TestVector { len: 29, seed: 0xbdd89aa982704029, hash: 0xad8b2c359ee807fb },
+    TestVector { len: 30, seed: 0xbdd89aa982704029, hash: 0x2628732ede22f7d0 },
+    TestVector { len: 31, seed: 0xbdd89aa982704029, hash: 0xa2f81abb0f17d03d },
+    TestVector { len: 32, seed: 0xbdd89aa982704029, hash: 0x9e3556ccfca34704 },
+    TestVector { len: 33, seed: 0xbdd89aa982704029, hash: 0xa4fd66146ad8c239 },
+    TestVector { len: 34, seed: 0xbdd89aa982704029, hash: 0x58551763624f7114 },
+    TestVector { len: 35, seed: 0xbdd89aa982704029, hash: 0xda840cf15e23540e },
+    TestVector { len: 36, seed: 0xbdd89aa982704029, hash: 0x5f90056f858bdac9 },
+    TestVector { len: 37, seed: 0xbdd89aa982704029, hash: 0xd29d439bcf5612b7 },
+    TestVector { len: 38, seed: 0xbdd89aa982704029, hash: 0xa0afff57f1662e2d },
+    TestVector { len: 39, seed: 0xbdd89aa982704029, hash: 0x181ef2a350741400 },
+    TestVector { len: 40, seed: 0xbdd89aa982704029, hash: 0xc84ca0facd49df68 },
+    TestVector { len: 41, seed: 0xbdd89aa982704029, hash: 0xbfedd6db194cb48b },
+    TestVector { len: 42, seed: 0xbdd89aa982704029, hash: 0xfbeaf36cd2e8d147 },
+    TestVector { len: 43, seed: 0xbdd89aa982704029, hash: 0xbd5084f083052911 },
+    TestVector { len: 44, seed: 0xbdd89aa982704029, hash: 0xcb76c0b16c027b7f },
+    TestVector { len: 45, seed: 0xbdd89aa982704029, hash: 0x94613e116842adb4 },
+    TestVector { len: 46, seed: 0xbdd89aa982704029, hash: 0x1195368f596979a2 },
+    TestVector { len: 47, seed: 0xbdd89aa982704029, hash: 0xe179a804beea5831 },
+    TestVector { len: 49, seed: 0xbdd89aa982704029, hash: 0xc8d80f3891c87404 },
+    TestVector { len: 50, seed: 0xbdd89aa982704029, hash: 0x0c3f75142b8c4390 },
+    TestVector { len: 51, seed: 0xbdd89aa982704029, hash: 0x182c6db9fafc486f },
+    TestVector { len: 52, seed: 0xbdd89aa982704029, hash: 0xa7ae88d7cdddb008 },
+    TestVector { len: 53, seed: 0xbdd89aa982704029, hash: 0x30489b649b1814f9 },
+    TestVector { len: 54, seed: 0xbdd89aa982704029, hash: 0x9ebb536db4791f39 },
+    TestVector { len: 55, seed: 0xbdd89aa982704029, hash: 0xd6f455a8cab5f537 },
+    TestVector { len: 56, seed: 0xbdd89aa982704029, hash: 0xa9ede31d431d4e34 },
+    TestVector { len: 57, seed: 0xbdd89aa982704029, hash: 0x658a5929f8c352c8 },
+    TestVector { len: 58, seed: 0xbdd89aa982704029, hash: 0x3759a6ce3254230f },
+    TestVector { len: 59, seed: 0xbdd89aa982704029, hash: 0x8a56e5c345c6a8c7 },
+    TestVector { len: 60, seed: 0xbdd89aa982704029, hash: 0xa6a2a8e85b476f0e },
+    TestVector { len: 61, seed: 0xbdd89aa982704029, hash: 0x6f5a0e3a25b238a1 },
+    TestVector { len: 62, seed: 0xbdd89aa982704029, hash: 0xf100890e73d384db },
+    TestVector { len: 63, seed: 0xbdd89aa982704029, hash: 0x675267c1fc464896 },
+    TestVector { len: 64, seed: 0xbdd89aa982704029, hash: 0x22400680c0a6ff48 },
+    TestVector { len: 65, seed: 0xbdd89aa982704029, hash: 0xc936b7484759408c },
+    TestVector { len: 66, seed: 0xbdd89aa982704029, hash: 0x2fa5a77c223a2621 },
+    TestVector { len: 67, seed: 0xbdd89aa982704029, hash: 0xfa04a219b2ebabbd },
+    TestVector { len: 68, seed: 0xbdd89aa982704029, hash: 0x5829c66e92d947eb },
+    TestVector { len: 69, seed: 0xbdd89aa982704029, hash: 0x26e18a67eb884e4b },
+    TestVector { len: 70, seed: 0xbdd89aa982704029, hash: 0x2d9eb45c8c8b7387 },
+    TestVector { len: 71, seed: 0xbdd89aa982704029, hash: 0xcd8a01c910aa1708 },
+    TestVector { len: 72, seed: 0xbdd89aa982704029, hash: 0x9b26f3b857bfdffa },
+    TestVector { len: 73, seed: 0xbdd89aa982704029, hash: 0xd051989348ec90ba },
+    TestVector { len: 74, seed: 0xbdd89aa982704029, hash: 0xf571ad690c8cfeac },
+    TestVector { len: 75, seed: 0xbdd89aa982704029, hash: 0x91a524a5e6bfcf3e },
+    TestVector { len: 76, seed: 0xbdd89aa982704029, hash: 0xf1dcab75e4d02852 },
+    TestVector { len: 77, seed: 0xbdd89aa982704029, hash: 0xdb9790b24253de26 },
+    TestVector { len: 78, seed: 0xbdd89aa982704029, hash: 0xd9dd03892b20610b },
+    TestVector { len: 79, seed: 0xbdd89aa982704029, hash: 0xe1c7f6a222b0b299 },
+    TestVector { len: 80, seed: 0xbdd89aa982704029, hash: 0xecacddbbf7e0dd16 },
+    TestVector { len: 81, seed: 0xbdd89aa982704029, hash: 0x1fb04a7fafd6e166 },
+    TestVector { len: 82, seed: 0xbdd89aa982704029, hash: 0x866e2f9075a82c77 },
+    TestVector { len: 83, seed: 0xbdd89aa982704029, hash: 0xdb0ad22b6c986746 },
+    TestVector { len: 84, seed: 0xbdd89aa982704029, hash: 0x1946fe5cca473581 },
+    TestVector { len: 85, seed: 0xbdd89aa982704029, hash: 0x05a4d58697591ed0 },
+    TestVector { len: 86, seed: 0xbdd89aa982704029, hash: 0x8f30210b08874013 },
+    TestVector { len: 87, seed: 0xbdd89aa982704029, hash: 0x473ce4b91e75a14a },
+    TestVector { len: 88, seed: 0xbdd89aa982704029, hash: 0x78900fd06f65b55a },
+    TestVector { len: 89, seed: 0xbdd89aa982704029, hash: 0xac899cfbf49fe239 },
+    TestVector { len: 90, seed: 0xbdd89aa982704029, hash: 0xf7d62518e075bf70 },
+    TestVector { len: 91, seed: 0xbdd89aa982704029, hash: 0x65c04c30a4d4fa42 },
+    TestVector { len: 92, seed: 0xbdd89aa982704029, hash: 0x70ebe8f775d91605 },
+    TestVector { len: 93, seed: 0xbdd89aa982704029, hash: 0xf6b41343edaa6e9b },
+    TestVector { len: 94, seed: 0xbdd89aa982704029, hash: 0x570b41c3771d188d },
+    TestVector { len: 95, seed: 0xbdd89aa982704029, hash: 0xa00cb5898f95d98e },
+    TestVector { len: 96, seed: 0xbdd89aa982704029, hash: 0xd383ab4f1bb32676 },
+    TestVector { len: 97, seed: 0xbdd89aa982704029, hash: 0x8056ac0a48d3f65e },
+    TestVector { len: 98, seed: 0xbdd89aa982704029, hash: 0x660c5114d97ee188 },
+    TestVector { len: 99, seed: 0xbdd89aa982704029, hash: 0x2ff401318d86b555 },
+    TestVector { len: 100, seed: 0xbdd89aa982704029, hash: 0xe3018b53422e6dd3 },
+    TestVector { len: 101, seed: 0xbdd89aa982704029, hash: 0xf64ee1b0b9bb58cc },
+    TestVector { len: 102, seed: 0xbdd89aa982704029, hash: 0xefbeba898fa938a6 },
+    TestVector { len: 103, seed: 0xbdd89aa982704029, hash: 0x0bd103f9d319b3b8 },
+    TestVector { len: 104, seed: 0xbdd89aa982704029, hash: 0x04833d618784c6dc },
+    TestVector { len: 105, seed: 0xbdd89aa982704029, hash: 0x5c837afed0986fc9 },
+    TestVector { len: 106, seed: 0xbdd89aa982704029, hash: 0xdd2810430e50a4cd },
+    TestVector { len: 107, seed: 0xbdd89aa982704029, hash: 0x3f90f980acf69b0e },
+    TestVector { len: 108, seed: 0xbdd89aa982704029, hash: 0x456f48fdb258fe0c },
+    TestVector { len: 109, seed: 0xbdd89aa982704029, hash: 0x81e8cb7cb423fc38 },
+    TestVector { len: 110, seed: 0xbdd89aa982704029, hash: 0x3afe28cab07ac081 },
+    TestVector { len: 111, seed: 0xbdd89aa982704029, hash: 0x2bde3d05b8ee638b },
+    TestVector { len: 112, seed: 0xbdd89aa982704029, hash: 0x6b2ea265f1afcb9d },
+    TestVector { len: 113, seed: 0xbdd89aa982704029, hash: 0xfb25f17ca842d8b7 },
+    TestVector { len: 114, seed: 0xbdd89aa982704029, hash: 0xe693fb7d94b9110a },
+    TestVector { len: 115, seed: 0xbdd89aa982704029, hash: 0x6240e132bfddf08c },
+    TestVector { len: 116, seed: 0xbdd89aa982704029, hash: 0xdb72de43c1929f82 },
+    TestVector { len: 117, seed: 0xbdd89aa982704029, hash: 0xb0226be75699dd54 },
+    TestVector { len: 118, seed: 0xbdd89aa982704029, hash: 0x51e814f1302b9608 },
+    TestVector { len: 119, seed: 0xbdd89aa982704029, hash: 0xd130de3e915ed78d },
+    TestVector { len: 120, seed: 0xbdd89aa982704029, hash: 0x620bd40e92a761fe },
+    TestVector { len: 121, seed: 0xbdd89aa982704029, hash: 0x059a2a0ca799e03c },
+    TestVector { len: 122, seed: 0xbdd89aa982704029, hash: 0xc376809d5e22a61e },
+    TestVector { len: 123, seed: 0xbdd89aa982704029, hash: 0x7f0d6470b5493dd9 },
+    TestVector { len: 124, seed: 0xbdd89aa982704029, hash: 0xbf4679d07bf4523c },
+    TestVector { len: 125, seed: 0xbdd89aa982704029, hash: 0xc40bd9282a275949 },
+    TestVector { len: 126, seed: 0xbdd89aa982704029, hash: 0x5b4d5d031d464030 },
+    TestVector { len: 127, seed: 0xbdd89aa982704029, hash: 0xdc56ebf000c45532 },
+    TestVector { len: 128, seed: 0xbdd89aa982704029, hash: 0xa806046c50b31d95 },
+    TestVector { len: 191, seed: 0xbdd89aa982704029, hash: 0xe1c54a3d6b2238bd },
+    TestVector { len: 192, seed: 0xbdd89aa982704029, hash: 0xd628ef08652eac17 },
+    TestVector { len: 193, seed: 0xbdd89aa982704029, hash: 0xe61df5e299ec7cb7 },
+    TestVector { len: 255, seed: 0xbdd89aa982704029, hash: 0xc064bfb7e8485ca1 },
+    TestVector { len: 256, seed: 0xbdd89aa982704029, hash: 0xa26f23f252a09e70 },
+    TestVector { len: 257, seed: 0xbdd89aa982704029, hash: 0xc5c19acf2a5d7caa },
+    TestVector { len: 511, seed: 0xbdd89aa982704029, hash: 0x878f3bda4b319e1b },
+    TestVector { len: 512, seed: 0xbdd89aa982704029, hash: 0xf5fd5408a885f92d },
+    TestVector { len: 513, seed: 0xbdd89aa982704029, hash: 0xb47c5e2dacbc8b20 },
+    TestVector { len: 1000, seed: 0xbdd89aa982704029, hash: 0xa3e0f900949850aa },
+    TestVector { len: 1024, seed: 0xbdd89aa982704029, hash: 0x537ee20a497614f9 },
+    TestVector { len: 0, seed: 0x0123456789abcdef, hash: 0x16d3b0a07d2cea83 },
+    TestVector { len: 1, seed: 0x0123456789abcdef, hash: 0x35e4526bf7c1d5c0 },
+    TestVector { len: 2, seed: 0x0123456789abcdef, hash: 0xc35f249270d50ac7 },
+    TestVector { len: 3, seed: 0x0123456789abcdef, hash: 0xf2577cc852e5553e },
+    TestVector { len: 4, seed: 0x0123456789abcdef, hash: 0x255c5f9cf294d093 },
+    TestVector { len: 5, seed: 0x0123456789abcdef, hash: 0x672106993291b3f3 },
+    TestVector { len: 6, seed: 0x0123456789abcdef, hash: 0xa1075901b98a3fba },
+    TestVector { len: 7, seed: 0x0123456789abcdef, hash: 0xb244861df16c30da },
+    TestVector { len: 8, seed: 0x0123456789abcdef, hash: 0xcb14f39ef415ce22 },
+    TestVector { len: 9, seed: 0x0123456789abcdef, hash: 0xf0e5dd6def5ddcc3 },
+    TestVector { len: 10, seed: 0x0123456789abcdef, hash: 0x9220d0c3f0d9cce0 },
+    TestVector { len: 11, seed: 0x0123456789abcdef, hash: 0x482232e8cbbbb8d2 },
+    TestVector { len: 12, seed: 0x0123456789abcdef, hash: 0x7f67ad48725b0ea2 },
+    TestVector { len: 13, seed: 0x0123456789abcdef, hash: 0x2b375e56813fb2b3 },
+    TestVector { len: 14, seed: 0x0123456789abcdef, hash: 0x17d81b74f7c982f2 },
+    TestVector { len: 15, seed: 0x0123456789abcdef, hash: 0xc70e78a8c457b344 },
+    TestVector { len: 16, seed: 0x0123456789abcdef, hash: 0xf59298d8559fd456 },
+    TestVector { len: 17, seed: 0x0123456789abcdef, hash: 0xd65f20bc669c895c },
+    TestVector { len: 18, seed: 0x0123456789abcdef, hash: 0x46a861be41c81b21 },
+    TestVector { len: 19, seed: 0x0123456789abcdef, hash: 0x2d345ef542bcf753 },
+    TestVector { len: 20, seed: 0x0123456789abcdef, hash: 0xd42b0e57e3983576 },
+    TestVector { len: 21, seed: 0x0123456789abcdef, hash: 0x68c8aae7f8f30fbf },
+    TestVector { len: 22, seed: 0x0123456789abcdef, hash: 0x5b5a9973e509ed38 },
+    TestVector { len: 23, seed: 0x0123456789abcdef, hash: 0x9ea2df70fe5b6803 },
+    TestVector { len: 24, seed: 0x0123456789abcdef, hash: 0x09d716f3c260182f },
+    TestVector { len: 25, seed: 0x0123456789abcdef, hash: 0xe924fee723089a57 },
+    TestVector { len: 26, seed: 0x0123456789abcdef, hash: 0xd88586502c14b3d5 },
+    TestVector { len: 27, seed: 0x0123456789abcdef, hash: 0x5501d6b9b66db6de },
+    TestVector { len: 28, seed: 0x0123456789abcdef, hash: 0x790adafa84c7408f },
+    TestVector { len: 29, seed: 0x0123456789abcdef, hash: 0x4b154e7b2ba5b5a8 },
+    TestVector { len: 30, seed: 0x0123456789abcdef, hash: 0xa282bb4a7017825c },
+    TestVector { len: 31, seed: 0x0123456789abcdef, hash: 0x9d29974cb2f917cb },
+    TestVector { len: 32, seed: 0x0123456789abcdef, hash: 0xd3122f1517447cb9 },
+    TestVector { len: 33, seed: 0x0123456789abcdef, hash: 0x452ac41d4cf3ad7d },
+    TestVector { len: 34, seed: 0x0123456789abcdef, hash: 0x8421b01860cee7a3 },
+    TestVector { len: 35, seed: 0x0123456789abcdef, hash: 0xe22fa89dec047b80 },
+    TestVector { len: 36, seed: 0x0123456789abcdef, hash: 0xad2b4b167ca48157 },
+    TestVector { len: 37, seed: 0x0123456789abcdef, hash: 0xd79113f11d898cd5 },
+    TestVector { len: 38, seed: 0x0123456789abcdef, hash: 0x899635f3c2e63656 },
+    TestVector { len: 39, seed: 0x0123456789abcdef, hash: 0x387b5120dc465ea6 },
+    TestVector { len: 40, seed: 0x0123456789abcdef, hash: 0x4d53b18b6e75330e },
+    TestVector { len: 41, seed: 0x0123456789abcdef, hash: 0x7fef05cf088ef0bf },
+    TestVector { len: 42, seed: 0x0123456789abcdef, hash: 0xeebfff4c28598eb6 },
+    TestVector { len: 43, seed: 0x0123456789abcdef, hash: 0xf1ec01cffbde8b79 },
+    TestVector { len: 44, seed: 0x0123456789abcdef, hash: 0x7fa732ff5dab5010 },
+    TestVector { len: 45, seed: 0x0123456789abcdef, hash: 0x4f369bbff1b66eb8 },
+    TestVector { len: 46, seed: 0x0123456789abcdef, hash: 0xba456e90857254fe },
+    TestVector { len: 47, seed: 0x0123456789abcdef, hash: 0xa274937cd26a0e4e },
+    TestVector { len: 49, seed: 0x0123456789abcdef, hash: 0x18b6e152c4366caf },
+    TestVector { len: 50, seed: 0x0123456789abcdef, hash: 0x9c2f5d5f868b83c8 },
+    TestVector { len: 51, seed: 0x0123456789abcdef, hash: 0xf3587abb7f0f393e },
+    TestVector { len: 52, seed: 0x0123456789abcdef, hash: 0xde365d8aacd986dd },
+    TestVector { len: 53, seed: 0x0123456789abcdef, hash: 0x77a898e59596412e },
+    TestVector { len: 54, seed: 0x0123456789abcdef, hash: 0x35472db1d02676a4 },
+    TestVector { len: 55, seed: 0x0123456789abcdef, hash: 0xb0cb56b5419633e5 },
+    TestVector { len: 56, seed: 0x0123456789abcdef, hash: 0xf4130d577303cf62 },
+    TestVector { len: 57, seed: 0x0123456789abcdef, hash: 0x161944eb4cb30057 },
+    TestVector { len: 58, seed: 0x0123456789abcdef, hash: 0x3f6237c44a4dc0c9 },
+    TestVector { len: 59, seed: 0x0123456789abcdef, hash: 0xe13edd2139c736e1 },
+    TestVector { len: 60, seed: 0x0123456789abcdef, hash: 0xe358a450e55bdd43 },
+    TestVector { len: 61, seed: 0x0123456789abcdef, hash: 0xf82dad6753f0a0fc },
+    TestVector { len: 62, seed: 0x0123456789abcdef, hash: 0xf11dcbc965440717 },
+    TestVector { len: 63, seed: 0x0123456789abcdef, hash: 0x0966486d8e1f0912 },
+    TestVector { len: 64, seed: 0x0123456789abcdef, hash: 0x29f62e405a18baaf },
+    TestVector { len: 65, seed: 0x0123456789abcdef, hash: 0xb2d9d54334b2a903 },
+    TestVector { len: 66, seed: 0x0123456789abcdef, hash: 0x7799888af8be1629 },
+    TestVector { len: 67, seed: 0x0123456789abcdef, hash: 0x0daa8622a12903da },
+    TestVector { len: 68, seed: 0x0123456789abcdef, hash: 0x5a84929dcce964fc },
+    TestVector { len: 69, seed: 0x0123456789abcdef, hash: 0xb05cc3e935ce9515 },
+    TestVector { len: 70, seed: 0x0123456789abcdef, hash: 0xe0ff30e841a5b8ba },
+    TestVector { len: 71, seed: 0x0123456789abcdef, hash: 0x5ae5f453acc034de },
+    TestVector { len: 72, seed: 0x0123456789abcdef, hash: 0xf99e873e3bfa76a6 },
+    TestVector { len: 73, seed: 0x0123456789abcdef, hash: 0x17d101f5ff4a5cf9 },
+    TestVector { len: 74, seed: 0x0123456789abcdef, hash: 0x6143e4c0bdc36501 },
+    TestVector { len: 75, seed: 0x0123456789abcdef, hash: 0xe0d4e651fdc87541 },
+    TestVector { len: 76, seed: 0x0123456789abcdef, hash: 0x045587d86442c2d0 },
+    TestVector { len: 77, seed: 0x0123456789abcdef, hash: 0x17cfa6cc5236cf0b },
+    TestVector { len: 78, seed: 0x0123456789abcdef, hash: 0xdeaa097d69b633e0 },
+    TestVector { len: 79, seed: 0x0123456789abcdef, hash: 0x2f595f72847204ef },
+    TestVector { len: 80, seed: 0x0123456789abcdef, hash: 0x6ea1d40f7d260e6d },
+    TestVector { len: 81, seed: 0x0123456789abcdef, hash: 0xe88f58c3274bb877 },
+    TestVector { len: 82, seed: 0x0123456789abcdef, hash: 0xbe12ca396585bffc },
+    TestVector { len: 83, seed: 0x0123456789abcdef, hash: 0x4debc399eb39da53 },
+    TestVector { len: 84, seed: 0x0123456789abcdef, hash: 0xf06151bef047f60f },
+    TestVector { len: 85, seed: 0x0123456789abcdef, hash: 0xfd178f8abdedb20f },
+    TestVector { len: 86, seed: 0x0123456789abcdef, hash: 0xdcb5ee52cc40b182 },
+    TestVector { len: 87, seed: 0x0123456789abcdef, hash: 0x55bdedd5d3e15efb },
+    TestVector { len: 88, seed: 0x0123456789abcdef, hash: 0x2d75907ae7058d4d },
+    TestVector { len: 89, seed: 0x0123456789abcdef, hash: 0xfc51aa24f51f4e5a },
+    TestVector { len: 90, seed: 0x0123456789abcdef, hash: 0x9fb9771cc055b744 },
+    TestVector { len: 91, seed: 0x0123456789abcdef, hash: 0xbaeab1126baf43f3 },
+    TestVector { len: 92, seed: 0x0123456789abcdef, hash: 0xac4dbb0f90799259 },
+    TestVector { len: 93, seed: 0x0123456789abcdef, hash: 0x9d04dced53df2e2c },
+    TestVector { len: 94, seed: 0x0123456789abcdef, hash: 0xbfcf098b2ed6275d },
+    TestVector { len: 95, seed: 0x0123456789abcdef, hash: 0x611637e28bbe12eb },
+    TestVector { len: 96, seed: 0x0123456789abcdef, hash: 0xb7aa0ac547984ca1 },
+    TestVector { len: 97, seed: 0x0123456789abcdef, hash: 0x891caa3ffc73ae0d },
+    TestVector { len: 98, seed: 0x0123456789abcdef, hash: 0x5d8eca2e622fceab },
+    TestVector { len: 99, seed: 0x0123456789abcdef, hash: 0xeb420e22ccdac4fc },
+    TestVector { len: 100, seed: 0x0123456789abcdef, hash: 0xab62cd077e781702 },
+    TestVector { len: 101, seed: 0x0123456789abcdef, hash: 0x15424b00d1ee151e },
+    TestVector { len: 102, seed: 0x0123456789abcdef, hash: 0xcea9b1110f8456ab },
+    TestVector { len: 103, seed: 0x0123456789abcdef, hash: 0x715606b9f3a6b489 },
+    TestVector { len: 104, seed: 0x0123456789abcdef, hash: 0x61b3c8db02f8bba4 },
+    TestVector { len: 105, seed: 0x0123456789abcdef, hash: 0x0d2a42300aeda6d2 },
+    TestVector { len: 106, seed: 0x0123456789abcdef, hash: 0xfdec75633d446a21 },
+    TestVector { len: 107, seed: 0x0123456789abcdef, hash: 0xd8d45889d3582dd0 },
+    TestVector { len: 108, seed: 0x0123456789abcdef, hash: 0x3a39807d2cf80195 },
+    TestVector { len: 109, seed: 0x0123456789abcdef, hash: 0xf20589c9e1d6ae97 },
+    TestVector { len: 110, seed: 0x0123456789abcdef, hash: 0xc445291f24d5459e },
+    TestVector { len: 111, seed: 0x0123456789abcdef, hash: 0xcb89609456dea395 },
+    TestVector { len: 112, seed: 0x0123456789abcdef, hash: 0xcd3b0ce6cbcc2d0c },
+    TestVector { len: 113, seed: 0x0123456789abcdef, hash: 0x0d3b4a8c95c0fa3f },
+    TestVector { len: 114, seed: 0x0123456789abcdef, hash: 0xc9dbd26790833985 },
+    TestVector { len: 115, seed: 0x0123456789abcdef, hash: 0xdb1e0e3ae6b5a738 },
+    TestVector { len: 116, seed: 0x0123456789abcdef, hash: 0xd334b9ba71f2e5ec },
+    TestVector { len: 117, seed: 0x0123456789abcdef, hash: 0xa814cee73556f6d1 },
+    TestVector { len: 118, seed: 0x0123456789abcdef, hash: 0xe4974b90e4ce4e14 },
+    TestVector { len: 119, seed: 0x0123456789abcdef, hash: 0xdfcd4f6deeb7bf52 },
+    TestVector { len: 120, seed: 0x0123456789abcdef, hash: 0x6f05775a919f8668 },
+    TestVector { len: 121, seed: 0x0123456789abcdef, hash: 0xf7f30018942cc4f7 },
+    TestVector { len: 122, seed: 0x0123456789abcdef, hash: 0xe5a37218b390f846 },
+    TestVector { len: 123, seed: 0x0123456789abcdef, hash: 0x318104e57894c37e },
+    TestVector { len: 124, seed: 0x0123456789abcdef, hash: 0x0c841af361994b95 },
+    TestVector { len: 125, seed: 0x0123456789abcdef, hash: 0x9d7cf9ec61670a2c },
+    TestVector { len: 126, seed: 0x0123456789abcdef, hash: 0xf3966d34b1b8da3c },
+    TestVector { len: 127, seed: 0x0123456789abcdef, hash: 0x13452ae491211796 },
+    TestVector { len: 128, seed: 0x0123456789abcdef, hash: 0xaf75f7a600fba571 },
+    TestVector { len: 191, seed: 0x0123456789abcdef, hash: 0x466a21de67511b62 },
+    TestVector { len: 192, seed: 0x0123456789abcdef, hash: 0xe08ba3e13fa14bd9 },
+    TestVector { len: 193, seed: 0x0123456789abcdef, hash: 0x9ecbade0c891f99a },
+    TestVector { len: 255, seed: 0x0123456789abcdef, hash: 0x5361d2994d846663 },
+    TestVector { len: 256, seed: 0x0123456789abcdef, hash: 0x35cfecd33a2ee697 },
+    TestVector { len: 257, seed: 0x0123456789abcdef, hash: 0xa247cc3ccb3d6430 },
+    TestVector { len: 511, seed: 0x0123456789abcdef, hash: 0x8053046b8f97ad33 },
+    TestVector { len: 512, seed: 0x0123456789abcdef, hash: 0xcbbd5e9e308bd721 },
+    TestVector { len: 513, seed: 0x0123456789abcdef, hash: 0x7e6982364e178c38 },
+    TestVector { len: 1000, seed: 0x0123456789abcdef, hash: 0x184dc876aa196a80 },
+    TestVector { len: 1024, seed: 0x0123456789abcdef, hash: 0x1055c6d8d9b10790 },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vectors_verify() {
+        assert_eq!(verify_reference_vectors(), Ok(()));
+        assert_eq!(REFERENCE_VECTORS.len(), 417);
+    }
+
+    #[test]
+    fn vectors_include_default_seed() {
+        let vector = REFERENCE_VECTORS.iter()
+            .find(|v| v.len == 0 && v.seed == crate::RAPID_SEED)
+            .unwrap();
+        assert_eq!(vector.hash, crate::rapidhash(&[]));
+    }
+}
@@ -0,0 +1,42 @@
+//! The low-level building blocks of the rapidhash algorithm.
+//!
+//! Every hasher in this crate, including the oneshot [crate::rapidhash], [crate::RapidHasher], and
+//! [crate::RapidStreamHasher], is built on these const functions, so custom variants can reuse the
+//! exact same mixing without copying the algorithm.
+//!
+//! The oneshot hash of `data` with `seed` is composed as follows:
+//!
+//! ```
+//! use rapidhash::raw::{rapidhash_core, rapidhash_finish, rapidhash_seed};
+//!
+//! let data = b"hello world";
+//! let seed = rapidhash_seed(rapidhash::RAPID_SEED, data.len() as u64);
+//! let (a, b, _) = rapidhash_core(0, 0, seed, data);
+//! let hash = rapidhash_finish(a, b, data.len() as u64);
+//!
+//! assert_eq!(hash, rapidhash::rapidhash(data));
+//! ```
+//!
+//! The module is named `raw` rather than `core`, so a glob import of this crate doesn't shadow the
+//! `core` crate:
+//!
+//! ```
+//! use rapidhash::*;
+//!
+//! let (mut a, mut b) = (1, 2);
+//! core::mem::swap(&mut a, &mut b);
+//! assert_eq!((a, b), (2, 1));
+//! ```
+
+#[doc(inline)]
+pub use crate::rapid_core::{RapidCore, RAPID_BLOCK};
+#[doc(inline)]
+pub use crate::rapid_const::{
+    rapid_mix,
+    rapid_mum,
+    rapidhash_core,
+    rapidhash_finish,
+    rapidhash_seed,
+    RAPID_SECRET,
+    RAPID_SEED,
+};